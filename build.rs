@@ -4,6 +4,9 @@ use std::{
     path::PathBuf,
 };
 
+#[path = "build/consts.rs"]
+mod consts;
+
 const DEFAULT_ROUNDING_MODE: &'static str = "HalfUp";
 const FMT_EXPONENTIAL_LOWER_THRESHOLD: &'static str = "5";
 const FMT_EXPONENTIAL_UPPER_THRESHOLD: &'static str = "15";
@@ -14,6 +17,9 @@ fn main() {
     let out_dir: PathBuf = env::var_os("OUT_DIR").unwrap().into();
     let file = std::fs::File::create(out_dir.join("config.rs")).unwrap();
     write_config(file);
+
+    let file = std::fs::File::create(out_dir.join("consts.rs")).unwrap();
+    consts::write_consts(file);
 }
 
 macro_rules! load_env {
@@ -0,0 +1,514 @@
+//! Generates the digits of the mathematical constants.
+//!
+//! Every constant is computed as a fixed-point integer scaled by
+//! _10<sup>P</sup>_, with some guard digits over the digits written out, and
+//! truncated. _π_ is computed by Chudnovsky binary splitting, _e_ from the
+//! series of _1/k!_, _ln 2_, _ln 3_ and _ln 10_ from Machin-like formulas in
+//! _atanh_, _γ_ by the Brent–McMillan algorithm, Catalan's constant by
+//! Ramanujan's series and _ζ(3)_ by the Amdeberhan–Zeilberger series; the
+//! other constants are derived from these.
+
+use std::{cmp::Ordering, io::Write};
+
+/// Widest decimal type in bits.
+const MAX_BITS: usize = 8192;
+
+/// Extra digits stored beyond the coefficient of the widest type.
+const EXTRA_PRECISION_DIGITS: usize = 7;
+
+const GUARD_DIGITS: usize = 20;
+
+pub fn write_consts<W: Write>(mut out: W) {
+    // Decimal digits of the widest coefficient, rounded up, plus a margin.
+    let digits = MAX_BITS * 30103 / 100000 + 1 + EXTRA_PRECISION_DIGITS + 8;
+    let p = digits + GUARD_DIGITS;
+    let one = Nat::pow10(p);
+
+    let pi = pi(p);
+    let e = e(&one);
+    let ln_2 = ln_2(&one);
+    let ln_10 = ln_10(&one, &ln_2);
+    let sqrt_2 = one.mul(&one).mul_small(2).isqrt();
+    let sqrt_3 = one.mul(&one).mul_small(3).isqrt();
+    let sqrt_5 = one.mul(&one).mul_small(5).isqrt();
+    let sqrt_pi = pi.mul(&one).isqrt();
+
+    let consts = [
+        ("E", e),
+        ("PI", pi.clone()),
+        ("TAU", pi.mul_small(2)),
+        ("FRAC_1_PI", one.mul(&one).div(&pi)),
+        ("FRAC_2_PI", one.mul(&one).mul_small(2).div(&pi)),
+        ("FRAC_PI_2", pi.div_small(2)),
+        ("FRAC_PI_3", pi.div_small(3)),
+        ("FRAC_PI_4", pi.div_small(4)),
+        ("FRAC_PI_6", pi.div_small(6)),
+        ("FRAC_PI_8", pi.div_small(8)),
+        ("FRAC_2_SQRT_PI", one.mul(&one).mul_small(2).div(&sqrt_pi)),
+        ("FRAC_1_SQRT_PI", one.mul(&one).div(&sqrt_pi)),
+        ("LN_2", ln_2.clone()),
+        ("LN_10", ln_10.clone()),
+        ("LOG2_E", one.mul(&one).div(&ln_2)),
+        ("LOG10_E", one.mul(&one).div(&ln_10)),
+        ("SQRT_2", sqrt_2.clone()),
+        ("FRAC_1_SQRT_2", sqrt_2.div_small(2)),
+        ("LOG10_2", ln_2.mul(&one).div(&ln_10)),
+        ("LOG2_10", ln_10.mul(&one).div(&ln_2)),
+        ("SQRT_3", sqrt_3.clone()),
+        ("SQRT_5", sqrt_5.clone()),
+        ("PHI", sqrt_5.add(&one).div_small(2)),
+        ("LN_3", ln_3(&one, &ln_2)),
+        ("EULER_GAMMA", euler_gamma(&one, &ln_2)),
+        ("CATALAN", catalan(&one, &pi, &sqrt_3)),
+        ("APERY", apery(&one)),
+    ];
+
+    for (name, value) in consts {
+        let s = value.to_string();
+        let (int, frac) = s.split_at(s.len() - p);
+        let int = if int.is_empty() { "0" } else { int };
+        writeln!(out, "const {name}: &str = \"{int}.{}\";", &frac[..digits]).unwrap();
+    }
+}
+
+/// _π · 10<sup>p</sup>_ by the Chudnovsky series.
+fn pi(p: usize) -> Nat {
+    // Each term adds about 14.18 digits.
+    let terms = p as u64 / 14 + 2;
+    let (_, q, t) = chudnovsky(0, terms);
+    let one = Nat::pow10(p);
+    let sqrt_10005 = one.mul(&one).mul_small(10005).isqrt();
+
+    q.mul_small(426880).mul(&sqrt_10005).div(&t.mag)
+}
+
+/// Binary splitting of the Chudnovsky series over the terms `a..b`.
+fn chudnovsky(a: u64, b: u64) -> (Nat, Nat, Int) {
+    // 640320³ / 24
+    const C3_24: u64 = 10939058860032000;
+
+    if b - a == 1 {
+        let (p, q) = if a == 0 {
+            (Nat::from(1), Nat::from(1))
+        } else {
+            let p = Nat::from(6 * a - 5)
+                .mul_small(2 * a - 1)
+                .mul_small(6 * a - 1);
+            let q = Nat::from(a).mul_small(a).mul_small(a).mul_small(C3_24);
+            (p, q)
+        };
+        let t = Int {
+            neg: a % 2 == 1,
+            mag: p.mul_small(13591409 + 545140134 * a),
+        };
+        (p, q, t)
+    } else {
+        let m = (a + b) / 2;
+        let (p1, q1, t1) = chudnovsky(a, m);
+        let (p2, q2, t2) = chudnovsky(m, b);
+        let t = t1.mul(&q2).add(&t2.mul(&p1));
+        (p1.mul(&p2), q1.mul(&q2), t)
+    }
+}
+
+/// _e · 10<sup>p</sup>_ as the sum of _10<sup>p</sup>/k!_.
+fn e(one: &Nat) -> Nat {
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut k = 1;
+
+    while !term.is_zero() {
+        term = term.div_small(k);
+        sum = sum.add(&term);
+        k += 1;
+    }
+
+    sum
+}
+
+/// _ln 2 = 18·atanh(1/26) − 2·atanh(1/4801) + 8·atanh(1/8749)_.
+fn ln_2(one: &Nat) -> Nat {
+    atanh_inv(one, 26)
+        .mul_small(18)
+        .add(&atanh_inv(one, 8749).mul_small(8))
+        .sub(&atanh_inv(one, 4801).mul_small(2))
+}
+
+/// _ln 10 = 3·ln 2 + ln(5/4) = 3·ln 2 + 2·atanh(1/9)_.
+fn ln_10(one: &Nat, ln_2: &Nat) -> Nat {
+    ln_2.mul_small(3).add(&atanh_inv(one, 9).mul_small(2))
+}
+
+/// _ln 3 = ln 2 + ln(3/2) = ln 2 + 2·atanh(1/5)_.
+fn ln_3(one: &Nat, ln_2: &Nat) -> Nat {
+    ln_2.add(&atanh_inv(one, 5).mul_small(2))
+}
+
+/// Euler–Mascheroni constant by the Brent–McMillan algorithm B1:
+/// _γ = W/V − ln n_ with _V = Σ (n<sup>k</sup>/k!)<sup>2</sup>_ and
+/// _W = Σ (n<sup>k</sup>/k!)<sup>2</sup> H<sub>k</sub>_, where the error is
+/// _O(e<sup>−4n</sup>)_.
+fn euler_gamma(one: &Nat, ln_2: &Nat) -> Nat {
+    // n = 2^log_n with 4n > ln(10^p).
+    let mut log_n = 1;
+    while (4u64 << log_n) as f64 <= one.bits() as f64 * std::f64::consts::LN_2 {
+        log_n += 1;
+    }
+    let n2 = 1u64 << (2 * log_n);
+
+    // B_k = B_(k−1)·n²/k² and C_k = C_(k−1)·n²/k² + B_k/k = B_k·H_k
+    let mut b = one.clone();
+    let mut c = Nat::from(0);
+    let mut v = one.clone();
+    let mut w = Nat::from(0);
+    let mut k = 1u64;
+
+    while !b.is_zero() || !c.is_zero() {
+        b = b.mul_small(n2).div_small(k * k);
+        c = c.mul_small(n2).div_small(k * k).add(&b.div_small(k));
+        v = v.add(&b);
+        w = w.add(&c);
+        k += 1;
+    }
+
+    w.mul(one).div(&v).sub(&ln_2.mul_small(log_n))
+}
+
+/// Catalan's constant by Ramanujan's series
+/// _G = π/8 · ln(2 + √3) + 3/8 · Σ (k!)<sup>2</sup>/((2k)! (2k + 1)<sup>2</sup>)_.
+fn catalan(one: &Nat, pi: &Nat, sqrt_3: &Nat) -> Nat {
+    // ln(2 + √3) = 2·atanh(1/√3) = 2/√3 · Σ 3^−k/(2k + 1)
+    let mut power = one.clone();
+    let mut sum = Nat::from(0);
+    let mut k = 1;
+    while !power.is_zero() {
+        sum = sum.add(&power.div_small(k));
+        power = power.div_small(3);
+        k += 2;
+    }
+    let ln = sum.mul_small(2).mul(one).div(sqrt_3);
+
+    let mut term = one.clone();
+    let mut series = Nat::from(0);
+    let mut k = 0u64;
+    while !term.is_zero() {
+        series = series.add(&term.div_small((2 * k + 1) * (2 * k + 1)));
+        k += 1;
+        term = term.mul_small(k).div_small(2 * (2 * k - 1));
+    }
+
+    pi.mul(&ln).div(one).add(&series.mul_small(3)).div_small(8)
+}
+
+/// Apéry's constant _ζ(3)_ by the Amdeberhan–Zeilberger series
+/// _ζ(3) = 1/64 · Σ (−1)<sup>k</sup> (k!)<sup>10</sup> (205k<sup>2</sup> + 250k + 77)/((2k + 1)!)<sup>5</sup>_.
+fn apery(one: &Nat) -> Nat {
+    let mut term = one.clone();
+    let mut pos = Nat::from(0);
+    let mut neg = Nat::from(0);
+    let mut k = 0u64;
+
+    while !term.is_zero() {
+        let t = term.mul_small(205 * k * k + 250 * k + 77);
+        if k % 2 == 0 {
+            pos = pos.add(&t);
+        } else {
+            neg = neg.add(&t);
+        }
+
+        k += 1;
+        for _ in 0..5 {
+            term = term.mul_small(k * k);
+        }
+        for _ in 0..5 {
+            term = term.div_small(2 * k * (2 * k + 1));
+        }
+    }
+
+    pos.sub(&neg).div_small(64)
+}
+
+/// _atanh(1/n) · 10<sup>p</sup>_.
+fn atanh_inv(one: &Nat, n: u64) -> Nat {
+    let mut power = one.div_small(n);
+    let mut sum = Nat::from(0);
+    let mut k = 1;
+
+    while !power.is_zero() {
+        sum = sum.add(&power.div_small(k));
+        power = power.div_small(n * n);
+        k += 2;
+    }
+
+    sum
+}
+
+/// Arbitrary precision natural number, little-endian in base _2<sup>32</sup>_.
+#[derive(Clone, PartialEq, Eq)]
+struct Nat(Vec<u32>);
+
+impl From<u64> for Nat {
+    fn from(n: u64) -> Self {
+        Nat(vec![n as u32, (n >> 32) as u32]).normalized()
+    }
+}
+
+impl Nat {
+    fn pow10(n: usize) -> Self {
+        let mut res = Nat::from(1);
+        for _ in 0..n {
+            res = res.mul_small(10);
+        }
+        res
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn bits(&self) -> usize {
+        match self.0.last() {
+            Some(top) => self.0.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut res = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let t = *self.0.get(i).unwrap_or(&0) as u64
+                + *other.0.get(i).unwrap_or(&0) as u64
+                + carry;
+            res.push(t as u32);
+            carry = t >> 32;
+        }
+        res.push(carry as u32);
+        Nat(res).normalized()
+    }
+
+    /// `self - other`, which must not be negative.
+    fn sub(&self, other: &Self) -> Self {
+        let mut res = Vec::with_capacity(self.0.len());
+        let mut borrow = 0;
+        for i in 0..self.0.len() {
+            let t = self.0[i] as i64 - *other.0.get(i).unwrap_or(&0) as i64 - borrow;
+            res.push(t as u32);
+            borrow = (t < 0) as i64;
+        }
+        assert_eq!(borrow, 0, "negative difference");
+        Nat(res).normalized()
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut res = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.0.iter().enumerate() {
+                let t = a as u64 * b as u64 + res[i + j] as u64 + carry;
+                res[i + j] = t as u32;
+                carry = t >> 32;
+            }
+            res[i + other.0.len()] = carry as u32;
+        }
+        Nat(res).normalized()
+    }
+
+    fn mul_small(&self, n: u64) -> Self {
+        self.mul(&Nat::from(n))
+    }
+
+    fn div_small(&self, n: u64) -> Self {
+        if n <= u32::MAX as u64 {
+            self.div_rem_digit(n as u32).0
+        } else {
+            self.div(&Nat::from(n))
+        }
+    }
+
+    fn div_rem_digit(&self, n: u32) -> (Self, u32) {
+        let mut res = vec![0; self.0.len()];
+        let mut rem = 0u64;
+        for i in (0..self.0.len()).rev() {
+            let t = (rem << 32) | self.0[i] as u64;
+            res[i] = (t / n as u64) as u32;
+            rem = t % n as u64;
+        }
+        (Nat(res).normalized(), rem as u32)
+    }
+
+    fn shl(&self, bits: usize) -> Self {
+        let mut res = vec![0u32; bits / 32];
+        let shift = bits % 32;
+        let mut carry = 0;
+        for &d in &self.0 {
+            res.push((d << shift) | carry);
+            carry = if shift == 0 { 0 } else { d >> (32 - shift) };
+        }
+        res.push(carry);
+        Nat(res).normalized()
+    }
+
+    /// Integer quotient `self / other` by Knuth's algorithm D.
+    fn div(&self, other: &Self) -> Self {
+        assert!(!other.is_zero(), "division by zero");
+
+        if self.cmp(other) == Ordering::Less {
+            return Nat::from(0);
+        }
+        if other.0.len() == 1 {
+            return self.div_rem_digit(other.0[0]).0;
+        }
+
+        // Normalize so that the top digit of the divisor has its high bit set.
+        let shift = other.0.last().unwrap().leading_zeros() as usize;
+        let v = other.shl(shift).0;
+        let mut u = self.shl(shift).0;
+        u.resize(self.0.len() + 2, 0);
+
+        let n = v.len();
+        let m = self.0.len() + 1 - n;
+        let mut quot = vec![0u32; m + 1];
+        let base = 1u64 << 32;
+
+        for j in (0..=m).rev() {
+            let top = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+            let mut qhat = top / v[n - 1] as u64;
+            let mut rhat = top % v[n - 1] as u64;
+
+            while qhat >= base || qhat * v[n - 2] as u64 > (rhat << 32) + u[j + n - 2] as u64 {
+                qhat -= 1;
+                rhat += v[n - 1] as u64;
+                if rhat >= base {
+                    break;
+                }
+            }
+
+            // u[j..=j + n] -= qhat * v
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let p = qhat * v[i] as u64 + carry;
+                carry = p >> 32;
+                let t = u[i + j] as i64 - borrow - (p as u32) as i64;
+                u[i + j] = t as u32;
+                borrow = (t < 0) as i64;
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+
+            if t < 0 {
+                // The estimate was one too large: add the divisor back.
+                qhat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let t = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = t as u32;
+                    carry = t >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+
+            quot[j] = qhat as u32;
+        }
+
+        Nat(quot).normalized()
+    }
+
+    fn shr1(&self) -> Self {
+        let res = (0..self.0.len())
+            .map(|i| (self.0[i] >> 1) | self.0.get(i + 1).map_or(0, |d| d << 31))
+            .collect();
+        Nat(res).normalized()
+    }
+
+    /// Integer square root by Newton's method.
+    fn isqrt(&self) -> Self {
+        if self.is_zero() {
+            return Nat::from(0);
+        }
+
+        let mut x = Nat::from(1).shl(self.bits().div_ceil(2));
+        loop {
+            let y = x.add(&self.div(&x)).shr1();
+            if y.cmp(&x) != Ordering::Less {
+                return x;
+            }
+            x = y;
+        }
+    }
+}
+
+impl std::fmt::Display for Nat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const BASE: u32 = 1_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem_digit(BASE);
+            chunks.push(r);
+            n = q;
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((top, rest)) => {
+                write!(f, "{top}")?;
+                for chunk in rest.iter().rev() {
+                    write!(f, "{chunk:09}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Signed integer used by the binary splitting.
+struct Int {
+    neg: bool,
+    mag: Nat,
+}
+
+impl Int {
+    fn mul(&self, other: &Nat) -> Self {
+        Int {
+            neg: self.neg,
+            mag: self.mag.mul(other),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.neg == other.neg {
+            return Int {
+                neg: self.neg,
+                mag: self.mag.add(&other.mag),
+            };
+        }
+
+        match self.mag.cmp(&other.mag) {
+            Ordering::Less => Int {
+                neg: other.neg,
+                mag: other.mag.sub(&self.mag),
+            },
+            _ => Int {
+                neg: self.neg,
+                mag: self.mag.sub(&other.mag),
+            },
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::utils::{assert_eq_size, err_msg};
+
+/// Exponent is always written with a sign.
+const ALWAYS_SIGNED: u8 = 0b01;
+
+/// Engineering notation writes SI prefixes instead of exponents.
+const SI_PREFIX: u8 = 0b10;
+
+/// SI prefixes of the powers _10<sup>3k</sup>_ from _10<sup>-30</sup>_ to
+/// _10<sup>30</sup>_.
+const SI_PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    "R", "Q",
+];
+
+/// # Exponent Style
+///
+/// The way exponents are written in the scientific and the engineering
+/// notations and wherever [Display](core::fmt::Display) switches to
+/// exponential form, as part of the [FormatStyle](crate::decimal::FormatStyle)
+/// of a [Context](crate::decimal::Context).
+///
+/// The default style keeps the symbol and the sign each notation uses on its
+/// own, e.g. `1.2345E-7` and `1e+21` for [Display](core::fmt::Display) and
+/// `1.5e3` for the scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{*, decimal::*};
+///
+/// let style = ExponentStyle::new()
+///     .with_uppercase(true)
+///     .with_always_signed(true)
+///     .with_min_digits(2);
+/// let ctx = Context::default().with_exponent_style(style);
+///
+/// assert_eq!(dec256!(1.5e3).with_ctx(ctx).to_scientific_notation(), "1.5E+03");
+/// assert_eq!(dec256!(1.5e-7).with_ctx(ctx).to_string(), "1.5E-07");
+///
+/// let si = ExponentStyle::new().with_si_prefix(true);
+/// let ctx = Context::default().with_exponent_style(si);
+///
+/// assert_eq!(dec256!(1.5e3).with_ctx(ctx).to_engineering_notation(), "1.5k");
+/// assert_eq!(dec256!(0.0000023).with_ctx(ctx).to_engineering_notation(), "2.3µ");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct ExponentStyle {
+    /// `e` or `E`, or zero for the symbol of the notation.
+    symbol: u8,
+
+    /// Exponent digits are padded with zeros to at least this many.
+    min_digits: u8,
+
+    flags: u8,
+}
+
+impl ExponentStyle {
+    /// The default `ExponentStyle`: the symbol and the sign of each notation,
+    /// no zero padding and no SI prefixes.
+    pub const DEFAULT: Self = Self {
+        symbol: 0,
+        min_digits: 1,
+        flags: 0,
+    };
+
+    /// Returns the [default](Self::DEFAULT) `ExponentStyle`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Writes the exponent symbol as `E` if `uppercase` is set and as `e`
+    /// otherwise, in every notation.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_uppercase(mut self, uppercase: bool) -> Self {
+        self.symbol = if uppercase { b'E' } else { b'e' };
+        self
+    }
+
+    /// Writes a `+` before non-negative exponents too, e.g. `1e+5`.
+    ///
+    /// Without it only the notations which always sign their exponents do.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_always_signed(mut self, always_signed: bool) -> Self {
+        self.set_flag(ALWAYS_SIGNED, always_signed);
+        self
+    }
+
+    /// Pads the exponent with zeros to at least `digits` digits after its
+    /// sign, e.g. `1e+005` for `3`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_min_digits(mut self, digits: u8) -> Self {
+        if digits == 0 {
+            panic!(err_msg!("min_digits should be greater than zero!"));
+        }
+        self.min_digits = digits;
+        self
+    }
+
+    /// Writes the engineering notation with SI prefixes, e.g. `1.5k` and
+    /// `2.3µ`, instead of exponents between _10<sup>-30</sup>_ (`q`) and
+    /// _10<sup>30</sup>_ (`Q`). Micro is written as `µ` (U+00B5).
+    ///
+    /// Numbers out of this range keep their exponent.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_si_prefix(mut self, si_prefix: bool) -> Self {
+        self.set_flag(SI_PREFIX, si_prefix);
+        self
+    }
+
+    /// Returns `true` if the exponent symbol is set to `E`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_uppercase(&self) -> bool {
+        self.symbol == b'E'
+    }
+
+    /// Returns `true` if the exponent symbol is set to `e`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_lowercase(&self) -> bool {
+        self.symbol == b'e'
+    }
+
+    /// Returns `true` if non-negative exponents are always written with `+`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_always_signed(&self) -> bool {
+        self.flags & ALWAYS_SIGNED != 0
+    }
+
+    /// Get the minimum number of exponent digits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn min_digits(&self) -> u8 {
+        self.min_digits
+    }
+
+    /// Returns `true` if the engineering notation is written with SI
+    /// prefixes.
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_si_prefix(&self) -> bool {
+        self.flags & SI_PREFIX != 0
+    }
+
+    /// This style with the symbol and the sign of a notation where it
+    /// doesn't set them itself.
+    #[inline(always)]
+    pub(crate) const fn or_notation(mut self, uppercase: bool, plus: bool) -> Self {
+        if self.symbol == 0 {
+            self.symbol = if uppercase { b'E' } else { b'e' };
+        }
+        if plus {
+            self.flags |= ALWAYS_SIGNED;
+        }
+        self
+    }
+
+    /// The exponent symbol, `e` unless set to `E`.
+    #[inline(always)]
+    pub(crate) const fn symbol(&self) -> &'static str {
+        if self.is_uppercase() {
+            "E"
+        } else {
+            "e"
+        }
+    }
+
+    /// The SI prefix of the power _10<sup>exp</sup>_, if it has one.
+    #[inline]
+    pub(crate) const fn si_prefix(exp: i32) -> Option<&'static str> {
+        if exp % 3 != 0 || exp < -30 || exp > 30 {
+            return None;
+        }
+        Some(SI_PREFIXES[(exp / 3 + 10) as usize])
+    }
+
+    #[inline(always)]
+    const fn set_flag(&mut self, flag: u8, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Display for ExponentStyle {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Exponent(")?;
+        match self.symbol {
+            0 => {}
+            symbol => write!(f, "{}", symbol as char)?,
+        }
+        if self.is_always_signed() {
+            write!(f, "+")?;
+        }
+        write!(f, "{}", self.min_digits)?;
+        if self.is_si_prefix() {
+            write!(f, ",SI")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Debug for ExponentStyle {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+assert_eq_size!(ExponentStyle, [u8; 3]);
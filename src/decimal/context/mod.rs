@@ -1,8 +1,10 @@
 //! Context of fastnum.
 
+mod exponent_style;
 mod rounding_mode;
 mod signal_traps;
 
+pub use exponent_style::ExponentStyle;
 pub use rounding_mode::RoundingMode;
 pub use signal_traps::SignalsTraps;
 
@@ -88,6 +90,9 @@ pub struct FormatStyle {
     /// the format notation.
     pub notation: Notation,
 
+    /// the way exponents are written.
+    exponent_style: ExponentStyle,
+
     /// maximum leading zeros allowed in full-scale format.
     /// e.g. it's 3 zeros for `0.0001` and `5.0002`.
     ///
@@ -111,6 +116,7 @@ impl FormatStyle {
     /// the default value of `FormatStyle`.
     pub const DEFAULT: Self = Self {
         notation: Notation::DEFAULT,
+        exponent_style: ExponentStyle::DEFAULT,
         leading_zero_threshold: EXPONENTIAL_FORMAT_LEADING_ZERO_THRESHOLD,
         trailing_zero_threshold: EXPONENTIAL_FORMAT_TRAILING_ZERO_THRESHOLD,
     };
@@ -120,6 +126,11 @@ impl FormatStyle {
         Self::DEFAULT
     }
 
+    /// getter for `.exponent_style`
+    pub const fn exponent_style(&self) -> ExponentStyle {
+        self.exponent_style
+    }
+
     /// getter for `.leading_zero_threshold`
     pub const fn leading_zero_threshold(&self) -> u32 {
         self.leading_zero_threshold
@@ -130,6 +141,12 @@ impl FormatStyle {
         self.trailing_zero_threshold
     }
 
+    /// setter for `.exponent_style`
+    pub const fn set_exponent_style(&mut self, val: ExponentStyle) -> &mut Self {
+        self.exponent_style = val;
+        self
+    }
+
     /// setter for `.leading_zero_threshold`
     pub const fn set_leading_zero_threshold(&mut self, val: u32) -> &mut Self {
         if val == 0 {
@@ -157,6 +174,9 @@ impl Display for FormatStyle {
                    self.trailing_zero_threshold
                   )?;
         }
+        if self.exponent_style != ExponentStyle::DEFAULT {
+            write!(f, " {}", self.exponent_style)?;
+        }
         Ok(())
     }
 }
@@ -289,6 +309,28 @@ impl Context {
         self
     }
 
+    /// Get [ExponentStyle] of given `Context`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn exponent_style(&self) -> ExponentStyle {
+        self.format_style.exponent_style
+    }
+
+    /// Set [ExponentStyle] of given `Context`.
+    #[inline(always)]
+    pub const fn set_exponent_style(&mut self, val: ExponentStyle) -> &mut Self {
+        self.format_style.set_exponent_style(val);
+        self
+    }
+
+    /// Apply the given [ExponentStyle] to the `Context`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_exponent_style(mut self, val: ExponentStyle) -> Self {
+        self.set_exponent_style(val);
+        self
+    }
+
     /// Get "leading zero threshold" of given `Context`.
     #[must_use]
     #[inline(always)]
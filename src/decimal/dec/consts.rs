@@ -27,6 +27,11 @@ macro_rules! consts_impl {
             /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
             pub const EPSILON: Self = Self::new(UInt::ONE, ControlBlock::basic(Intrinsics::<N>::MAX_CLENGTH as i16 - 1, Sign::Plus), Context::DEFAULT);
 
+            /// The length of the longest string this decimal type is formatted to
+            /// by [`format_into`](Self::format_into): the sign, every digit of the
+            /// coefficient and up to 32'768 zeros of the plain notation.
+            pub const MAX_STR_LEN: usize = 1 + Intrinsics::<N>::MAX_CLENGTH as usize + E_LIMIT as usize;
+
             consts_impl!(CONSTS ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
 
             /// The value of `0.5` represented by this decimal type.
@@ -90,6 +95,32 @@ macro_rules! consts_impl {
 
             /// log<sub>2</sub>(10).
             pub const LOG2_10: Self = Consts::<N>::LOG2_10.round_extra_precision();
+
+            /// 1/sqrt(π).
+            pub const FRAC_1_SQRT_PI: Self = Consts::<N>::FRAC_1_SQRT_PI.round_extra_precision();
+
+            /// sqrt(3).
+            pub const SQRT_3: Self = Consts::<N>::SQRT_3.round_extra_precision();
+
+            /// sqrt(5).
+            pub const SQRT_5: Self = Consts::<N>::SQRT_5.round_extra_precision();
+
+            /// The golden ratio (φ)
+            ///
+            /// Equal to (1 + sqrt(5))/2.
+            pub const PHI: Self = Consts::<N>::PHI.round_extra_precision();
+
+            /// ln(3).
+            pub const LN_3: Self = Consts::<N>::LN_3.round_extra_precision();
+
+            /// The Euler–Mascheroni constant (γ).
+            pub const EULER_GAMMA: Self = Consts::<N>::EULER_GAMMA.round_extra_precision();
+
+            /// Catalan's constant (G).
+            pub const CATALAN: Self = Consts::<N>::CATALAN.round_extra_precision();
+
+            /// Apéry's constant ζ(3).
+            pub const APERY: Self = Consts::<N>::APERY.round_extra_precision();
         }
     };
     (CONSTS $($name: ident $num: literal), *) => {
@@ -1,32 +1,635 @@
-use core::{cmp::Ordering, fmt, fmt::Write, ops::Neg};
+use core::{
+    fmt,
+    fmt::{Alignment, Write},
+};
 
-use crate::decimal::round::round_pair_digits;
 #[cfg(not(feature = "numtraits"))]
 use crate::decimal::utils::cast::ToPrimitive;
+use crate::decimal::{dec::scale, round::round_pair_digits};
 #[cfg(feature = "numtraits")]
 use num_traits::ToPrimitive;
 
-use crate::decimal::{Context, RoundingMode, Sign};
+use crate::{
+    decimal::{
+        Decimal, ExponentStyle, FormatStyle, FractionStyle, Language, Notation, NumberFormat,
+        RoundingMode, Sign, SignPlacement, WordsStyle,
+    },
+    int::{math::div_rem, radix, UInt},
+    utils::err_msg,
+};
 
 use crate::config::*;
 
+/// Most parts any notation is made of, e.g. `1` `.` `2345` `000` `E` `-7`.
+const MAX_PARTS: usize = 6;
+
+const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+const DIGIT_CHARS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Piece of a formatted decimal number, written without intermediate
+/// allocation.
+#[derive(Copy, Clone)]
+pub(crate) enum Part<'a> {
+    /// Given number of zero digits.
+    Zero(usize),
+
+    /// Exponent, with an explicit `+` for non-negative values if `plus` is
+    /// set, padded with zeros to at least `digits` digits.
+    Exp { value: i32, plus: bool, digits: u8 },
+
+    /// ASCII digits or symbols.
+    Copy(&'a [u8]),
+}
+
+impl Part<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        match *self {
+            Part::Zero(n) => n,
+            Part::Exp {
+                value,
+                plus,
+                digits,
+            } => {
+                let sign = (value < 0 || plus) as usize;
+                let len = value.unsigned_abs().checked_ilog10().unwrap_or(0) as usize + 1;
+                sign + len.max(digits as usize)
+            }
+            Part::Copy(bytes) => bytes.len(),
+        }
+    }
+
+    #[inline]
+    fn write<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match *self {
+            Part::Zero(mut n) => {
+                while n > 0 {
+                    let chunk = n.min(ZEROS.len());
+                    w.write_str(&ZEROS[..chunk])?;
+                    n -= chunk;
+                }
+                Ok(())
+            }
+            Part::Exp {
+                value,
+                plus,
+                digits,
+            } => {
+                if value < 0 {
+                    w.write_char('-')?;
+                } else if plus {
+                    w.write_char('+')?;
+                }
+                let width = digits as usize;
+                write!(w, "{:0width$}", value.unsigned_abs())
+            }
+            // Only ASCII is ever copied.
+            Part::Copy(bytes) => w.write_str(core::str::from_utf8(bytes).map_err(|_| fmt::Error)?),
+        }
+    }
+}
+
+/// Formatted decimal number without its sign.
+pub(crate) struct Parts<'a> {
+    parts: [Part<'a>; MAX_PARTS],
+    count: usize,
+}
+
+impl<'a> Parts<'a> {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            parts: [Part::Zero(0); MAX_PARTS],
+            count: 0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, part: Part<'a>) {
+        self.parts[self.count] = part;
+        self.count += 1;
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Part<'a>] {
+        &self.parts[..self.count]
+    }
+
+    /// Number of characters.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.as_slice().iter().map(Part::len).sum()
+    }
+
+    #[inline]
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> fmt::Result {
+        for part in self.as_slice() {
+            part.write(w)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writer into a fixed byte buffer, failing once it is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes the decimal digits of the coefficient into the stack buffer `buf`,
+/// which holds 20 digits per limb.
+#[inline]
+pub(crate) fn ascii_digits<'a, const N: usize>(
+    digits: &UInt<N>,
+    buf: &'a mut [[u8; 20]; N],
+) -> &'a mut [u8] {
+    let buf = buf.as_flattened_mut();
+    let len = match radix::to_str_radix_into(digits, buf, 10) {
+        Some(s) => s.len(),
+        None => unreachable!(),
+    };
+    &mut buf[..len]
+}
+
+/// Formats `d` in the given `style` into the beginning of `buf`.
+pub(crate) fn format_into<'a, const N: usize>(
+    d: &Decimal<N>,
+    buf: &'a mut [u8],
+    style: &FormatStyle,
+) -> Result<&'a str, fmt::Error> {
+    let mut w = SliceWriter { buf, len: 0 };
+
+    if d.is_nan() {
+        w.write_str("NaN")?;
+    } else if d.is_infinite() {
+        write!(w, "{}Inf", d.sign())?;
+    } else {
+        let mut digits = [[0; 20]; N];
+        let digits = ascii_digits(&d.digits, &mut digits);
+        let parts = format_notation(
+            style,
+            digits,
+            d.cb.get_scale(),
+            d.sign(),
+            d.cb.get_rounding_mode(),
+            None,
+        );
+
+        if d.is_sign_negative() {
+            w.write_str("-")?;
+        }
+        parts.write(&mut w)?;
+    }
+
+    let SliceWriter { buf, len } = w;
+
+    // Only ASCII has been written.
+    core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+/// Writes `NaN` or a signed `Inf` to the formatter, padded to its width like
+/// any finite value.
+///
+/// As for `f64`, `NaN` is never signed.
+pub(crate) fn pad_special<const N: usize>(d: &Decimal<N>, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut parts = Parts::new();
+
+    if d.is_nan() {
+        parts.push(Part::Copy(b"NaN"));
+        pad_integral(f, "", &parts)
+    } else {
+        parts.push(Part::Copy(b"Inf"));
+        pad_integral(f, determine_sign(f, d.sign()), &parts)
+    }
+}
+
+/// Sign to prefix a value with, `+` is written for positive values only if
+/// the formatter asks for it.
+pub(crate) fn determine_sign(f: &fmt::Formatter, sign: Sign) -> &'static str {
+    match sign {
+        Sign::Minus => "-",
+        Sign::Plus if f.sign_plus() => "+",
+        Sign::Plus => "",
+    }
+}
+
+/// Writes the `sign` and the `parts` to the formatter, padded to its width
+/// like an integer.
+pub(crate) fn pad_integral(f: &mut fmt::Formatter, sign: &str, parts: &Parts) -> fmt::Result {
+    let width = sign.len() + parts.len();
+    let padding = match f.width() {
+        Some(min) if width < min => min - width,
+        _ => 0,
+    };
+
+    if padding == 0 {
+        f.write_str(sign)?;
+        return parts.write(f);
+    }
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        Part::Zero(padding).write(f)?;
+        return parts.write(f);
+    }
+
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    parts.write(f)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Writes `d` in the locale-specific format `nf` to the formatter, padded to
+/// its width.
+pub(crate) fn pad_number_format<const N: usize>(
+    d: &Decimal<N>,
+    nf: &NumberFormat,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    let Some(min) = f.width() else {
+        return write_number_format(d, nf, f);
+    };
+
+    let mut counter = CharCounter(0);
+    write_number_format(d, nf, &mut counter)?;
+
+    let padding = min.saturating_sub(counter.0);
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    write_number_format(d, nf, f)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Writes `d` in the locale-specific format `nf`.
+pub(crate) fn write_number_format<W: Write, const N: usize>(
+    d: &Decimal<N>,
+    nf: &NumberFormat,
+    w: &mut W,
+) -> fmt::Result {
+    if d.is_nan() {
+        return w.write_str("NaN");
+    }
+
+    let mut buf = [[0; 20]; N];
+    let plain = if d.is_infinite() {
+        None
+    } else {
+        Some(PlainDigits::new(d, nf.max_fraction_digits(), &mut buf))
+    };
+
+    // A negative number rounded to zero is written without its sign.
+    let negative = d.is_sign_negative() && !matches!(&plain, Some(plain) if plain.is_zero());
+    let (prefix, suffix) = match nf.sign_placement() {
+        SignPlacement::Leading if negative => ("-", ""),
+        SignPlacement::LeadingAlways if negative => ("-", ""),
+        SignPlacement::LeadingAlways => ("+", ""),
+        SignPlacement::Trailing if negative => ("", "-"),
+        SignPlacement::Parentheses if negative => ("(", ")"),
+        _ => ("", ""),
+    };
+
+    w.write_str(prefix)?;
+
+    match plain {
+        Some(plain) => write_grouped_plain(&plain, nf, w)?,
+        None => w.write_str("Inf")?,
+    }
+
+    w.write_str(suffix)
+}
+
+/// Digits of a finite decimal in plain notation: the integer digits followed
+/// by zeros, and zeros followed by the fraction digits.
+struct PlainDigits<'a> {
+    integer_digits: &'a [u8],
+    integer_zeros: usize,
+    fraction_zeros: usize,
+    fraction_digits: &'a [u8],
+}
+
+impl<'a> PlainDigits<'a> {
+    /// Rounds `d` to at most `max_fraction_digits` without raising the
+    /// signals to the context traps and splits its digits, written into
+    /// `buf`.
+    fn new<const N: usize>(
+        d: &Decimal<N>,
+        max_fraction_digits: Option<u16>,
+        buf: &'a mut [[u8; 20]; N],
+    ) -> Self {
+        let mut d = *d;
+
+        if let Some(max) = max_fraction_digits {
+            if d.cb.get_scale() > 0 && d.cb.get_scale() as u16 > max {
+                scale::rescale(&mut d, max as i16);
+                d = d.round_extra_precision();
+            }
+        }
+
+        let digits = ascii_digits(&d.digits, buf);
+        let scale = d.cb.get_scale() as i32;
+        let digit_count = digits.len() as i32;
+
+        if scale <= 0 {
+            Self {
+                integer_zeros: if digits == b"0" { 0 } else { -scale as usize },
+                integer_digits: digits,
+                fraction_zeros: 0,
+                fraction_digits: b"",
+            }
+        } else if scale < digit_count {
+            let (integer_digits, fraction_digits) = digits.split_at((digit_count - scale) as usize);
+            Self {
+                integer_digits,
+                integer_zeros: 0,
+                fraction_zeros: 0,
+                fraction_digits,
+            }
+        } else {
+            Self {
+                integer_digits: b"0",
+                integer_zeros: 0,
+                fraction_zeros: (scale - digit_count) as usize,
+                fraction_digits: digits,
+            }
+        }
+    }
+
+    /// Number of fraction digits.
+    #[inline]
+    fn fraction_len(&self) -> usize {
+        self.fraction_zeros + self.fraction_digits.len()
+    }
+
+    /// Returns `true` if all digits are zeros.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.integer_digits.iter().chain(self.fraction_digits).all(|&c| c == b'0')
+    }
+}
+
+fn write_grouped_plain<W: Write>(plain: &PlainDigits, nf: &NumberFormat, w: &mut W) -> fmt::Result {
+    write_grouped_integer(plain.integer_digits, plain.integer_zeros, nf, w)?;
+
+    let fraction_len = plain.fraction_len();
+    let fraction_padding = (nf.min_fraction_digits() as usize).saturating_sub(fraction_len);
+
+    if fraction_len + fraction_padding > 0 {
+        w.write_char(nf.decimal_separator())?;
+        Part::Zero(plain.fraction_zeros).write(w)?;
+        Part::Copy(plain.fraction_digits).write(w)?;
+        Part::Zero(fraction_padding).write(w)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the integer `digits` followed by `zeros` zero digits, grouped as
+/// set in `nf`.
+fn write_grouped_integer<W: Write>(
+    digits: &[u8],
+    zeros: usize,
+    nf: &NumberFormat,
+    w: &mut W,
+) -> fmt::Result {
+    let Some(separator) = nf.grouping_separator() else {
+        Part::Copy(digits).write(w)?;
+        return Part::Zero(zeros).write(w);
+    };
+
+    let (primary, secondary) = nf.group_sizes();
+    let (primary, secondary) = (primary as usize, secondary as usize);
+    let len = digits.len() + zeros;
+
+    // the leading group holds what is left over by the full groups
+    let mut start = 0;
+    let mut end = match len.checked_sub(primary) {
+        Some(rest @ 1..) => match rest % secondary {
+            0 => secondary,
+            leading => leading,
+        },
+        _ => len,
+    };
+
+    loop {
+        if start < digits.len() {
+            Part::Copy(&digits[start..end.min(digits.len())]).write(w)?;
+        }
+        if end > digits.len() {
+            Part::Zero(end - start.max(digits.len())).write(w)?;
+        }
+
+        if end == len {
+            return Ok(());
+        }
+
+        w.write_char(separator)?;
+        start = end;
+        end += if len - end > primary {
+            secondary
+        } else {
+            primary
+        };
+    }
+}
+
+/// Spells out `d` in words in the given `style`, see [`Decimal::to_words`].
+pub(crate) fn write_words<W: Write, L: Language, const N: usize>(
+    d: &Decimal<N>,
+    style: &WordsStyle<L>,
+    w: &mut W,
+) -> fmt::Result {
+    let language = style.language();
+
+    if d.is_nan() {
+        return w.write_str(language.nan());
+    }
+
+    if d.is_sign_negative() {
+        w.write_str(language.minus())?;
+        w.write_char(' ')?;
+    }
+
+    if d.is_infinite() {
+        return w.write_str(language.infinity());
+    }
+
+    let mut buf = [[0; 20]; N];
+    let plain = PlainDigits::new(d, style.fraction_digits(), &mut buf);
+
+    if plain.integer_zeros == 0 {
+        language.write_cardinal(plain.integer_digits, w)?;
+    } else {
+        let mut integer = plain.integer_digits.to_vec();
+        integer.resize(integer.len() + plain.integer_zeros, b'0');
+        language.write_cardinal(&integer, w)?;
+    }
+
+    let fraction_len = plain.fraction_len();
+    let fraction_padding =
+        (style.fraction_digits().unwrap_or(0) as usize).saturating_sub(fraction_len);
+
+    if fraction_len + fraction_padding == 0 {
+        return Ok(());
+    }
+
+    match style.fraction_style() {
+        FractionStyle::Point => {
+            w.write_char(' ')?;
+            w.write_str(language.point())?;
+
+            let digits = core::iter::repeat_n(0, plain.fraction_zeros)
+                .chain(plain.fraction_digits.iter().map(|&c| c - b'0'))
+                .chain(core::iter::repeat_n(0, fraction_padding));
+
+            for digit in digits {
+                w.write_char(' ')?;
+                language.write_digit(digit, w)?;
+            }
+        }
+        FractionStyle::Ratio => {
+            w.write_char(' ')?;
+            w.write_str(language.and())?;
+            w.write_char(' ')?;
+
+            Part::Zero(plain.fraction_zeros).write(w)?;
+            Part::Copy(plain.fraction_digits).write(w)?;
+            Part::Zero(fraction_padding).write(w)?;
+            w.write_str("/1")?;
+            Part::Zero(fraction_len + fraction_padding).write(w)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writer counting the characters without writing them.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+pub(crate) fn format_notation<'a>(
+    style: &FormatStyle,
+    digits: &'a mut [u8],
+    scale: i16,
+    sign: Sign,
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+) -> Parts<'a> {
+    let exp_style = &style.exponent_style();
+
+    match style.notation {
+        Notation::Unspecified => format(style, digits, scale, sign, rounding_mode, precision),
+        Notation::Scientific => {
+            scientific_notation(digits, scale, sign, rounding_mode, precision, exp_style)
+        }
+        Notation::FullScale => format_full_scale(
+            false,
+            digits,
+            scale,
+            sign,
+            rounding_mode,
+            precision,
+            exp_style,
+        ),
+        Notation::Plain => format_full_scale(
+            true,
+            digits,
+            scale,
+            sign,
+            rounding_mode,
+            precision,
+            exp_style,
+        ),
+    }
+}
+
 pub(crate) fn write_scientific_notation<W: Write>(
-    digits: String,
+    digits: &mut [u8],
     scale: i16,
+    exp_style: &ExponentStyle,
     w: &mut W,
 ) -> fmt::Result {
-    let (first_digit, remaining_digits) = digits.as_str().split_at(1);
-    w.write_str(first_digit)?;
-    if !remaining_digits.is_empty() {
-        w.write_str(".")?;
-        w.write_str(remaining_digits)?;
-    }
-    write!(w, "e{}", remaining_digits.len() as i32 - scale as i32)
+    scientific_notation(
+        digits,
+        scale,
+        Sign::Plus,
+        RoundingMode::default(),
+        None,
+        exp_style,
+    )
+    .write(w)
 }
 
+fn scientific_notation<'a>(
+    digits: &'a mut [u8],
+    scale: i16,
+    sign: Sign,
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
+    // zero is written as `0e0` whatever its scale
+    let scale = if digits == b"0" { 0 } else { scale };
+
+    exponential(
+        digits,
+        scale,
+        sign,
+        rounding_mode,
+        precision,
+        &exp_style.or_notation(false, false),
+    )
+}
+
+/// Writes `digits` in engineering notation, with an SI prefix instead of
+/// the exponent if `exp_style` asks for it and there is one.
 pub(crate) fn write_engineering_notation<W: Write>(
-    digits: String,
+    digits: &str,
     scale: i16,
+    exp_style: &ExponentStyle,
     out: &mut W,
 ) -> fmt::Result {
     let digit_count = digits.len();
@@ -43,9 +646,9 @@ pub(crate) fn write_engineering_notation<W: Write>(
     // handle adding zero padding
     if let Some(padding_zero_count) = shift_amount.checked_sub(digits.len()) {
         let zeros = &"000"[..padding_zero_count];
-        out.write_str(&digits)?;
+        out.write_str(digits)?;
         out.write_str(zeros)?;
-        return write!(out, "e{}", exp);
+        return write_engineering_exponent(exp, exp_style, out);
     }
 
     let (head, rest) = digits.split_at(shift_amount);
@@ -58,31 +661,54 @@ pub(crate) fn write_engineering_notation<W: Write>(
         out.write_str(rest)?;
     }
 
-    write!(out, "e{}", exp)
+    write_engineering_exponent(exp, exp_style, out)
+}
+
+fn write_engineering_exponent<W: Write>(
+    exp: i32,
+    exp_style: &ExponentStyle,
+    out: &mut W,
+) -> fmt::Result {
+    if exp_style.is_si_prefix() {
+        if let Some(prefix) = ExponentStyle::si_prefix(exp) {
+            return out.write_str(prefix);
+        }
+    }
+
+    let mut parts = Parts::new();
+    push_exponent(&mut parts, &exp_style.or_notation(false, false), exp);
+    parts.write(out)
 }
 
-pub(crate) fn format(
-    ctx: Context,
-    digits: String,
+/// Pushes the exponent `value` written in `exp_style`.
+#[inline]
+fn push_exponent(parts: &mut Parts, exp_style: &ExponentStyle, value: i32) {
+    parts.push(Part::Copy(exp_style.symbol().as_bytes()));
+    parts.push(Part::Exp {
+        value,
+        plus: exp_style.is_always_signed(),
+        digits: exp_style.min_digits(),
+    });
+}
+
+pub(crate) fn format<'a>(
+    style: &FormatStyle,
+    digits: &'a mut [u8],
     scale: i16,
     sign: Sign,
-    f: &mut fmt::Formatter,
-) -> fmt::Result {
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+) -> Parts<'a> {
     // number of zeros between the most significant digit and decimal point
-    // this ignores scientific-formatting if precision is requested
     let leading_zeros =
-        f.precision()
-         .map(|_| 0)
-         .unwrap_or_else(|| {
-             scale.to_u64()
-                  .and_then(|scale| scale.checked_sub(digits.len() as u64))
-                  .unwrap_or(0)
-         });
+        scale.to_u64()
+             .and_then(|scale| scale.checked_sub(digits.len() as u64))
+             .unwrap_or(0);
 
     // number of zeros between last significant digit and decimal point
     // this ignores scientific-formatting if precision is requested
     let trailing_zeros =
-        f.precision()
+        precision
          .map(|_| 0)
          .unwrap_or_else(|| {
              scale.checked_neg()
@@ -90,191 +716,265 @@ pub(crate) fn format(
                   .unwrap_or(0)
          });
 
-    let leading_zero_threshold = ctx.leading_zero_threshold() as u64;
-    let trailing_zero_threshold = ctx.trailing_zero_threshold() as u64;
+    let leading_zero_threshold = style.leading_zero_threshold() as u64;
+    let trailing_zero_threshold = style.trailing_zero_threshold() as u64;
+    let exp_style = &style.exponent_style();
 
     // use exponential form if decimal point is outside
     // the upper and lower thresholds of the decimal
     if leading_zero_threshold < leading_zeros {
-        format_exponential(digits, scale, sign, f, "E")
+        format_exponential(
+            digits,
+            scale,
+            sign,
+            rounding_mode,
+            precision,
+            &exp_style.or_notation(true, true),
+        )
     } else if trailing_zero_threshold < trailing_zeros {
         // non-scientific notation
-        format_dotless_exponential(digits, scale, sign, f, "e")
+        format_dotless_exponential(digits, scale, &exp_style.or_notation(false, true))
     } else {
-        format_full_scale(false, digits, scale, sign, f)
+        format_full_scale(
+            false,
+            digits,
+            scale,
+            sign,
+            rounding_mode,
+            precision,
+            exp_style,
+        )
     }
 }
 
-pub(crate) fn format_exponential(
-    digits: String,
+pub(crate) fn format_exponential<'a>(
+    digits: &'a mut [u8],
     scale: i16,
     sign: Sign,
-    f: &mut fmt::Formatter,
-    e_symbol: &str,
-) -> fmt::Result {
-    let exp = (scale as i128).neg();
-    format_exponential_be_ascii_digits(digits, exp, sign, f, e_symbol)
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
+    exponential(
+        digits,
+        scale,
+        sign,
+        rounding_mode,
+        precision,
+        &exp_style.or_notation(false, true),
+    )
 }
 
-fn format_dotless_exponential(
-    mut digits: String,
+fn exponential<'a>(
+    digits: &'a mut [u8],
     scale: i16,
     sign: Sign,
-    f: &mut fmt::Formatter,
-    e_symbol: &str,
-) -> fmt::Result {
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
+    let mut exp = -(scale as i32);
+    let mut digits = digits;
+
+    // how many zeros to pad at the end of the decimal
+    let mut extra_trailing_zero_count = 0;
+
+    if let Some(prec) = precision {
+        // 'prec' is number of digits after the decimal point
+        let total_prec = prec + 1;
+        let digit_count = digits.len();
+
+        if total_prec < digit_count {
+            // round to smaller precision
+            if apply_rounding_to_ascii_digits(digits, &mut exp, sign, rounding_mode, total_prec) {
+                digits[0] = b'1';
+            }
+            digits = &mut digits[..total_prec];
+        } else {
+            // increase number of zeros to add to end of digits
+            extra_trailing_zero_count = total_prec - digit_count;
+        }
+    }
+
+    let exponent = digits.len() as i32 + exp - 1;
+
+    let mut parts = Parts::new();
+    let (first_digit, remaining_digits) = digits.split_at(1);
+
+    parts.push(Part::Copy(first_digit));
+
+    // only add decimal point if there is more than 1 decimal digit
+    if !remaining_digits.is_empty() || extra_trailing_zero_count > 0 {
+        parts.push(Part::Copy(b"."));
+        parts.push(Part::Copy(remaining_digits));
+        parts.push(Part::Zero(extra_trailing_zero_count));
+    }
+
+    // always print exponent in exponential mode
+    push_exponent(&mut parts, exp_style, exponent);
+
+    parts
+}
+
+fn format_dotless_exponential<'a>(
+    digits: &'a [u8],
+    scale: i16,
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
     debug_assert!(scale <= 0);
-    write!(digits, "{}{:+}", e_symbol, -scale)?;
-    let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &digits)
+
+    let mut parts = Parts::new();
+    parts.push(Part::Copy(digits));
+    push_exponent(&mut parts, exp_style, -(scale as i32));
+    parts
 }
 
-pub(crate) fn format_full_scale(
+pub(crate) fn format_full_scale<'a>(
     plain: bool,
-    digits: String,
+    digits: &'a mut [u8],
     scale: i16,
     sign: Sign,
-    f: &mut fmt::Formatter,
-) -> fmt::Result {
-    let mut digits = digits.into_bytes();
-    let mut exp = (scale as i128).neg();
-
+    rounding_mode: RoundingMode,
+    precision: Option<usize>,
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
     if scale <= 0 {
         // formatting an integer value (add trailing zeros to the right)
-        zero_right_pad_integer_ascii_digits(plain, &mut digits, &mut exp, f.precision());
-    } else {
-        let scale = scale as u64;
-        // no-precision behaves the same as precision matching scale (i.e. no padding or
-        // rounding)
-        let prec = f
-            .precision()
-            .and_then(|prec| prec.to_u64())
-            .unwrap_or(scale);
-
-        if scale < digits.len() as u64 {
-            // format both integer and fractional digits (always 'trim' to precision)
-            trim_ascii_digits(&mut digits, scale, sign, prec, &mut exp);
-        } else {
-            // format only fractional digits
-            shift_or_trim_fractional_digits(&mut digits, scale, sign, prec, &mut exp);
-        }
-        // never print exp when in this branch
-        exp = 0;
+        return zero_right_pad_integer_ascii_digits(
+            plain,
+            digits,
+            -(scale as i32),
+            precision,
+            exp_style,
+        );
     }
 
-    // move digits back into String form
-    let mut buf = String::from_utf8(digits).unwrap();
+    let scale = scale as usize;
+    // no-precision behaves the same as precision matching scale (i.e. no padding or
+    // rounding)
+    let prec = precision.unwrap_or(scale);
 
-    // add exp part to buffer (if not zero)
-    if exp != 0 {
-        write!(buf, "e{:+}", exp)?;
+    // never print exp when in this branch
+    if scale < digits.len() {
+        // format both integer and fractional digits (always 'trim' to precision)
+        trim_ascii_digits(digits, scale, sign, rounding_mode, prec)
+    } else {
+        // format only fractional digits
+        shift_or_trim_fractional_digits(digits, scale, sign, rounding_mode, prec)
     }
-
-    // write buffer to formatter
-    let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &buf)
 }
 
-/// Fill appropriate number of zeros and decimal point into Vec of (ascii/utf-8)
-/// digits
+/// Fill appropriate number of zeros and decimal point after the integer
+/// (ascii/utf-8) digits.
 ///
-/// Exponent is set to zero if zeros were added
-fn zero_right_pad_integer_ascii_digits(
+/// The exponent is only kept if no zeros were added.
+fn zero_right_pad_integer_ascii_digits<'a>(
     plain: bool,
-    digits: &mut Vec<u8>,
-    exp: &mut i128,
+    digits: &'a [u8],
+    exp: i32,
     precision: Option<usize>,
-) {
-    debug_assert!(*exp >= 0);
+    exp_style: &ExponentStyle,
+) -> Parts<'a> {
+    debug_assert!(exp >= 0);
 
-    let trailing_zero_count = match exp.to_usize() {
-        Some(n) => {
-            n
-        },
-        None => {
-            return;
-        }
-    };
+    let mut parts = Parts::new();
+    parts.push(Part::Copy(digits));
 
-    if ! plain { 
+    let trailing_zero_count = exp as usize;
+
+    let pad = if plain {
+        true
+    } else {
         let total_additional_zeros = trailing_zero_count.saturating_add(precision.unwrap_or(0));
-        if total_additional_zeros > (FMT_MAX_INTEGER_PADDING as usize) {
-            return;
-        }
-    }
+        total_additional_zeros <= (FMT_MAX_INTEGER_PADDING as usize)
+            && (precision.is_some() || trailing_zero_count < 20)
+    };
 
-    let digits_len = digits.len();
+    if pad {
+        parts.push(Part::Zero(trailing_zero_count));
 
-    // requested 'prec' digits of precision after decimal point
-    match precision {
-        Some(prec) => {
-            digits.resize(digits_len + trailing_zero_count, b'0');
-            if prec > 0 {
-                digits.push(b'.');
-                digits.resize(digits_len + prec, b'0');
-            }
-            *exp = 0;
-        },
-        _ => {
-            if plain || trailing_zero_count < 20 {
-                digits.resize(digits_len + trailing_zero_count, b'0');
-                *exp = 0;
-            }
+        // requested 'prec' digits of precision after decimal point
+        if let Some(prec @ 1..) = precision {
+            parts.push(Part::Copy(b"."));
+            parts.push(Part::Zero(prec));
         }
+    } else if exp != 0 {
+        push_exponent(&mut parts, &exp_style.or_notation(false, true), exp);
     }
-}
 
-fn trim_ascii_digits(digits: &mut Vec<u8>, scale: u64, sign: Sign, prec: u64, exp: &mut i128) {
-    let mut digits_len = digits.len();
+    parts
+}
 
+fn trim_ascii_digits(
+    digits: &mut [u8],
+    scale: usize,
+    sign: Sign,
+    rounding_mode: RoundingMode,
+    prec: usize,
+) -> Parts<'_> {
     // there are both integer and fractional digits
-    let mut integer_digit_count = {
-        let digits_len = digits_len as u64;
-        debug_assert!(scale < digits_len);
+    debug_assert!(scale < digits.len());
+    let integer_digit_count = digits.len() - scale;
 
-        (digits_len - scale).to_usize().expect("Number of digits exceeds maximum usize")
-    };
+    let mut parts = Parts::new();
 
     if prec < scale {
-        let prec = prec.to_usize().expect("Precision exceeds maximum usize");
-        if apply_rounding_to_ascii_digits(digits, exp, sign, integer_digit_count + prec) {
-            digits[0] = b'1';
-            integer_digit_count += 1;
-            digits.push(b'0');
+        let mut exp = 0;
+        let carry = apply_rounding_to_ascii_digits(
+            digits,
+            &mut exp,
+            sign,
+            rounding_mode,
+            integer_digit_count + prec,
+        );
+        let (integer_digits, fractional_digits) =
+            digits[..integer_digit_count + prec].split_at(integer_digit_count);
+
+        if carry {
+            // all remaining digits are zeros
+            parts.push(Part::Copy(b"1"));
         }
-    }
+        parts.push(Part::Copy(integer_digits));
 
-    if prec != 0 {
-        digits.insert(integer_digit_count, b'.');
-        digits_len = digits.len();
-    }
+        if prec != 0 {
+            parts.push(Part::Copy(b"."));
+            parts.push(Part::Copy(fractional_digits));
+        }
+    } else {
+        let (integer_digits, fractional_digits) = digits.split_at(integer_digit_count);
 
-    if scale < prec {
-        let trailing_zero_count = (prec - scale).to_usize().expect("Too Big");
+        parts.push(Part::Copy(integer_digits));
 
-        // precision required beyond scale
-        digits.resize(digits_len + trailing_zero_count, b'0');
+        if prec != 0 {
+            parts.push(Part::Copy(b"."));
+            parts.push(Part::Copy(fractional_digits));
+            // precision required beyond scale
+            parts.push(Part::Zero(prec - scale));
+        }
     }
+
+    parts
 }
 
 fn shift_or_trim_fractional_digits(
-    digits: &mut Vec<u8>,
-    scale: u64,
+    digits: &mut [u8],
+    scale: usize,
     sign: Sign,
-    prec: u64,
-    exp: &mut i128,
-) {
-    debug_assert!(scale >= digits.len() as u64);
+    rounding_mode: RoundingMode,
+    prec: usize,
+) -> Parts<'_> {
+    debug_assert!(scale >= digits.len());
     // there are no integer digits
-    let leading_zeros = scale - digits.len() as u64;
+    let leading_zeros = scale - digits.len();
+
+    let mut parts = Parts::new();
 
     match prec.checked_sub(leading_zeros) {
         None => {
-            digits.clear();
-            digits.push(b'0');
+            parts.push(Part::Copy(b"0"));
             if prec > 0 {
-                digits.push(b'.');
-                digits.resize(2 + prec as usize, b'0');
+                parts.push(Part::Copy(b"."));
+                parts.push(Part::Zero(prec));
             }
         }
         Some(0) => {
@@ -282,112 +982,64 @@ fn shift_or_trim_fractional_digits(
             let insig_digit = digits[0] - b'0';
             let trailing_zeros = digits[1..].iter().all(|&d| d == b'0');
 
-            let rounded_value = round_pair_digits(
-                (0, insig_digit),
-                sign,
-                RoundingMode::default(),
-                trailing_zeros,
-            );
+            let rounded_value =
+                round_pair_digits((0, insig_digit), sign, rounding_mode, trailing_zeros);
 
-            digits.clear();
             if leading_zeros != 0 {
-                digits.push(b'0');
-                digits.push(b'.');
-                digits.resize(1 + leading_zeros as usize, b'0');
+                parts.push(Part::Copy(b"0."));
+                parts.push(Part::Zero(leading_zeros - 1));
             }
-            digits.push(rounded_value + b'0');
+            parts.push(Part::Copy(if rounded_value == 0 { b"0" } else { b"1" }));
         }
         Some(digit_prec) => {
             let mut carry = false;
-            let digit_prec = digit_prec as usize;
-            let mut leading_zeros = leading_zeros
-                .to_usize()
-                .expect("Number of leading zeros exceeds max usize");
             let trailing_zeros = digit_prec.saturating_sub(digits.len());
             if digit_prec < digits.len() {
-                carry = apply_rounding_to_ascii_digits(digits, exp, sign, digit_prec);
+                let mut exp = 0;
+                carry = apply_rounding_to_ascii_digits(
+                    digits,
+                    &mut exp,
+                    sign,
+                    rounding_mode,
+                    digit_prec,
+                );
             }
-            if carry {
-                if prec <= digit_prec as u64 {
-                    digits.extend_from_slice(b"1.");
-                } else {
-                    digits.insert(0, b'1');
-                    leading_zeros = leading_zeros.saturating_sub(1);
-                    digits.extend_from_slice(b"0.");
-                }
+            let digits = &digits[..digit_prec.min(digits.len())];
+
+            if carry && leading_zeros == 0 {
+                // all remaining digits are zeros
+                parts.push(Part::Copy(b"1."));
+                parts.push(Part::Copy(digits));
+            } else if carry {
+                parts.push(Part::Copy(b"0."));
+                parts.push(Part::Zero(leading_zeros - 1));
+                parts.push(Part::Copy(b"1"));
+                parts.push(Part::Copy(digits));
             } else {
-                digits.extend_from_slice(b"0.");
+                parts.push(Part::Copy(b"0."));
+                parts.push(Part::Zero(leading_zeros));
+                parts.push(Part::Copy(digits));
             }
-            digits.resize(digits.len() + leading_zeros, b'0');
-            digits.rotate_right(leading_zeros + 2);
 
             // add any extra trailing zeros
-            digits.resize(digits.len() + trailing_zeros, b'0');
+            parts.push(Part::Zero(trailing_zeros));
         }
     }
-}
-
-fn format_exponential_be_ascii_digits(
-    digits: String,
-    mut exp: i128,
-    sign: Sign,
-    f: &mut fmt::Formatter,
-    e_symbol: &str,
-) -> fmt::Result {
-    let mut digits = digits.into_bytes();
-
-    // how many zeros to pad at the end of the decimal
-    let mut extra_trailing_zero_count = 0;
 
-    if let Some(prec) = f.precision() {
-        // 'prec' is number of digits after the decimal point
-        let total_prec = prec + 1;
-        let digit_count = digits.len();
-
-        match total_prec.cmp(&digit_count) {
-            Ordering::Equal => {
-                // digit count is one more than precision - do nothing
-            }
-            Ordering::Less => {
-                // round to smaller precision
-                if apply_rounding_to_ascii_digits(&mut digits, &mut exp, sign, total_prec) {
-                    digits[0] = b'1';
-                }
-            }
-            Ordering::Greater => {
-                // increase number of zeros to add to end of digits
-                extra_trailing_zero_count = total_prec - digit_count;
-            }
-        }
-    }
-
-    let needs_decimal_point = digits.len() > 1 || extra_trailing_zero_count > 0;
-
-    let mut abs_int = String::from_utf8(digits).unwrap();
-
-    let exponent = abs_int.len() as i128 + exp - 1;
-
-    if needs_decimal_point {
-        // only add decimal point if there is more than 1 decimal digit
-        abs_int.insert(1, '.');
-    }
-
-    if extra_trailing_zero_count > 0 {
-        abs_int.extend(core::iter::repeat_n('0', extra_trailing_zero_count));
-    }
-
-    // always print exponent in exponential mode
-    write!(abs_int, "{}{:+}", e_symbol, exponent)?;
-
-    let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &abs_int)
+    parts
 }
 
+/// Rounds the ascii digits to the first `prec` of them in place, the rest is
+/// to be dropped by the caller.
+///
+/// Returns `true` if the carry overflowed, the first `prec` digits are zeros
+/// then.
 #[must_use = "must use carry result"]
 fn apply_rounding_to_ascii_digits(
-    ascii_digits: &mut Vec<u8>,
-    exp: &mut i128,
+    ascii_digits: &mut [u8],
+    exp: &mut i32,
     sign: Sign,
+    rounding_mode: RoundingMode,
     prec: usize,
 ) -> bool {
     if ascii_digits.len() < prec {
@@ -395,7 +1047,7 @@ fn apply_rounding_to_ascii_digits(
     }
 
     // shift exp to align with new length of digits
-    *exp += (ascii_digits.len() - prec) as i128;
+    *exp += (ascii_digits.len() - prec) as i32;
 
     // true if all ascii_digits after precision are zeros
     let trailing_zeros = ascii_digits[prec + 1..].iter().all(|&d| d == b'0');
@@ -406,27 +1058,23 @@ fn apply_rounding_to_ascii_digits(
     let rounded_digit = round_pair_digits(
         (sig_digit, insig_digit),
         sign,
-        RoundingMode::default(),
+        rounding_mode,
         trailing_zeros,
     );
 
-    // remove insignificant digits
-    ascii_digits.truncate(prec - 1);
-
-    // push rounded value
+    // replace the last significant digit with the rounded value
     if rounded_digit < 10 {
-        ascii_digits.push(rounded_digit + b'0');
+        ascii_digits[prec - 1] = rounded_digit + b'0';
         return false;
     }
 
     debug_assert_eq!(rounded_digit, 10);
 
-    // push zero and carry-the-one
-    ascii_digits.push(b'0');
+    // set zero and carry-the-one
+    ascii_digits[prec - 1] = b'0';
 
-    // loop through digits in reverse order (skip the 0 we just pushed)
-    let digits = ascii_digits.iter_mut().rev().skip(1);
-    for digit in digits {
+    // loop through digits in reverse order (skip the 0 we just set)
+    for digit in ascii_digits[..prec - 1].iter_mut().rev() {
         if *digit < b'9' {
             // we've carried the one as far as it will go
             *digit += 1;
@@ -443,3 +1091,152 @@ fn apply_rounding_to_ascii_digits(
     *exp += 1;
     true
 }
+
+/// Writes the digits of `d` in the given `radix`, see
+/// [`Decimal::to_str_radix`].
+#[track_caller]
+pub(crate) fn to_str_radix<const N: usize>(d: &Decimal<N>, radix: u32) -> String {
+    if !(2..=36).contains(&radix) {
+        panic!(err_msg!("radix must be in the range 2..=36"));
+    }
+
+    if d.is_nan() {
+        return String::from("NaN");
+    }
+
+    let mut output = String::new();
+
+    if d.is_sign_negative() {
+        output.push('-');
+    }
+
+    if d.is_infinite() {
+        output.push_str("Inf");
+        return output;
+    }
+
+    let scale = d.fractional_digits_count() as i32;
+    let digits = d.digits();
+
+    // `d` is split into the integer `q` and the fraction _r / 10^scale_.
+    let (q, r) = if scale <= 0 {
+        (digits, UInt::ZERO)
+    } else {
+        match UInt::TEN.checked_pow(scale as u32) {
+            Some(pow) => div_rem(digits, pow),
+            None => (UInt::ZERO, digits),
+        }
+    };
+
+    write_integer_radix(&q, scale.min(0).unsigned_abs(), radix, &mut output);
+
+    if !r.is_zero() {
+        output.push('.');
+        write_fraction_radix(&r, scale as u32, radix, &mut output);
+    }
+
+    output
+}
+
+/// Writes the digits of _q · 10<sup>k</sup>_ in the given `radix`.
+fn write_integer_radix<const N: usize>(q: &UInt<N>, k: u32, radix: u32, output: &mut String) {
+    let fits = match UInt::TEN.checked_pow(k) {
+        Some(pow) => q.checked_mul(pow),
+        None => None,
+    };
+
+    if let Some(n) = fits {
+        let mut buf = [[0; 64]; N];
+        if let Some(s) = radix::to_str_radix_into(&n, buf.as_flattened_mut(), radix) {
+            output.push_str(s);
+        }
+        return;
+    }
+
+    // _log2(10) < 3.33, and every digit takes at least one bit.
+    let bits = N * 64 + k as usize * 333 / 100 + 1;
+    let mut x = vec![0; bits.div_ceil(64)];
+    x[..N].copy_from_slice(q.digits());
+
+    let mut buf = vec![0; bits];
+    if let Some(len) = radix::write_mul_pow10(&mut x, k, &mut buf, radix) {
+        output.extend(buf[..len].iter().map(|&c| c as char));
+    }
+}
+
+/// Writes the digits of the fraction _r / 10<sup>scale</sup>_ in the given
+/// `radix`, without the leading `0.`.
+///
+/// The fraction is held in limbs of `19` decimal digits, and every
+/// multiplication by the largest power of the radix fitting into a limb
+/// carries out as many digits of the radix. Unless the expansion terminates,
+/// it's cut off where a unit in the last place gets smaller than a unit in
+/// the last place of the decimal and followed by `…`.
+fn write_fraction_radix<const N: usize>(r: &UInt<N>, scale: u32, radix: u32, output: &mut String) {
+    const POWER: usize = 19;
+    const BASE: u128 = 10u128.pow(POWER as u32);
+
+    let limit = (scale as f64 * core::f64::consts::LN_10 / (radix as f64).ln()).ceil() as usize;
+
+    // Digits of `r` right-aligned to `scale` places and padded with zeros on
+    // the right to whole limbs.
+    let mut buf = [[0; 20]; N];
+    let r = ascii_digits(r, &mut buf);
+    let width = (scale as usize).div_ceil(POWER) * POWER;
+    let mut padded = vec![b'0'; width];
+    padded[scale as usize - r.len()..scale as usize].copy_from_slice(r);
+
+    let mut x: Vec<u64> = padded
+        .rchunks(POWER)
+        .map(|chunk| chunk.iter().fold(0, |v, &c| v * 10 + (c - b'0') as u64))
+        .collect();
+
+    let chunk = u64::MAX.ilog(radix as u64) as usize;
+    let m = (radix as u128).pow(chunk as u32);
+
+    let mut lo = 0;
+    let mut count = 0;
+    let mut carried = [0; 64];
+    let mut truncated = false;
+
+    while count < limit {
+        while lo < x.len() && x[lo] == 0 {
+            lo += 1;
+        }
+
+        if lo == x.len() {
+            break;
+        }
+
+        let mut carry = 0;
+        for limb in x[lo..].iter_mut() {
+            let t = *limb as u128 * m + carry;
+            *limb = (t % BASE) as u64;
+            carry = t / BASE;
+        }
+
+        for c in carried[..chunk].iter_mut().rev() {
+            *c = DIGIT_CHARS[(carry % radix as u128) as usize];
+            carry /= radix as u128;
+        }
+
+        let mut take = chunk.min(limit - count);
+        let terminated = x[lo..].iter().all(|&limb| limb == 0);
+
+        // The last digits of a terminating expansion are padding.
+        if terminated {
+            while take > 0 && carried[take - 1] == b'0' {
+                take -= 1;
+            }
+        }
+
+        truncated = !terminated || carried[take..chunk].iter().any(|&c| c != b'0');
+
+        output.extend(carried[..take].iter().map(|&c| c as char));
+        count += take;
+    }
+
+    if truncated {
+        output.push('…');
+    }
+}
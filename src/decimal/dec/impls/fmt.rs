@@ -1,78 +1,67 @@
 use core::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp};
 
-use crate::decimal::{
-    utils,
-    dec::format,
-    Decimal,
-    Notation,
-};
+use crate::decimal::{dec::format, utils, Decimal};
 
 impl<const N: usize> Display for Decimal<N> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.is_nan() {
-            return write!(f, "NaN");
-        } else if self.is_infinite() {
-            return write!(f, "{}Inf", self.sign());
+        if self.is_nan() || self.is_infinite() {
+            return format::pad_special(self, f);
         }
 
-        let plain;
-
-        use Notation::*;
-        match self.ctx.notation() {
-            Unspecified => {
-                return format::format(
-                    self.ctx,
-                    self.digits.to_str_radix(10),
-                    self.cb.get_scale(),
-                    self.sign(),
-                    f,
-                );
-            },
-            Scientific => {
-                return self.write_scientific_notation(f);
-            },
-            FullScale => {
-                plain = false;
-            },
-            Plain => {
-                plain = true;
-            }
-        }
-
-        format::format_full_scale(
-            plain,
-            self.digits.to_str_radix(10),
+        let mut buf = [[0; 20]; N];
+        let parts = format::format_notation(
+            self.ctx.format_style(),
+            format::ascii_digits(&self.digits, &mut buf),
             self.cb.get_scale(),
             self.sign(),
-            f,
-        )
+            self.cb.get_rounding_mode(),
+            f.precision(),
+        );
+
+        format::pad_integral(f, format::determine_sign(f, self.sign()), &parts)
     }
 }
 
 impl<const N: usize> LowerExp for Decimal<N> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        format::format_exponential(
-            self.digits.to_str_radix(10),
+        if self.is_nan() || self.is_infinite() {
+            return format::pad_special(self, f);
+        }
+
+        let mut buf = [[0; 20]; N];
+        let parts = format::format_exponential(
+            format::ascii_digits(&self.digits, &mut buf),
             self.cb.get_scale(),
             self.sign(),
-            f,
-            "e",
-        )
+            self.cb.get_rounding_mode(),
+            f.precision(),
+            &self.ctx.exponent_style().with_uppercase(false),
+        );
+
+        format::pad_integral(f, format::determine_sign(f, self.sign()), &parts)
     }
 }
 
 impl<const N: usize> UpperExp for Decimal<N> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        format::format_exponential(
-            self.digits.to_str_radix(10),
+        if self.is_nan() || self.is_infinite() {
+            return format::pad_special(self, f);
+        }
+
+        let mut buf = [[0; 20]; N];
+        let parts = format::format_exponential(
+            format::ascii_digits(&self.digits, &mut buf),
             self.cb.get_scale(),
             self.sign(),
-            f,
-            "E",
-        )
+            self.cb.get_rounding_mode(),
+            f.precision(),
+            &self.ctx.exponent_style().with_uppercase(true),
+        );
+
+        format::pad_integral(f, format::determine_sign(f, self.sign()), &parts)
     }
 }
 
@@ -1,30 +1,52 @@
 pub(crate) mod abs;
 pub(crate) mod acos;
 pub(crate) mod acosh;
+pub(crate) mod acot;
+pub(crate) mod acoth;
+pub(crate) mod acsc;
+pub(crate) mod acsch;
 pub(crate) mod add;
+pub(crate) mod asec;
+pub(crate) mod asech;
 pub(crate) mod asin;
 pub(crate) mod asinh;
 pub(crate) mod atan;
 pub(crate) mod atan2;
 pub(crate) mod atanh;
+pub(crate) mod beta;
+pub(crate) mod binomial;
 pub(crate) mod cbrt;
 pub(crate) mod consts;
+pub(crate) mod correctly_rounded;
 pub(crate) mod cos;
 pub(crate) mod cosh;
+pub(crate) mod cot;
+pub(crate) mod coth;
+pub(crate) mod csc;
+pub(crate) mod csch;
+pub(crate) mod deg;
 pub(crate) mod div;
+pub(crate) mod erf;
 pub(crate) mod exp;
 pub(crate) mod exp2;
+pub(crate) mod factorial;
+pub(crate) mod gamma;
 pub(crate) mod hypot;
+pub(crate) mod lambert_w;
 pub(crate) mod ln;
 pub(crate) mod log;
 pub(crate) mod log10;
 pub(crate) mod log2;
+pub(crate) mod log_sum_exp;
 pub(crate) mod mul;
+pub(crate) mod norm;
 pub(crate) mod nth_root;
 pub(crate) mod pow;
 pub(crate) mod powi;
 pub(crate) mod recip;
 pub(crate) mod rem;
+pub(crate) mod sec;
+pub(crate) mod sech;
 pub(crate) mod sin;
 pub(crate) mod sin_cos;
 pub(crate) mod sinh;
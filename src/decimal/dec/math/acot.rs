@@ -0,0 +1,33 @@
+use crate::decimal::{
+    dec::math::{atan::atan, consts::Consts, div::div, sub::sub},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _arccot(x) = arctan(1/x)_ with the principal values _−π/2 < y <= π/2_.
+#[inline]
+pub(crate) const fn acot<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return Consts::FRAC_PI_2.set_ctx(x.context());
+    }
+
+    if x.is_infinite() {
+        return D::ZERO.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    if x.abs().ge(&D::ONE) {
+        return atan(div(D::ONE.set_ctx(x.context()), x));
+    }
+
+    // arctan(1/x) = ±π/2 − arctan(x)
+    if x.is_negative() {
+        sub(Consts::FRAC_PI_2.neg(), atan(x))
+    } else {
+        sub(Consts::FRAC_PI_2, atan(x))
+    }
+}
@@ -0,0 +1,28 @@
+use core::cmp::Ordering;
+
+use crate::decimal::{
+    dec::math::{div::div, ln::ln_1p, mul::mul, sub::sub},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _arcoth(x) = artanh(1/x) = ln(1 + 2 / (x − 1)) / 2_ for _|x| > 1_.
+#[inline]
+pub(crate) const fn acoth<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return D::ZERO.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    match x.abs().cmp(&D::ONE) {
+        Ordering::Less => return x.signaling_nan(),
+        Ordering::Equal => return div(x, D::ZERO.set_ctx(x.context())),
+        Ordering::Greater => {}
+    }
+
+    mul(ln_1p(div(D::TWO, sub(x, D::ONE))), D::HALF)
+}
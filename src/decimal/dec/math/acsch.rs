@@ -0,0 +1,51 @@
+use crate::decimal::{
+    dec::{
+        intrinsics::E_LIMIT,
+        math::{
+            add::add,
+            consts::Consts,
+            div::div,
+            ln::{ln, ln_1p},
+            mul::mul,
+            sqrt::sqrt,
+        },
+    },
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _arcsch(x) = arsinh(1/x)_ with _arsinh(y) = ln(1 + y + y² / (1 + √(1 +
+/// y²)))_ for _y = 1/|x|_, which keeps its precision for small `y` and is odd
+/// by construction.
+#[inline]
+pub(crate) const fn acsch<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return div(D::ONE.set_ctx(x.context()), x);
+    }
+
+    if x.is_infinite() {
+        return D::ZERO.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    let y = div(D::ONE, x.abs());
+
+    // arsinh(y) = ln(2y) + 1/(4y²) − …, where the correction is below
+    // 10^(−E_LIMIT) and y² would overflow.
+    let result = if x.decimal_power() < -E_LIMIT / 2 {
+        add(ln(y), Consts::LN_2)
+    } else {
+        let y2 = mul(y, y);
+        ln_1p(add(y, div(y2, add(D::ONE, sqrt(add(D::ONE, y2))))))
+    };
+
+    if x.is_negative() {
+        result.neg()
+    } else {
+        result
+    }
+}
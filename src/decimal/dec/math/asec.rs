@@ -0,0 +1,20 @@
+use crate::decimal::{
+    dec::math::{acos::acos, div::div},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _arcsec(x) = arccos(1/x)_ for _|x| >= 1_.
+#[inline]
+pub(crate) const fn asec<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.abs().lt(&D::ONE) {
+        return x.signaling_nan();
+    }
+
+    acos(div(D::ONE.set_ctx(x.context()), x))
+}
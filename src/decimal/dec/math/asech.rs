@@ -0,0 +1,29 @@
+use crate::decimal::{
+    dec::math::{add::add, div::div, ln::ln_1p, mul::mul, sqrt::sqrt, sub::sub},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _arsech(x) = arcosh(1/x) = ln(1 + (1 − x + √(1 − x²)) / x)_ for _0 < x <=
+/// 1_, where _1 − x_ is exact so that arguments close to one keep their
+/// precision.
+#[inline]
+pub(crate) const fn asech<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return div(D::ONE.set_ctx(x.context()), x);
+    }
+
+    if x.is_negative() || x.gt(&D::ONE) {
+        return x.signaling_nan();
+    }
+
+    let y = sub(D::ONE, x);
+    let s = sqrt(mul(y, add(D::ONE, x)));
+
+    ln_1p(div(add(y, s), x))
+}
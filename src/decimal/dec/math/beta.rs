@@ -0,0 +1,58 @@
+use crate::decimal::{
+    dec::{
+        math::{
+            add::add,
+            div::div,
+            exp::exp,
+            gamma::{gamma, ln_gamma},
+            mul::mul,
+            sub::sub,
+        },
+        parse::from_u32,
+    },
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Above this _a + b_ the product of the gamma functions grows too large for
+/// the division by _Γ(a + b)_.
+const DIRECT_MAX: u32 = 300;
+
+/// _B(a, b) = Γ(a) Γ(b) / Γ(a + b)_.
+#[inline]
+pub(crate) const fn beta<const N: usize>(a: D<N>, b: D<N>) -> D<N> {
+    if a.is_nan() {
+        return a.compound(&b).op_invalid();
+    }
+
+    if b.is_nan() {
+        return b.compound(&a).op_invalid();
+    }
+
+    if a.is_infinite() || b.is_infinite() {
+        return a.compound(&b).signaling_nan();
+    }
+
+    if is_pole(&a) || is_pole(&b) {
+        return a.compound(&b).signaling_nan();
+    }
+
+    let s = add(a, b);
+
+    if is_pole(&s) {
+        return D::ZERO.compound(&a).compound(&b);
+    }
+
+    if a.is_positive() && b.is_positive() && s.gt(&from_u32(DIRECT_MAX)) {
+        // B(a, b) = exp(ln Γ(a) + ln Γ(b) − ln Γ(a + b))
+        exp(sub(add(ln_gamma(a), ln_gamma(b)), ln_gamma(s)))
+    } else {
+        div(mul(gamma(a), gamma(b)), gamma(s))
+    }
+}
+
+#[inline(always)]
+const fn is_pole<const N: usize>(x: &D<N>) -> bool {
+    x.is_integral() && (x.is_zero() || x.is_negative())
+}
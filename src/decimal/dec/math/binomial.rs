@@ -0,0 +1,59 @@
+use crate::decimal::{
+    dec::{
+        convert::to_u64,
+        math::{add::add, div::div, mul::mul, sub::sub, utils::overflow},
+        parse::from_u64,
+    },
+    Decimal, Sign,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _C(n, k) = n! / (k! (n − k)!)_ for integral _n >= 0_.
+///
+/// Computed as _C(n − k + i, i) = C(n − k + i − 1, i − 1)·(n − k + i) / i_ for
+/// _i = 1, …, k_ with _k <= n − k_, every partial result is an integer, so it
+/// is exact while it fits the coefficient and rounded with the extra
+/// precision after that.
+#[inline]
+pub(crate) const fn binomial<const N: usize>(n: D<N>, k: D<N>) -> D<N> {
+    if n.is_nan() {
+        return n.compound(&k).op_invalid();
+    }
+
+    if k.is_nan() {
+        return k.compound(&n).op_invalid();
+    }
+
+    if n.is_infinite() || n.is_negative() || !n.is_integral() {
+        return n.compound(&k).signaling_nan();
+    }
+
+    if k.is_infinite() || !k.is_integral() {
+        return k.compound(&n).signaling_nan();
+    }
+
+    if k.is_negative() || k.gt(&n) {
+        return D::ZERO.compound(&n).compound(&k);
+    }
+
+    let n_k = sub(n, k);
+    let k = if n_k.lt(&k) { n_k } else { k };
+
+    let k = match to_u64(k) {
+        Ok(k) => k,
+        Err(_) => return overflow(Sign::Plus, n.signals(), n.context()),
+    };
+
+    let base = sub(n, from_u64(k));
+    let mut result = D::ONE.compound(&n);
+    let mut i = 1;
+
+    while i <= k && !result.is_infinite() {
+        let i_dec = from_u64(i);
+        result = div(mul(result, add(base, i_dec)), i_dec);
+        i += 1;
+    }
+
+    result
+}
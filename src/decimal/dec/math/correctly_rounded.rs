@@ -0,0 +1,413 @@
+//! Correctly rounded evaluation of the transcendental functions.
+//!
+//! The function is evaluated in the wider `Decimal<M>` and the result is
+//! rounded once to the precision of `Decimal<N>` under the active
+//! [RoundingMode](crate::decimal::RoundingMode), taking every digit of the
+//! wide result into account.
+//!
+//! The wide result is only accurate to some units in its last place, so the
+//! rounding can't be decided from a tail that lies within _10<sup>k/2</sup>_
+//! units (where `k` is the number of digits cut off) of a rounding boundary.
+//! Such a tail is what an exact result looks like, so the value on the
+//! boundary is first checked for being the exact result: the exact powers and
+//! roots for [pow](pow::pow), [exp2](exp2::exp2), [sqrt](sqrt::sqrt) and
+//! [cbrt](cbrt::cbrt), and their inverses for the logarithms. The other
+//! functions are exact only at the trivial points (_e<sup>0</sup>_, _ln 1_,
+//! _sin 0_, …), where the evaluation itself is exact.
+//!
+//! Otherwise the result is evaluated again in those of `Decimal<8>`,
+//! `Decimal<16>`, … up to [`Decimal<WIDEST>`](WIDEST) which are wider than
+//! `Decimal<M>`, until the rounding is decided. A result which can't be
+//! decided in `Decimal<WIDEST>` either exceeds the working precision, like
+//! the _Insufficient storage_ condition of the General Decimal Arithmetic,
+//! and is `NaN` with the [`Invalid`](crate#invalid-operation) signal.
+
+use crate::{
+    decimal::{
+        dec::{
+            construct::construct,
+            convert::to_i32,
+            math::{
+                acos, acosh, asin, asinh, atan, atanh, cbrt, cos, cosh, exp, exp2, ln, log, log10,
+                log2, nth_root::exact_root, pow, powi::powi, sin, sinh, sqrt, tan, tanh,
+            },
+            scale::reduce,
+            transmute::transmute,
+            ExtraPrecision,
+        },
+        signals::Signals,
+        Decimal,
+        RoundingMode::*,
+    },
+    int::{convert, resize, UInt},
+    utils::err_msg,
+};
+
+type D<const N: usize> = Decimal<N>;
+type U<const N: usize> = UInt<N>;
+
+macro_rules! correctly_rounded {
+    ($($name: ident => $f: path, $exact: path;)*) => {
+        $(
+            #[track_caller]
+            #[inline]
+            pub(crate) const fn $name<const N: usize, const M: usize>(x: D<N>) -> D<N> {
+                #[inline]
+                const fn eval<const N: usize, const W: usize>(x: D<N>) -> Rounded<N, W> {
+                    narrow(x, $f(transmute::<N, W>(x)))
+                }
+
+                #[inline]
+                const fn exact<const N: usize, const W: usize>(x: D<N>, boundary: D<W>) -> Option<D<W>> {
+                    $exact(transmute::<N, W>(x), boundary)
+                }
+
+                assert_wider::<N, M>();
+                retry!(x, eval(x), exact(x))
+            }
+        )*
+    };
+    ($($name: ident($arg: ident) => $f: path, $exact: path;)*) => {
+        $(
+            #[track_caller]
+            #[inline]
+            pub(crate) const fn $name<const N: usize, const M: usize>(x: D<N>, $arg: D<N>) -> D<N> {
+                #[inline]
+                const fn eval<const N: usize, const W: usize>(x: D<N>, $arg: D<N>) -> Rounded<N, W> {
+                    narrow(x, $f(transmute::<N, W>(x), transmute::<N, W>($arg)))
+                }
+
+                #[inline]
+                const fn exact<const N: usize, const W: usize>(x: D<N>, $arg: D<N>, boundary: D<W>) -> Option<D<W>> {
+                    $exact(transmute::<N, W>(x), transmute::<N, W>($arg), boundary)
+                }
+
+                assert_wider::<N, M>();
+                retry!(x, eval(x, $arg), exact(x, $arg))
+            }
+        )*
+    };
+}
+
+/// Checks the first result for being exact, then evaluates again in the wider
+/// types until the rounding is decided.
+macro_rules! retry {
+    ($x: ident, $eval: ident $args: tt, $exact: ident($($exact_args: ident),*)) => {{
+        let res = $eval::<N, M> $args;
+
+        if res.decided {
+            return res.value;
+        }
+
+        if let Some(w) = $exact::<N, M>($($exact_args,)* res.boundary) {
+            return narrow($x, w).value;
+        }
+
+        retry!(@ $eval $args, 8, 16, 32, 64, WIDEST);
+
+        $x.signaling_nan()
+    }};
+    (@ $eval: ident $args: tt, $($w: tt),*) => {
+        $(
+            if $w > M {
+                let res = $eval::<N, $w> $args;
+
+                if res.decided {
+                    return res.value;
+                }
+            }
+        )*
+    };
+}
+correctly_rounded! {
+    exp => exp::exp, inexact;
+    exp_m1 => exp::exp_m1, inexact;
+    exp2 => exp2::exp2, exact_exp2;
+    ln => ln::ln, inexact;
+    ln_1p => ln::ln_1p, inexact;
+    log2 => log2::log2, exact_log2;
+    log10 => log10::log10, exact_log10;
+    sqrt => sqrt::sqrt, exact_sqrt;
+    cbrt => cbrt::cbrt, exact_cbrt;
+    sin => sin::sin, inexact;
+    cos => cos::cos, inexact;
+    tan => tan::tan, inexact;
+    asin => asin::asin, inexact;
+    acos => acos::acos, inexact;
+    atan => atan::atan, inexact;
+    sinh => sinh::sinh, inexact;
+    cosh => cosh::cosh, inexact;
+    tanh => tanh::tanh, inexact;
+    asinh => asinh::asinh, inexact;
+    acosh => acosh::acosh, inexact;
+    atanh => atanh::atanh, inexact;
+}
+
+correctly_rounded! {
+    pow(n) => pow::pow, exact_pow;
+    log(base) => log::log, exact_log;
+}
+
+/// Width of the widest decimal the result is evaluated in.
+const WIDEST: usize = 128;
+
+/// Largest number of decimal places of a fractional exponent for which the
+/// exact root is looked for.
+const MAX_ROOT_SCALE: i16 = 4;
+
+/// Result rounded to `N` digits, and whether its tail was far enough from the
+/// rounding boundaries to decide the rounding. Otherwise `boundary` is the
+/// wide result moved onto the nearest boundary.
+struct Rounded<const N: usize, const W: usize> {
+    value: D<N>,
+    decided: bool,
+    boundary: D<W>,
+}
+
+/// The functions which are exact only where they are evaluated exactly.
+#[inline(always)]
+const fn inexact<const N: usize>(_x: D<N>, _boundary: D<N>) -> Option<D<N>> {
+    None
+}
+
+#[inline]
+const fn exact_exp2<const N: usize>(x: D<N>, _boundary: D<N>) -> Option<D<N>> {
+    exact_power(D::TWO, x)
+}
+
+#[inline]
+const fn exact_pow<const N: usize>(x: D<N>, n: D<N>, _boundary: D<N>) -> Option<D<N>> {
+    exact_power(x, n)
+}
+
+#[inline]
+const fn exact_sqrt<const N: usize>(x: D<N>, _boundary: D<N>) -> Option<D<N>> {
+    exact_nth_root(x, 2)
+}
+
+#[inline]
+const fn exact_cbrt<const N: usize>(x: D<N>, _boundary: D<N>) -> Option<D<N>> {
+    exact_nth_root(x, 3)
+}
+
+#[inline]
+const fn exact_log2<const N: usize>(x: D<N>, boundary: D<N>) -> Option<D<N>> {
+    exact_log(x, D::TWO, boundary)
+}
+
+#[inline]
+const fn exact_log10<const N: usize>(x: D<N>, boundary: D<N>) -> Option<D<N>> {
+    exact_log(x, D::TEN, boundary)
+}
+
+/// Returns the `boundary` if it is the exact logarithm of `x` to the `base`.
+#[inline]
+const fn exact_log<const N: usize>(x: D<N>, base: D<N>, boundary: D<N>) -> Option<D<N>> {
+    match exact_power(base, boundary) {
+        Some(power) if power.eq(&x) => Some(boundary),
+        _ => None,
+    }
+}
+
+/// Returns _b<sup>y</sup>_ if it is exact and representable.
+///
+/// For an integral `y` this is the integer power of `b`. Otherwise, for
+/// _y = p/q_ in lowest terms with at most [MAX_ROOT_SCALE] decimal places, it is the `p`-th power of
+/// the exact `q`-th root of `b`.
+#[inline]
+const fn exact_power<const N: usize>(b: D<N>, y: D<N>) -> Option<D<N>> {
+    if b.cb.is_special() || y.cb.is_special() || b.has_extra_precision() {
+        return None;
+    }
+
+    let b = D::from_parts(b.digits, b.cb.get_exponent(), b.sign(), b.context());
+    let y = reduce(y);
+
+    let scale = y.cb.get_scale();
+
+    if scale <= 0 {
+        return match to_i32(y) {
+            Ok(n) => exact_powi(b, n),
+            Err(_) => None,
+        };
+    }
+
+    if scale > MAX_ROOT_SCALE {
+        return None;
+    }
+
+    let mut p = match convert::to_u32(y.digits) {
+        Ok(p) => p,
+        Err(_) => return None,
+    };
+    let mut q = 10_u32.pow(scale as u32);
+
+    let (mut a, mut r) = (p, q);
+    while r != 0 {
+        (a, r) = (r, a % r);
+    }
+    p /= a;
+    q /= a;
+
+    if p > i32::MAX as u32 {
+        return None;
+    }
+
+    match exact_nth_root(b, q) {
+        Some(root) if y.is_negative() => exact_powi(root, -(p as i32)),
+        Some(root) => exact_powi(root, p as i32),
+        None => None,
+    }
+}
+
+/// Returns the exact `n`-th root of `x`, the negative one for a negative `x`
+/// and an odd `n`.
+#[inline]
+const fn exact_nth_root<const N: usize>(x: D<N>, n: u32) -> Option<D<N>> {
+    if !x.is_negative() {
+        exact_root(x, n)
+    } else if n % 2 == 1 {
+        match exact_root(x.neg(), n) {
+            Some(root) => Some(root.neg()),
+            None => None,
+        }
+    } else {
+        None
+    }
+}
+
+#[inline]
+const fn exact_powi<const N: usize>(b: D<N>, n: i32) -> Option<D<N>> {
+    let power = powi(b, n);
+
+    if power.cb.is_special()
+        || power.has_extra_precision()
+        || power.is_op_inexact()
+        || power.is_op_rounded()
+    {
+        None
+    } else {
+        Some(power)
+    }
+}
+
+#[track_caller]
+#[inline(always)]
+const fn assert_wider<const N: usize, const M: usize>() {
+    if M <= N {
+        panic!(err_msg!("working width must be wider than the decimal"));
+    }
+}
+
+/// Rounds the wide result `w` of an operation on `x` to `N` digits.
+#[inline]
+const fn narrow<const N: usize, const M: usize>(x: D<N>, mut w: D<M>) -> Rounded<N, M> {
+    if w.cb.is_special() {
+        let digits = if w.is_infinite() { UInt::MAX } else { UInt::ZERO };
+        return Rounded {
+            value: D::new(digits, w.cb, x.context()),
+            decided: true,
+            boundary: w,
+        };
+    }
+
+    // An underflowed result is rounded at the bottom of the exponent range,
+    // which a wider working width doesn't extend.
+    let inexact = !w.cb.take_extra_precision().is_empty()
+        || (w.is_op_inexact() && !w.is_op_underflow());
+    let max = resize::widen::<N, M>(U::<N>::MAX);
+
+    // Cut off `k` digits so that rounding up can't overflow the coefficient.
+    let mut digits = w.digits;
+    let mut unit = U::<M>::ONE;
+    let mut k = 0;
+
+    while !digits.lt(&max) {
+        digits = digits.strict_div(U::TEN);
+        unit = unit.strict_mul(U::TEN);
+        k += 1;
+    }
+
+    let mut tail = w.digits.strict_sub(digits.strict_mul(unit));
+    let half = unit.strict_div(U::TWO);
+    let mut decided = true;
+
+    if inexact {
+        let mut tolerance = U::<M>::ONE;
+        let mut i = 0;
+        while i < k / 2 {
+            tolerance = tolerance.strict_mul(U::TEN);
+            i += 1;
+        }
+
+        if tail.lt(&tolerance) {
+            tail = U::ZERO;
+            decided = false;
+        } else if unit.strict_sub(tail).le(&tolerance) {
+            digits = digits.strict_add(U::ONE);
+            tail = U::ZERO;
+            decided = false;
+        } else if tail.abs_diff(half).lt(&tolerance) {
+            tail = half;
+            decided = false;
+        }
+    }
+
+    // The coefficient rounded up onto the boundary may not fit next to
+    // `U::<M>::MAX`, then there is no candidate for an exact result.
+    let boundary = match digits.checked_mul(unit) {
+        Some(boundary) if !decided => match boundary.checked_add(tail) {
+            Some(boundary) => construct(
+                boundary,
+                w.cb.get_exponent(),
+                w.sign(),
+                Signals::empty(),
+                w.context(),
+                ExtraPrecision::new(),
+            ),
+            None => w,
+        },
+        _ => w,
+    };
+
+    let mut signals = w.cb.get_signals();
+
+    if !tail.is_zero() {
+        signals = signals.combine(Signals::OP_ROUNDED.combine(Signals::OP_INEXACT));
+
+        let negative = w.is_negative();
+        let odd = digits.digits()[0] & 1 != 0;
+
+        let up = match x.cb.get_rounding_mode() {
+            No | Down => false,
+            Up => true,
+            Ceiling => !negative,
+            Floor => negative,
+            HalfUp => !tail.lt(&half),
+            HalfDown => tail.gt(&half),
+            HalfEven => tail.gt(&half) || (tail.eq(&half) && odd),
+        };
+
+        if up {
+            digits = digits.strict_add(U::ONE);
+        }
+    }
+
+    let digits = match resize::try_narrow::<M, N>(digits) {
+        Some(digits) => digits,
+        None => unreachable!(),
+    };
+
+    Rounded {
+        value: construct(
+            digits,
+            w.cb.get_exponent() + k,
+            w.sign(),
+            signals,
+            x.context(),
+            ExtraPrecision::new(),
+        ),
+        decided,
+        boundary,
+    }
+}
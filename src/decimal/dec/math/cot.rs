@@ -0,0 +1,11 @@
+use crate::decimal::{
+    dec::math::{cos::cos, div::div, sin::sin},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+#[inline]
+pub(crate) const fn cot<const N: usize>(x: D<N>) -> D<N> {
+    div(cos(x).set_ctx(x.context()), sin(x))
+}
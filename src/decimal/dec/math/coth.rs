@@ -0,0 +1,42 @@
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::Intrinsics,
+            math::{add::add, div::div, exp::exp_m1, mul::mul},
+            parse::from_u32,
+        },
+        Decimal,
+    },
+    signals,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _coth(x) = (e<sup>2x</sup> + 1) / (e<sup>2x</sup> − 1)_, with
+/// _e<sup>2x</sup> − 1_ computed directly so that small arguments keep their
+/// precision.
+#[inline]
+pub(crate) const fn coth<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return div(D::ONE.set_ctx(x.context()), x);
+    }
+
+    if x.is_infinite() {
+        return D::ONE.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    // e^(−2|x|) < 10^(−1.7·MAX_CLENGTH) is below the working precision.
+    if x.abs().gt(&from_u32(2 * Intrinsics::<N>::MAX_CLENGTH)) {
+        return D::ONE
+            .set_ctx(x.context())
+            .set_sign(x.sign())
+            .raise_signals(signals![!ROUND, !INEXACT]);
+    }
+
+    let e = exp_m1(mul(x, D::TWO));
+    div(add(e, D::TWO), e)
+}
@@ -0,0 +1,11 @@
+use crate::decimal::{
+    dec::math::{div::div, sin::sin},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+#[inline]
+pub(crate) const fn csc<const N: usize>(x: D<N>) -> D<N> {
+    div(D::ONE.set_ctx(x.context()), sin(x))
+}
@@ -0,0 +1,51 @@
+use crate::decimal::{
+    dec::{
+        intrinsics::{Intrinsics, E_LIMIT},
+        math::{
+            add::add,
+            consts::Consts,
+            div::div,
+            exp::{exp, exp_m1},
+            mul::mul,
+            utils::underflow,
+        },
+        parse::{from_i32, from_u32},
+    },
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _csch(x) = 2(m + 1) / (m (m + 2))_ with _m = e<sup>x</sup> − 1_ computed
+/// directly, so that small arguments keep their precision.
+#[inline]
+pub(crate) const fn csch<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return div(D::ONE.set_ctx(x.context()), x);
+    }
+
+    if x.is_infinite() {
+        return D::ZERO.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    let abs = x.abs();
+
+    // 2e^(−|x|) < 10^(−E_LIMIT)
+    if abs.gt(&mul(from_i32(E_LIMIT), Consts::LN_10)) {
+        return underflow(x.sign(), x.signals(), x.context());
+    }
+
+    // csch(x) = ±2e^(−|x|) once e^(−2|x|) is below the working precision.
+    if abs.gt(&from_u32(2 * Intrinsics::<N>::MAX_CLENGTH)) {
+        let y = mul(exp(abs.neg()), D::TWO);
+
+        return if x.is_negative() { y.neg() } else { y };
+    }
+
+    let m = exp_m1(x);
+    div(mul(add(m, D::ONE), D::TWO), mul(m, add(m, D::TWO)))
+}
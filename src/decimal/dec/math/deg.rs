@@ -0,0 +1,203 @@
+use crate::decimal::{
+    dec::{
+        math::{
+            consts::Consts, cos::cos, div::div, mul::mul, rem::rem, sin::sin, sub::sub, tan::tan,
+        },
+        parse::from_u32,
+    },
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _sin(x°)_.
+#[inline]
+pub(crate) const fn sin_deg<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return x.signaling_nan();
+    }
+
+    let (quadrant, r) = reduce(x.abs());
+
+    let y = if quadrant % 2 == 0 {
+        sin_first_quadrant(r)
+    } else {
+        sin_first_quadrant(sub(ninety(), r))
+    };
+
+    signed(y, (quadrant >= 2) != x.is_negative(), x)
+}
+
+/// _cos(x°)_.
+#[inline]
+pub(crate) const fn cos_deg<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return x.signaling_nan();
+    }
+
+    let (quadrant, r) = reduce(x.abs());
+
+    let y = if quadrant % 2 == 0 {
+        sin_first_quadrant(sub(ninety(), r))
+    } else {
+        sin_first_quadrant(r)
+    };
+
+    signed(y, quadrant == 1 || quadrant == 2, x)
+}
+
+/// _tan(x°)_, infinite with a [`Division by zero`](crate#division-by-zero)
+/// condition at the odd multiples of _90°_.
+#[inline]
+pub(crate) const fn tan_deg<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return x.signaling_nan();
+    }
+
+    let (quadrant, r) = reduce(x.abs());
+
+    if quadrant % 2 == 0 {
+        return signed(tan_first_quadrant(r), x.is_negative(), x);
+    }
+
+    // The pole at 90° is approached with +∞ and the one at 270° with −∞.
+    if r.is_zero() {
+        let one = signed(D::ONE, (quadrant == 3) != x.is_negative(), x);
+        return div(one, D::ZERO.set_ctx(x.context()));
+    }
+
+    // tan(90° + r) = −tan(90° − r)
+    signed(tan_first_quadrant(sub(ninety(), r)), !x.is_negative(), x)
+}
+
+/// Applies the sign and the context of the argument `x` to the result `y`,
+/// zero stays positive.
+#[inline(always)]
+const fn signed<const N: usize>(y: D<N>, negative: bool, x: D<N>) -> D<N> {
+    let y = y.set_ctx(x.context());
+
+    if negative && !y.is_zero() {
+        y.neg()
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+const fn ninety<const N: usize>() -> D<N> {
+    from_u32(90)
+}
+
+/// Splits _x >= 0_ into _x = 360° k + 90° q + r_ with the quadrant _0 <= q <
+/// 4_ and _0 <= r < 90°_. Unlike the conversion to radians this is exact.
+#[inline]
+const fn reduce<const N: usize>(x: D<N>) -> (u32, D<N>) {
+    debug_assert!(!x.is_negative());
+
+    let mut r = rem_360(x);
+    let mut quadrant = 0;
+
+    while r.ge(&ninety()) {
+        r = sub(r, ninety());
+        quadrant += 1;
+    }
+
+    (quadrant, r)
+}
+
+/// _x mod 360°_ for _x >= 0_. An integral `x` with a positive exponent is
+/// reduced through its coefficient, since _10<sup>e</sup> ≡ 280 (mod 360)_
+/// for _e >= 3_.
+#[inline]
+const fn rem_360<const N: usize>(x: D<N>) -> D<N> {
+    let full = from_u32(360);
+    let scale = x.cb.get_scale();
+
+    if scale >= 0 {
+        return rem(x, full);
+    }
+
+    let power = match scale {
+        -1 => 10,
+        -2 => 100,
+        _ => 280,
+    };
+
+    let mut coefficient = x;
+    coefficient.cb.set_scale(0);
+
+    rem(mul(rem(coefficient, full), from_u32(power)), full)
+}
+
+/// _sin(r°)_ for _0 <= r <= 90°_, exact at the multiples of _30°_.
+#[inline]
+const fn sin_first_quadrant<const N: usize>(r: D<N>) -> D<N> {
+    if r.is_zero() {
+        return D::ZERO;
+    }
+
+    if r.eq(&from_u32(30)) {
+        return D::HALF;
+    }
+
+    if r.eq(&from_u32(45)) {
+        return Consts::FRAC_1_SQRT_2;
+    }
+
+    if r.eq(&from_u32(60)) {
+        return mul(Consts::SQRT_3, D::HALF);
+    }
+
+    if r.eq(&ninety()) {
+        return D::ONE;
+    }
+
+    if r.le(&from_u32(45)) {
+        sin(to_radians(r))
+    } else {
+        cos(to_radians(sub(ninety(), r)))
+    }
+}
+
+/// _tan(r°)_ for _0 <= r < 90°_, exact at _0°_ and _45°_.
+#[inline]
+const fn tan_first_quadrant<const N: usize>(r: D<N>) -> D<N> {
+    if r.is_zero() {
+        return D::ZERO;
+    }
+
+    if r.eq(&from_u32(30)) {
+        return div(Consts::SQRT_3, from_u32(3));
+    }
+
+    if r.eq(&from_u32(45)) {
+        return D::ONE;
+    }
+
+    if r.eq(&from_u32(60)) {
+        return Consts::SQRT_3;
+    }
+
+    if r.le(&from_u32(45)) {
+        tan(to_radians(r))
+    } else {
+        div(D::ONE, tan(to_radians(sub(ninety(), r))))
+    }
+}
+
+#[inline(always)]
+const fn to_radians<const N: usize>(x: D<N>) -> D<N> {
+    div(mul(x, Consts::PI), Consts::C_180)
+}
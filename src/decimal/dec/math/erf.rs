@@ -0,0 +1,245 @@
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::Intrinsics,
+            math::{
+                add::add,
+                consts::Consts,
+                div::div,
+                exp::{exp, exp_m1},
+                mul::mul,
+                sqrt::sqrt,
+                sub::sub,
+                utils::underflow,
+            },
+            parse::{from_u32, from_u64},
+            scale::extend_scale_to,
+            ExtraPrecision,
+        },
+        Decimal, Sign,
+    },
+    signals,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _erfc(x) < e<sup>−x²</sup>_ underflows every decimal type beyond this
+/// argument.
+const UNDERFLOW_MIN: u32 = 300;
+
+#[inline]
+pub(crate) const fn erf<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return x;
+    }
+
+    if x.is_infinite() {
+        return D::ONE.set_ctx(x.context()).set_sign(x.sign());
+    }
+
+    if x.is_negative() {
+        erf_abs(x.neg()).neg()
+    } else {
+        erf_abs(x)
+    }
+}
+
+#[inline]
+pub(crate) const fn erfc<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return D::ONE.with_ctx(x.context());
+    }
+
+    if x.is_infinite() {
+        return if x.is_negative() {
+            D::TWO.set_ctx(x.context())
+        } else {
+            D::ZERO.set_ctx(x.context())
+        };
+    }
+
+    if x.lt(&D::HALF) {
+        // erf(x) < 0.53, so the subtraction cancels no digits.
+        return sub(D::ONE, erf(x));
+    }
+
+    if mul(x, x).lt(&precision_log()) {
+        exponential_series(x)
+    } else if x.gt(&from_u32(UNDERFLOW_MIN)) {
+        underflow(Sign::Plus, x.signals(), x.context())
+    } else {
+        continued_fraction(x)
+    }
+}
+
+/// _e<sup>−x²</sup>_, computed as _(e<sup>−x²/2</sup>)<sup>2</sup>_ so that
+/// the exponential stays within range for _|x| <= 300_.
+#[inline]
+pub(crate) const fn exp_neg_square<const N: usize>(x: D<N>) -> D<N> {
+    let half = exp(mul(mul(x, x), D::HALF).neg());
+    mul(half, half)
+}
+
+/// Working precision in digits.
+#[inline(always)]
+const fn precision<const N: usize>() -> u32 {
+    Intrinsics::<N>::MAX_CLENGTH + ExtraPrecision::EXTRA_PRECISION_DIGITS + 1
+}
+
+/// _P ln 10_ for the working precision `P`, beyond it _e<sup>−x²</sup> <
+/// 10<sup>−P</sup>_.
+#[inline(always)]
+const fn precision_log<const N: usize>() -> D<N> {
+    mul(from_u32(precision::<N>()), Consts::LN_10)
+}
+
+#[inline]
+const fn erf_abs<const N: usize>(x: D<N>) -> D<N> {
+    debug_assert!(!x.is_negative());
+
+    let x2 = mul(x, x);
+
+    // The positive series needs more terms as x grows, the rest goes to
+    // erfc(x) < 1/2.
+    if x2.lt(&mul(precision_log(), D::HALF)) {
+        return positive_series(x);
+    }
+
+    // erfc(x) < e^(−x²) is below the working precision.
+    if x2.gt(&from_u32(3 * precision::<N>())) {
+        return D::ONE
+            .set_ctx(x.context())
+            .raise_signals(signals![!ROUND, !INEXACT]);
+    }
+
+    sub(D::ONE, erfc(x))
+}
+
+/// _erf(x) = 2/√π e<sup>−x²</sup> Σ 2<sup>n</sup> x<sup>2n+1</sup> /
+/// (2n + 1)!!_.
+///
+/// Unlike the Taylor series of _erf(x)_ the terms are positive, so no digits
+/// cancel.
+#[inline]
+const fn positive_series<const N: usize>(x: D<N>) -> D<N> {
+    let x2 = mul(mul(x, x), D::TWO);
+
+    let mut result = x;
+    let mut result_next;
+    let mut item = x;
+    let mut i = 1;
+
+    while i < Intrinsics::<N>::SERIES_MAX_ITERATIONS + 1 {
+        item = div(mul(item, x2), from_u32(2 * i + 1));
+        result_next = add(result, item);
+
+        if result.eq(&result_next) {
+            break;
+        }
+
+        result = result_next;
+        i += 1;
+    }
+
+    mul(mul(Consts::FRAC_2_SQRT_PI, exp_neg_square(x)), result)
+}
+
+/// The series of Chiarella and Reichel
+///
+/// _erfc(x) = 2xh/π e<sup>−x²</sup> (1/(2x²) + Σ e<sup>−n²h²</sup> / (n²h² +
+/// x²)) − 2 / (e<sup>2πx/h</sup> − 1)_
+///
+/// up to an error of about _e<sup>−π²/h²</sup>_, which is below the working
+/// precision for _h = π / √(P ln 10 + x²)_. It needs _O(P)_ terms where the
+/// [continued_fraction] needs _O(P²/x²)_. For _x >= 1/2_ the correction is
+/// much smaller than the result.
+#[inline]
+const fn exponential_series<const N: usize>(x: D<N>) -> D<N> {
+    let z = mul(x, x);
+    let h = div(Consts::PI, sqrt(add(precision_log(), z)));
+    let h2 = mul(h, h);
+
+    // e^(−n²h²) = e^(−(n − 1)²h²) q^(2n − 1), q = e^(−h²)
+    let q = exp(h2.neg());
+    let q2 = mul(q, q);
+    let mut q_odd = q;
+    let mut power = q;
+
+    let mut result = div(D::HALF, z);
+    let mut result_next;
+    let mut n = 1;
+
+    while n < Intrinsics::<N>::SERIES_MAX_ITERATIONS + 1 {
+        let item = div(power, add(mul(from_u32(n * n), h2), z));
+        result_next = add(result, item);
+
+        if result.eq(&result_next) {
+            break;
+        }
+
+        result = result_next;
+
+        q_odd = mul(q_odd, q2);
+        power = mul(power, q_odd);
+        n += 1;
+    }
+
+    let factor = mul(mul(D::TWO, Consts::FRAC_1_PI), mul(x, h));
+    let correction = div(D::TWO, exp_m1(div(mul(Consts::TAU, x), h)));
+
+    sub(mul(mul(factor, exp_neg_square(x)), result), correction)
+}
+
+/// _erfc(x) = x e<sup>−x²</sup> / (√π F)_ with the continued fraction of
+/// Legendre for _Γ(1/2, x²)_
+///
+/// _F = x² + 1/2 − (1·1/2) / (x² + 5/2 − (2·3/2) / (x² + 9/2 − …))_
+///
+/// evaluated by the modified Lentz algorithm. For _x² >= P ln 10_ it converges
+/// within about _P ln 10 / 16_ terms.
+#[inline]
+const fn continued_fraction<const N: usize>(x: D<N>) -> D<N> {
+    let precision = (Intrinsics::<N>::MAX_CLENGTH + 5) as i32;
+
+    let z = mul(x, x);
+
+    let mut f = add(z, D::HALF);
+    let mut c = f;
+    let mut d = D::ZERO;
+    let mut n: u64 = 1;
+
+    loop {
+        // a_n = −n (n − 1/2), b_n = x² + 2n + 1/2
+        let a = mul(from_u64(2 * n * n - n), D::HALF).neg();
+        let b = add(z, add(from_u64(2 * n), D::HALF));
+
+        d = div(D::ONE, add(b, mul(a, d)));
+        c = add(b, div(a, c));
+
+        let delta = mul(c, d);
+        f = mul(f, delta);
+
+        // The difference to one may lie entirely in the extra precision.
+        let error = sub(delta, D::ONE);
+        let error = extend_scale_to(
+            error,
+            error.cb.get_scale() + ExtraPrecision::EXTRA_PRECISION_DIGITS as i16,
+        );
+
+        if error.is_zero() || error.decimal_power() < -precision {
+            break;
+        }
+
+        n += 1;
+    }
+
+    div(mul(mul(x, exp_neg_square(x)), Consts::FRAC_1_SQRT_PI), f)
+}
@@ -1,10 +1,21 @@
-use crate::decimal::{
-    dec::{
-        intrinsics::Intrinsics,
-        math::{add::add, consts::Consts, div::div, mul::mul, sub::sub},
-        parse::from_u32,
+use crate::{
+    decimal::{
+        dec::{
+            convert::to_i32,
+            intrinsics::Intrinsics,
+            math::{
+                add::add,
+                consts::Consts,
+                div::div,
+                mul::mul,
+                sub::sub,
+                utils::{overflow, underflow},
+            },
+            parse::{from_i32, from_u32},
+        },
+        Decimal, Sign,
     },
-    Decimal,
+    signals,
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -20,7 +31,17 @@ pub(crate) const fn exp<const N: usize>(x: D<N>) -> D<N> {
     }
 
     if x.is_negative() {
-        return div(D::ONE, exp_abs(x.abs()));
+        if x.is_infinite() {
+            return D::ZERO.set_ctx(x.context());
+        }
+
+        let y = exp_abs(x.abs());
+
+        return if y.is_infinite() {
+            underflow(Sign::Plus, x.signals(), x.context())
+        } else {
+            div(D::ONE, y)
+        };
     }
 
     exp_abs(x)
@@ -28,7 +49,35 @@ pub(crate) const fn exp<const N: usize>(x: D<N>) -> D<N> {
 
 #[inline]
 pub(crate) const fn exp_m1<const N: usize>(x: D<N>) -> D<N> {
-    sub(exp(x), D::ONE)
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return x;
+    }
+
+    if x.is_infinite() {
+        return if x.is_negative() {
+            D::ONE.neg().set_ctx(x.context())
+        } else {
+            x
+        };
+    }
+
+    // For |x| >= 1/2 the subtraction cancels less than one digit, which is
+    // covered by the extra precision.
+    if x.abs().lt(&D::HALF) {
+        return exp_m1_taylor_series(x);
+    }
+
+    let exp = exp(x);
+
+    if exp.is_infinite() {
+        exp
+    } else {
+        sub(exp, D::ONE)
+    }
 }
 
 #[inline]
@@ -46,10 +95,36 @@ const fn exp_abs<const N: usize>(x: D<N>) -> D<N> {
     argument_reduction(x)
 }
 
+/// _e<sup>x</sup> = 10<sup>k</sup>·e<sup>x - k·ln(10)</sup>_ with _k =
+/// ⌊x / ln(10)⌋_, the power of ten is applied exactly through the scale.
 #[inline]
 const fn argument_reduction<const N: usize>(x: D<N>) -> D<N> {
-    if x.ge(&D::ONE) {
-        let y = argument_reduction(mul(x, D::HALF));
+    if x.lt(&Consts::LN_10) {
+        return halving_reduction(x);
+    }
+
+    let k = match to_i32(div(x, Consts::LN_10).floor()) {
+        Ok(k) if k <= i16::MAX as i32 => k,
+        _ => return overflow(Sign::Plus, x.signals(), x.context()),
+    };
+
+    let r = sub(x, mul(from_i32(k), Consts::LN_10));
+    let mut y = halving_reduction(r);
+
+    let scale = y.cb.get_scale() as i32 - k;
+    if scale < i16::MIN as i32 {
+        return overflow(Sign::Plus, y.signals(), x.context());
+    }
+
+    y.cb.set_scale(scale as i16);
+    y
+}
+
+/// _e<sup>x</sup> = (e<sup>x/2</sup>)<sup>2</sup>_ until _x < 1/2_.
+#[inline]
+const fn halving_reduction<const N: usize>(x: D<N>) -> D<N> {
+    if x.ge(&D::HALF) {
+        let y = halving_reduction(mul(x, D::HALF));
         mul(y, y)
     } else {
         taylor_series(x)
@@ -78,3 +153,31 @@ const fn taylor_series<const N: usize>(x: D<N>) -> D<N> {
 
     result.with_ctx(x.context())
 }
+
+/// _e<sup>x</sup> - 1 = x + x<sup>2</sup>/2! + x<sup>3</sup>/3! + …_ without
+/// the leading one, so that no significant digits cancel for small `x`.
+#[inline]
+const fn exp_m1_taylor_series<const N: usize>(x: D<N>) -> D<N> {
+    let mut result = x;
+    let mut result_next;
+    let mut item = div(mul(x, x), D::TWO);
+    let mut i = 3;
+
+    while i < Intrinsics::<N>::SERIES_MAX_ITERATIONS + 3 {
+        result_next = add(result, item);
+
+        if result.eq(&result_next) {
+            break;
+        }
+
+        item = div(mul(item, x), from_u32(i));
+
+        result = result_next;
+        i += 1;
+    }
+
+    // Terms below the precision may be dropped without rounding the sum.
+    result
+        .with_ctx(x.context())
+        .raise_signals(signals![!ROUND, !INEXACT])
+}
@@ -0,0 +1,46 @@
+use crate::decimal::{
+    dec::{
+        convert::to_u32,
+        math::{mul::mul, utils::overflow},
+        parse::from_u32,
+    },
+    Decimal, Sign,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _n! = 1·2·…·n_, exact while the product fits the coefficient and rounded
+/// with the extra precision after that.
+#[inline]
+pub(crate) const fn factorial<const N: usize>(n: D<N>) -> D<N> {
+    if n.is_nan() {
+        return n.op_invalid();
+    }
+
+    if n.is_infinite() || n.is_negative() || !n.is_integral() {
+        return n.signaling_nan();
+    }
+
+    let signals = n.signals();
+    let ctx = n.context();
+
+    let n = match to_u32(n) {
+        Ok(n) => n,
+        Err(_) => return overflow(Sign::Plus, signals, ctx),
+    };
+
+    let mut result = D::ONE.set_ctx(ctx);
+    let mut i = 2;
+
+    while i <= n {
+        result = mul(result, from_u32(i).set_ctx(ctx));
+
+        if result.is_infinite() {
+            return overflow(Sign::Plus, signals, ctx);
+        }
+
+        i += 1;
+    }
+
+    result
+}
@@ -0,0 +1,376 @@
+use crate::decimal::{
+    dec::{
+        convert::to_i32,
+        intrinsics::Intrinsics,
+        math::{
+            add::add,
+            consts::Consts,
+            div::div,
+            exp::exp,
+            factorial::factorial,
+            ln::ln,
+            mul::mul,
+            sin::sin,
+            sub::sub,
+            utils::{is_odd, overflow},
+        },
+        parse::{from_i32, from_u32},
+        ExtraPrecision,
+    },
+    signals::Signals,
+    Decimal, Sign,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Γ overflows every decimal type beyond this argument.
+const GAMMA_MAX: u32 = 20_000;
+
+/// Below this argument _ln Γ(x)_ is computed as _ln(Γ(x))_.
+const LN_GAMMA_DIRECT_MAX: u32 = 1_000;
+
+/// Power of `T` applied at once in [gamma_series], _T<sup>1000</sup>_ stays
+/// within the exponent range.
+const POWER_CHUNK: i32 = 1_000;
+
+#[inline]
+pub(crate) const fn gamma<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return pole(x).set_sign(x.sign());
+    }
+
+    if x.is_infinite() {
+        return if x.is_negative() {
+            x.signaling_nan()
+        } else {
+            x
+        };
+    }
+
+    if x.is_integral() && x.is_negative() {
+        return x.signaling_nan();
+    }
+
+    if x.gt(&from_u32(GAMMA_MAX)) {
+        return overflow(Sign::Plus, x.signals(), x.context());
+    }
+
+    if x.is_integral() {
+        return factorial(sub(x, D::ONE.set_ctx(x.context())));
+    }
+
+    if x.decimal_power() < near_zero_power::<N>() {
+        return gamma_near_zero(x);
+    }
+
+    if x.lt(&D::HALF) {
+        // Γ(x) = π / (sin(πx)·Γ(1 − x))
+        return div(Consts::PI, mul(sin_pi(x), gamma(sub(D::ONE, x))));
+    }
+
+    gamma_series(x)
+}
+
+#[inline]
+pub(crate) const fn ln_gamma<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return if x.is_negative() {
+            x.signaling_nan()
+        } else {
+            x
+        };
+    }
+
+    if x.is_integral() && (x.is_zero() || x.is_negative()) {
+        return pole(x);
+    }
+
+    if x.is_negative() {
+        // ln|Γ(x)| = ln(π / |sin(πx)|) − ln Γ(1 − x)
+        return sub(
+            ln(div(Consts::PI, sin_pi(x).abs())),
+            ln_gamma(sub(D::ONE, x)),
+        );
+    }
+
+    if x.lt(&from_u32(LN_GAMMA_DIRECT_MAX)) {
+        return ln(gamma(x));
+    }
+
+    let terms = stirling_terms(x);
+
+    if terms > 0 {
+        stirling_series(x, terms)
+    } else {
+        ln_gamma_series(x)
+    }
+}
+
+#[inline]
+const fn pole<const N: usize>(x: D<N>) -> D<N> {
+    D::INFINITY
+        .set_ctx(x.context())
+        .raise_signals(Signals::OP_DIV_BY_ZERO)
+        .op_invalid()
+}
+
+/// _Γ(x) = 1/x − γ + (γ²/2 + π²/12)·x + O(x²)_ for `x` close to zero, where
+/// the reflection formula loses the last digit of the pole _1/x_.
+#[inline]
+const fn gamma_near_zero<const N: usize>(x: D<N>) -> D<N> {
+    let g = Consts::EULER_GAMMA;
+    let c = add(
+        mul(mul(g, g), D::HALF),
+        div(mul(Consts::PI, Consts::PI), from_u32(12)),
+    );
+
+    add(sub(div(D::ONE.set_ctx(x.context()), x), g), mul(c, x))
+}
+
+/// Decimal power of `x` below which the _O(x²)_ term of [gamma_near_zero],
+/// relative to _1/x_, is below the working precision.
+#[inline]
+const fn near_zero_power<const N: usize>() -> i32 {
+    let precision = Intrinsics::<N>::MAX_CLENGTH + ExtraPrecision::EXTRA_PRECISION_DIGITS + 1;
+    -(precision as i32) / 3 - 1
+}
+
+/// _sin(πx) = (−1)<sup>n</sup> sin(π(x − n))_ where `n` is the integer
+/// nearest to `x`, so the sine is only taken of _|π(x − n)| <= π/2_.
+#[inline]
+const fn sin_pi<const N: usize>(x: D<N>) -> D<N> {
+    let n = add(x, D::HALF).floor().reduce();
+    let s = sin(mul(Consts::PI, sub(x, n)));
+
+    if is_odd(&n) {
+        s.neg()
+    } else {
+        s
+    }
+}
+
+/// _Γ(x) = T<sup>x</sup> e<sup>−T</sup> S_ for _0 < x <= 20000_, see
+/// [incomplete_series].
+#[inline]
+const fn gamma_series<const N: usize>(x: D<N>) -> D<N> {
+    let (sum, t) = incomplete_series(x);
+    let mut n = match to_i32(x.floor()) {
+        Ok(n) => n,
+        Err(_) => unreachable!(),
+    };
+
+    let t = from_u32(t);
+    let frac = sub(x, from_i32(n));
+
+    // The power of T is applied last and in chunks, the partial products grow
+    // monotonically towards Γ(x) and can't overflow before it does.
+    let mut res = div(mul(sum, exp(mul(frac, ln(t)))), exp(t));
+
+    if n >= POWER_CHUNK {
+        let chunk = pow(t, POWER_CHUNK);
+
+        while n >= POWER_CHUNK && !res.is_infinite() {
+            res = mul(res, chunk);
+            n -= POWER_CHUNK;
+        }
+    }
+
+    res = mul(res, pow(t, n));
+
+    if res.is_infinite() {
+        overflow(Sign::Plus, x.signals(), x.context())
+    } else {
+        res
+    }
+}
+
+/// _x<sup>n</sup>_ by squaring, rounding every product to the working
+/// precision.
+#[inline]
+const fn pow<const N: usize>(x: D<N>, mut n: i32) -> D<N> {
+    let mut base = x;
+    let mut result = D::ONE;
+
+    while n > 0 {
+        if n % 2 == 1 {
+            result = mul(result, base);
+        }
+
+        n /= 2;
+
+        if n > 0 {
+            base = mul(base, base);
+        }
+    }
+
+    result
+}
+
+/// _ln Γ(x) = ln S + x ln T − T_, see [incomplete_series].
+#[inline]
+const fn ln_gamma_series<const N: usize>(x: D<N>) -> D<N> {
+    let (sum, t) = incomplete_series(x);
+    let t = from_u32(t);
+
+    sub(add(ln(sum), mul(x, ln(t))), t)
+}
+
+/// Sum of the lower incomplete gamma series
+/// _γ(x, T) = T<sup>x</sup> e<sup>−T</sup> Σ T<sup>k</sup> / (x (x + 1) … (x +
+/// k))_ for _x > 0_.
+///
+/// The terms are positive, so no precision is lost to cancellation. With _T =
+/// 2⌈x⌉ + 3P_, where `P` is the working precision in digits, the omitted
+/// _Γ(x, T) = Γ(x) − γ(x, T)_ is below _10<sup>−P</sup> Γ(x)_.
+///
+/// Returns the sum and `T`.
+#[inline]
+const fn incomplete_series<const N: usize>(x: D<N>) -> (D<N>, u32) {
+    let c = match to_i32(x.ceil()) {
+        Ok(c) => c as u32,
+        Err(_) => unreachable!(),
+    };
+
+    let t = 2 * c + 3 * (Intrinsics::<N>::MAX_CLENGTH + ExtraPrecision::EXTRA_PRECISION_DIGITS);
+    let t_dec = from_u32(t);
+
+    let mut item = div(D::ONE, x);
+    let mut result = item;
+    let mut result_next;
+
+    // The terms grow while x + k < T and the sum may only settle after that.
+    let mut k = 1;
+
+    while k < 4 * t {
+        item = div(mul(item, t_dec), add(x, from_u32(k)));
+        result_next = add(result, item);
+
+        if k > t && result.eq(&result_next) {
+            break;
+        }
+
+        result = result_next;
+        k += 1;
+    }
+
+    (result, t)
+}
+
+/// Number of terms of the Stirling series that [stirling_series] needs at
+/// `x`, or `0` if it needs more than it can hold.
+///
+/// The `k`-th term relative to _ln Γ(x) > x_ is below _2 (k / (πe
+/// x))<sup>2k</sup>_, which is bounded from above with
+/// _log<sub>10</sub>(πe) > 0.9_ and the decimal lengths of `x` and `k`.
+#[inline]
+const fn stirling_terms<const N: usize>(x: D<N>) -> u32 {
+    let precision = Intrinsics::<N>::MAX_CLENGTH + ExtraPrecision::EXTRA_PRECISION_DIGITS + 1;
+    let log_x = x.decimal_power();
+
+    let mut k = 1;
+
+    while k <= stirling_max_terms::<N>() {
+        // Tenths of log10(πe·x / k).
+        let log = 10 * log_x + 9 - 10 * (k.ilog10() as i32 + 1);
+
+        if log > 0 && 2 * k as i32 * log >= 10 * precision as i32 {
+            return k;
+        }
+
+        k += 1;
+    }
+
+    0
+}
+
+/// Size of the table of tangent numbers in [stirling_series], which is kept
+/// on the stack.
+const STIRLING_MAX_TERMS: usize = 64;
+
+/// At most four terms per 64-bit digit of the coefficient are used, so the
+/// narrow types don't spend more work on the series than on its alternative.
+#[inline(always)]
+const fn stirling_max_terms<const N: usize>() -> u32 {
+    if 4 * N < STIRLING_MAX_TERMS {
+        4 * N as u32
+    } else {
+        STIRLING_MAX_TERMS as u32
+    }
+}
+
+/// _ln Γ(x) = (x − 1/2) ln x − x + ln(2π)/2 + Σ B<sub>2k</sub> / (2k (2k − 1)
+/// x<sup>2k−1</sup>)_ with `terms` terms of the sum.
+///
+/// The Bernoulli numbers are obtained from the tangent numbers
+/// _T<sub>k</sub>_ as _B<sub>2k</sub> = (−1)<sup>k−1</sup> 2k T<sub>k</sub> /
+/// (4<sup>k</sup> (4<sup>k</sup> − 1))_. These are computed by the recurrence
+/// of Brent and Harvey, which only adds and multiplies positive numbers.
+#[inline]
+const fn stirling_series<const N: usize>(x: D<N>, terms: u32) -> D<N> {
+    let mut tangent = [D::<N>::ZERO; STIRLING_MAX_TERMS];
+    let k_max = terms as usize;
+
+    macro_rules! t {
+        ($k: expr) => {
+            tangent[$k - 1]
+        };
+    }
+
+    t!(1) = D::ONE;
+
+    let mut k = 2;
+    while k <= k_max {
+        t!(k) = mul(t!(k - 1), from_u32(k as u32 - 1));
+        k += 1;
+    }
+
+    k = 2;
+    while k <= k_max {
+        let mut j = k;
+        while j <= k_max {
+            t!(j) = add(
+                mul(t!(j - 1), from_u32((j - k) as u32)),
+                mul(t!(j), from_u32((j - k + 2) as u32)),
+            );
+            j += 1;
+        }
+        k += 1;
+    }
+
+    let mut result = add(
+        sub(mul(sub(x, D::HALF), ln(x)), x),
+        mul(D::HALF, ln(Consts::TAU)),
+    );
+
+    let x2 = mul(x, x);
+    let mut power = div(D::ONE, x);
+    let mut four_k = D::ONE;
+
+    k = 1;
+    while k <= k_max {
+        four_k = mul(four_k, D::FOUR);
+
+        // B_2k / (2k (2k − 1)) = (−1)^(k−1) T_k / ((2k − 1) 4^k (4^k − 1))
+        let denominator = mul(from_u32(2 * k as u32 - 1), mul(four_k, sub(four_k, D::ONE)));
+        let item = mul(div(t!(k), denominator), power);
+
+        result = if k % 2 == 1 {
+            add(result, item)
+        } else {
+            sub(result, item)
+        };
+
+        power = div(power, x2);
+        k += 1;
+    }
+
+    result
+}
@@ -0,0 +1,230 @@
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::Intrinsics,
+            math::{
+                add::add,
+                consts::Consts,
+                div::div,
+                exp::exp,
+                ln::{ln, ln_1p},
+                mul::mul,
+                sqrt::sqrt,
+                sub::sub,
+            },
+            parse::from_u32,
+        },
+        Decimal,
+    },
+    signals,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Halley's iteration triples the number of correct digits, starting from
+/// the one of [initial_guess] this covers the widest decimal type.
+const HALLEY_MAX_ITERATIONS: u32 = 16;
+
+/// From this _|w|_ on _e<sup>w</sup>_ may leave the exponent range, so the
+/// equation is solved in the logarithmic form.
+const LOGARITHMIC_MIN: u32 = 10_000;
+
+/// _W<sub>0</sub>(x)_, the principal branch of the solution of _w e<sup>w</sup>
+/// = x_ for _x >= −1/e_.
+#[inline]
+pub(crate) const fn lambert_w0<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return x;
+    }
+
+    if x.is_infinite() && !x.is_negative() {
+        return x;
+    }
+
+    // p = √(2(ex + 1)) vanishes at the branch point.
+    let t = add(mul(Consts::E, x), D::ONE);
+
+    if is_branch_point(t) {
+        return branch_point(x);
+    }
+
+    if t.is_negative() {
+        return x.signaling_nan();
+    }
+
+    // Near the branch point the logarithmic form loses less precision.
+    if x.lt(&quarter().neg()) {
+        halley_ln(branch_series(sqrt(mul(t, D::TWO))), ln(x.neg()))
+    } else if x.gt(&Consts::E) {
+        solve(asymptotic_series(ln(x)), x)
+    } else {
+        halley_exp(initial_guess(x), x)
+    }
+}
+
+/// _W<sub>−1</sub>(x)_, the lower branch of the solution of _w e<sup>w</sup>
+/// = x_ for _−1/e <= x < 0_.
+#[inline]
+pub(crate) const fn lambert_wm1<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return D::NEG_INFINITY.op_invalid().with_ctx(x.context());
+    }
+
+    if !x.is_negative() {
+        return x.signaling_nan();
+    }
+
+    let t = add(mul(Consts::E, x), D::ONE);
+
+    if is_branch_point(t) {
+        return branch_point(x);
+    }
+
+    if t.is_negative() {
+        return x.signaling_nan();
+    }
+
+    if x.lt(&quarter().neg()) {
+        halley_ln(branch_series(sqrt(mul(t, D::TWO)).neg()), ln(x.neg()))
+    } else {
+        solve(asymptotic_series(ln(x.neg())), x)
+    }
+}
+
+/// Solves _w e<sup>w</sup> = x_ starting from `w`.
+#[inline]
+const fn solve<const N: usize>(w: D<N>, x: D<N>) -> D<N> {
+    if w.abs().gt(&from_u32(LOGARITHMIC_MIN)) {
+        halley_ln(w, ln(x.abs()))
+    } else {
+        halley_exp(w, x)
+    }
+}
+
+/// Whether _t = ex + 1_ vanishes up to a few units in the last place of the
+/// working precision, which the argument nearest to _−1/e_ misses by up to
+/// half a unit.
+#[inline(always)]
+const fn is_branch_point<const N: usize>(t: D<N>) -> bool {
+    t.is_zero() || t.decimal_power() < 2 - Intrinsics::<N>::MAX_CLENGTH as i32
+}
+
+/// _W(−1/e) = −1_ for an argument that equals _−1/e_ at the working
+/// precision.
+#[inline(always)]
+const fn branch_point<const N: usize>(x: D<N>) -> D<N> {
+    D::ONE
+        .neg()
+        .set_ctx(x.context())
+        .raise_signals(signals![!ROUND, !INEXACT])
+}
+
+#[inline(always)]
+const fn quarter<const N: usize>() -> D<N> {
+    div(D::ONE, D::FOUR)
+}
+
+/// _W(x) ≈ −1 + p − p²/3 + 11p³/72_ near the branch point, where _p =
+/// ±√(2(ex + 1))_ is positive for _W<sub>0</sub>_ and negative for
+/// _W<sub>−1</sub>_.
+#[inline]
+const fn branch_series<const N: usize>(p: D<N>) -> D<N> {
+    let p2 = mul(p, p);
+    let p3 = mul(p2, p);
+
+    let w = sub(p, div(p2, from_u32(3)));
+    let w = add(w, div(mul(p3, from_u32(11)), from_u32(72)));
+
+    sub(w, D::ONE)
+}
+
+/// _W(x) ≈ L<sub>1</sub> − L<sub>2</sub> + L<sub>2</sub>/L<sub>1</sub>_ with
+/// _L<sub>1</sub> = ln|x|_ and _L<sub>2</sub> = ln|L<sub>1</sub>|_, valid for
+/// large _x_ on _W<sub>0</sub>_ and for _x → 0_ on _W<sub>−1</sub>_.
+#[inline]
+const fn asymptotic_series<const N: usize>(l1: D<N>) -> D<N> {
+    let l2 = ln(l1.abs());
+    add(sub(l1, l2), div(l2, l1))
+}
+
+/// The approximation of Winitzki
+///
+/// _W<sub>0</sub>(x) ≈ L (1 − ln(1 + L) / (2 + L))_, _L = ln(1 + x)_
+///
+/// with a relative error of a few percent for _x >= −1/4_.
+#[inline]
+const fn initial_guess<const N: usize>(x: D<N>) -> D<N> {
+    let l = ln_1p(x);
+    mul(l, sub(D::ONE, div(ln_1p(l), add(D::TWO, l))))
+}
+
+/// Solves _w e<sup>w</sup> = x_ by Halley's iteration
+///
+/// _w ← w − f / (e<sup>w</sup>(w + 1) − (w + 2) f / (2w + 2))_, _f = w
+/// e<sup>w</sup> − x_.
+#[inline]
+const fn halley_exp<const N: usize>(mut w: D<N>, x: D<N>) -> D<N> {
+    let mut i = 0;
+
+    while i < HALLEY_MAX_ITERATIONS {
+        let e = exp(w);
+        let f = sub(mul(w, e), x);
+        let w1 = add(w, D::ONE);
+
+        let denominator = sub(mul(e, w1), div(mul(add(w, D::TWO), f), mul(w1, D::TWO)));
+        let step = div(f, denominator);
+
+        w = sub(w, step);
+
+        if is_converged(&step, &w) {
+            break;
+        }
+
+        i += 1;
+    }
+
+    w
+}
+
+/// Solves _w + ln|w| = ln|x|_, the logarithm of _w e<sup>w</sup> = x_, by
+/// Halley's iteration
+///
+/// _w ← w − f w / (w + 1) / (1 + f / (2(w + 1)²))_, _f = w + ln|w| − ln|x|_
+///
+/// which doesn't overflow for arguments of any magnitude.
+#[inline]
+const fn halley_ln<const N: usize>(mut w: D<N>, l: D<N>) -> D<N> {
+    let mut i = 0;
+
+    while i < HALLEY_MAX_ITERATIONS {
+        let f = sub(add(w, ln(w.abs())), l);
+        let w1 = add(w, D::ONE);
+
+        let newton = div(mul(f, w), w1);
+        let step = div(newton, add(D::ONE, div(f, mul(mul(w1, w1), D::TWO))));
+
+        w = sub(w, step);
+
+        if is_converged(&step, &w) {
+            break;
+        }
+
+        i += 1;
+    }
+
+    w
+}
+
+#[inline(always)]
+const fn is_converged<const N: usize>(step: &D<N>, w: &D<N>) -> bool {
+    let precision = (Intrinsics::<N>::MAX_CLENGTH + 2) as i32;
+    step.is_zero() || step.decimal_power() < w.decimal_power() - precision
+}
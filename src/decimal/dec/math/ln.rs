@@ -1,12 +1,15 @@
 use core::cmp::Ordering;
 
-use crate::decimal::{
-    dec::{
-        intrinsics::Intrinsics,
-        math::{add::add, consts::Consts, div::div, mul::mul, sqrt::sqrt, sub::sub},
-        parse::from_u32,
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::Intrinsics,
+            math::{add::add, consts::Consts, div::div, mul::mul, sqrt::sqrt, sub::sub},
+            parse::{from_i32, from_u32},
+        },
+        Decimal,
     },
-    Decimal,
+    signals,
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -38,24 +41,71 @@ pub(crate) const fn ln<const N: usize>(x: D<N>) -> D<N> {
 
 #[inline]
 pub(crate) const fn ln_1p<const N: usize>(x: D<N>) -> D<N> {
-    ln(add(D::ONE, x))
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_zero() {
+        return x;
+    }
+
+    // For |x| >= 1/2 rounding of 1 + x doesn't affect the relative precision.
+    if x.abs().lt(&D::HALF) {
+        // ln(1 + x) = 2·atanh(x / (2 + x)), terms below the precision may be
+        // dropped without rounding the sum.
+        atanh_series(div(x, add(D::TWO, x)))
+            .with_ctx(x.context())
+            .raise_signals(signals![!ROUND, !INEXACT])
+    } else {
+        ln(add(D::ONE, x))
+    }
 }
 
+/// _ln(x) = ln(m) + k·ln(10)_ where _x = m·10<sup>k</sup>_ and _1/2 <= m <
+/// 5_, the mantissa `m` is obtained exactly through the scale.
 #[inline]
 const fn argument_reduction<const N: usize>(x: D<N>) -> D<N> {
+    let mut k = x.decimal_power();
+    let mut m = x;
+    m.cb.set_scale((m.cb.get_scale() as i32 + k) as i16);
+
+    if m.ge(&D::FIVE) {
+        m.cb.inc_scale(1);
+        k += 1;
+    }
+
+    let ln_m = sqrt_reduction(m);
+
+    if k == 0 {
+        ln_m
+    } else {
+        add(ln_m, mul(from_i32(k), Consts::LN_10))
+    }
+}
+
+/// _ln(x) = 2·ln(√x)_ until _x <= 2_.
+#[inline]
+const fn sqrt_reduction<const N: usize>(x: D<N>) -> D<N> {
     match x.cmp(&D::TWO) {
         Ordering::Less => taylor_series(x),
         Ordering::Equal => Consts::LN_2,
-        Ordering::Greater => mul(D::TWO, argument_reduction(sqrt(x))),
+        Ordering::Greater => mul(D::TWO, sqrt_reduction(sqrt(x))),
     }
 }
 
 #[inline]
 const fn taylor_series<const N: usize>(x: D<N>) -> D<N> {
+    atanh_series(div(sub(x, D::ONE), add(x, D::ONE)))
+}
+
+/// _2·atanh(z) = 2·(z + z<sup>3</sup>/3 + z<sup>5</sup>/5 + …)_, which is
+/// _ln((1 + z) / (1 - z))_.
+#[inline]
+const fn atanh_series<const N: usize>(z: D<N>) -> D<N> {
     let mut result = D::ZERO;
     let mut result_next;
 
-    let mut base = div(sub(x, D::ONE), add(x, D::ONE));
+    let mut base = z;
     let mut item = base;
 
     base = mul(base, base);
@@ -0,0 +1,109 @@
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::E_LIMIT,
+            math::{
+                add::add,
+                consts::Consts,
+                exp::exp,
+                ln::{ln, ln_1p},
+                mul::mul,
+                sub::sub,
+            },
+            parse::from_i32,
+        },
+        Decimal,
+    },
+    signals,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _ln(e<sup>a</sup> + e<sup>b</sup>) = max + ln(1 + e<sup>min − max</sup>)_,
+/// where the exponential is at most one.
+#[inline]
+pub(crate) const fn log_add_exp<const N: usize>(a: D<N>, b: D<N>) -> D<N> {
+    if a.is_nan() {
+        return a.compound(&b).op_invalid();
+    }
+
+    if b.is_nan() {
+        return b.compound(&a).op_invalid();
+    }
+
+    let (max, min) = if a.ge(&b) { (a, b) } else { (b, a) };
+
+    if max.is_infinite() || min.is_infinite() {
+        return max.compound(&min);
+    }
+
+    add(max, ln_1p(exp_non_positive(sub(min, max))))
+}
+
+/// _ln Σ e<sup>x<sub>i</sub></sup> = m + ln Σ e<sup>x<sub>i</sub> − m</sup>_
+/// with the running maximum `m`. The sum is rescaled whenever the maximum
+/// changes, so every exponential is at most one and the iterator is traversed
+/// once.
+#[inline]
+pub(crate) fn log_sum_exp<const N: usize, I>(iter: I) -> D<N>
+where
+    I: Iterator<Item = D<N>>,
+{
+    let mut max: Option<D<N>> = None;
+    let mut sum = D::ONE;
+
+    for x in iter {
+        let Some(m) = max else {
+            if x.is_nan() {
+                return x.op_invalid();
+            }
+
+            max = Some(x);
+            continue;
+        };
+
+        if x.is_nan() {
+            return x.compound(&m).op_invalid();
+        }
+
+        if m.is_infinite() || x.is_infinite() {
+            if x.gt(&m) {
+                max = Some(x.compound(&m));
+            } else {
+                max = Some(m.compound(&x));
+            }
+
+            continue;
+        }
+
+        if x.gt(&m) {
+            // Σ e^(x_i − x) = e^(m − x) Σ e^(x_i − m) + 1
+            sum = add(mul(sum, exp_non_positive(sub(m, x))), D::ONE);
+            max = Some(x.compound(&m));
+        } else {
+            sum = add(sum, exp_non_positive(sub(x, m)));
+            max = Some(m.compound(&x));
+        }
+    }
+
+    match max {
+        None => D::NEG_INFINITY,
+        Some(m) if m.is_infinite() => m,
+        Some(m) => add(m, ln(sum)),
+    }
+}
+
+/// _e<sup>x</sup>_ for _x <= 0_, flushed to zero where it is below every
+/// subnormal number.
+#[inline]
+const fn exp_non_positive<const N: usize>(x: D<N>) -> D<N> {
+    debug_assert!(!x.is_positive() || x.is_zero());
+
+    if x.lt(&mul(from_i32(-E_LIMIT), Consts::LN_10)) {
+        D::ZERO
+            .set_ctx(x.context())
+            .raise_signals(signals![!ROUND, !INEXACT])
+    } else {
+        exp(x)
+    }
+}
@@ -1,12 +1,17 @@
-use crate::decimal::{
-    dec::{
-        convert::to_f64,
-        intrinsics::Intrinsics,
-        math::{add::add, div::div, mul::mul},
-        parse::{from_f64, from_u32},
+use crate::{
+    decimal::{
+        dec::{
+            intrinsics::{clength, Intrinsics},
+            math::{add::add, div::div, exp::exp, ln::ln, mul::mul},
+            parse::from_u32,
+        },
+        Decimal, Sign,
     },
-    utils::types,
-    Decimal,
+    int::{
+        intrinsics::Intrinsics as IntIntrinsics,
+        math::{inth_root, overflowing_mul10, strict_mul10},
+    },
+    signals,
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -17,27 +22,74 @@ pub(crate) const fn nth_root<const N: usize>(d: D<N>, n: u32) -> D<N> {
         return d.op_invalid();
     }
 
-    if d.is_zero() || d.is_one() {
+    if n == 0 {
+        return d.signaling_nan();
+    }
+
+    if n == 1 {
         return d;
     }
 
-    if d.is_negative() {
-        return d.signaling_nan();
+    if d.is_negative() && !d.is_zero() {
+        // The odd roots of negative numbers are real.
+        return if n % 2 == 1 {
+            nth_root(d.neg(), n).neg()
+        } else {
+            d.signaling_nan()
+        };
     }
 
     if d.is_infinite() {
         return d;
     }
 
+    if let Some(root) = exact_root(d, n) {
+        return root;
+    }
+
     nth_root_newton(d, n)
 }
 
+/// Returns the exact _n_-th root of a finite non-negative `d` if it is
+/// representable, with the ideal exponent _⌊e / n⌋_ of the exponent `e` of
+/// `d` and without the [`Inexact`](crate#inexact) signal.
+///
+/// For _d = c · 10<sup>e</sup>_ and _e = n q + k_ with _0 <= k < n_ the root is
+/// _(c · 10<sup>k</sup>)<sup>1/n</sup> · 10<sup>q</sup>_, which is exact
+/// if and only if _c · 10<sup>k</sup>_ is a perfect _n_-th power.
 #[inline]
-const fn nth_root_newton<const N: usize>(d: D<N>, n: u32) -> D<N> {
-    let approx_f64 = to_f64(d);
-    let guess = types::f64::sqrt(approx_f64);
+pub(crate) const fn exact_root<const N: usize>(d: D<N>, n: u32) -> Option<D<N>> {
+    if d.has_extra_precision() {
+        return None;
+    }
+
+    let exp = d.cb.get_exponent();
+    let q = exp.div_euclid(n as i32);
+    let k = exp.rem_euclid(n as i32) as u32;
+
+    if k > IntIntrinsics::<N>::MAX_POWER_OF_TEN {
+        return None;
+    }
+
+    let (m, overflow) = overflowing_mul10(d.digits, k);
+
+    if overflow {
+        return None;
+    }
 
-    let mut result = from_f64(guess).compound(&d);
+    let root = inth_root(m, n);
+
+    match root.checked_pow(n) {
+        Some(power) if power.eq(&m) => {
+            Some(D::from_parts(root, q, d.sign(), d.context()).compound(&d))
+        }
+        _ => None,
+    }
+}
+
+#[inline]
+const fn nth_root_newton<const N: usize>(d: D<N>, n: u32) -> D<N> {
+    let mut result = root_guess(d, n);
 
     let mut result_next;
 
@@ -68,3 +120,45 @@ const fn nth_root_newton<const N: usize>(d: D<N>, n: u32) -> D<N> {
 
     result
 }
+
+/// Initial approximation of _d<sup>1/n</sup>_ for a finite positive `d`.
+///
+/// The coefficient is scaled by a power of ten to the full width of `UInt<N>`
+/// (so that the exponent becomes divisible by `n`) and its exact integer
+/// `n`-th root is taken, which gives about _1/n_ of the full precision
+/// instead of the `16` digits of an `f64` estimate. For large `n` the guess
+/// is _e<sup>ln(d) / n</sup>_.
+#[inline]
+pub(crate) const fn root_guess<const N: usize>(d: D<N>, n: u32) -> D<N> {
+    let exp = d.cb.get_exponent();
+    let free = Intrinsics::<N>::MAX_CLENGTH as i32 - 1 - clength(d.digits) as i32;
+
+    // k ≡ exp (mod n)
+    let k = free - (free - exp).rem_euclid(n as i32);
+
+    // Too few digits are left for an integer root when `n` is close to the
+    // width of the coefficient, and `-k` may exceed the powers of ten.
+    if clength(d.digits) as i32 + k < 2 * n as i32 {
+        return log_guess(d, n)
+            .raise_signals(signals![!CP, !ROUND, !INEXACT])
+            .compound(&d);
+    }
+
+    let digits = if k >= 0 {
+        strict_mul10(d.digits, k as u32)
+    } else {
+        d.digits
+            .div(IntIntrinsics::<N>::POWERS_OF_TEN.lookup(-k as u32))
+    };
+
+    D::from_parts(inth_root(digits, n), (exp - k) / n as i32, Sign::Plus, d.context())
+        // Same signals as the former `f64` based estimate.
+        .raise_signals(signals![!CP, !ROUND, !INEXACT])
+        .compound(&d)
+}
+
+/// Approximation _e<sup>ln(d) / n</sup>_ of _d<sup>1/n</sup>_.
+#[inline]
+const fn log_guess<const N: usize>(d: D<N>, n: u32) -> D<N> {
+    exp(div(ln(d), from_u32(n)))
+}
@@ -0,0 +1,11 @@
+use crate::decimal::{
+    dec::math::{cos::cos, div::div},
+    Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+#[inline]
+pub(crate) const fn sec<const N: usize>(x: D<N>) -> D<N> {
+    div(D::ONE.set_ctx(x.context()), cos(x))
+}
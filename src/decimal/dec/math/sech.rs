@@ -0,0 +1,31 @@
+use crate::decimal::{
+    dec::{
+        intrinsics::E_LIMIT,
+        math::{add::add, consts::Consts, div::div, exp::exp, mul::mul, utils::underflow},
+        parse::from_i32,
+    },
+    Decimal, Sign,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// _sech(x) = 2t / (1 + t²)_ with _t = e<sup>−|x|</sup>_, which doesn't
+/// overflow for large arguments.
+#[inline]
+pub(crate) const fn sech<const N: usize>(x: D<N>) -> D<N> {
+    if x.is_nan() {
+        return x.op_invalid();
+    }
+
+    if x.is_infinite() {
+        return D::ZERO.set_ctx(x.context());
+    }
+
+    // 2e^(−|x|) < 10^(−E_LIMIT)
+    if x.abs().gt(&mul(from_i32(E_LIMIT), Consts::LN_10)) {
+        return underflow(Sign::Plus, x.signals(), x.context());
+    }
+
+    let t = exp(x.abs().neg());
+    div(mul(t, D::TWO), add(D::ONE, mul(t, t)))
+}
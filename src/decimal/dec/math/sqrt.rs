@@ -1,11 +1,13 @@
 use crate::decimal::{
     dec::{
-        convert::to_f64,
         intrinsics::Intrinsics,
-        math::{add::add, div::div, mul::mul},
-        parse::from_f64,
+        math::{
+            add::add,
+            div::div,
+            mul::mul,
+            nth_root::{exact_root, root_guess},
+        },
     },
-    utils::types,
     Decimal,
 };
 
@@ -21,12 +23,12 @@ pub(crate) const fn sqrt<const N: usize>(d: D<N>) -> D<N> {
         return d;
     }
 
-    if d.is_zero() || d.is_one() {
-        return d;
+    if d.is_negative() && !d.is_zero() {
+        return d.signaling_nan();
     }
 
-    if d.is_negative() {
-        return d.signaling_nan();
+    if let Some(root) = exact_root(d, 2) {
+        return root;
     }
 
     if d.eq(&D::TWO) {
@@ -38,11 +40,7 @@ pub(crate) const fn sqrt<const N: usize>(d: D<N>) -> D<N> {
 
 #[inline]
 const fn sqrt_heron<const N: usize>(d: D<N>) -> D<N> {
-    let approx_f64 = to_f64(d);
-    let guess = types::f64::sqrt(approx_f64);
-
-    // TODO: from_f64 - optimization point
-    let mut result = from_f64(guess).compound(&d);
+    let mut result = root_guess(d, 2);
 
     let mut result_next;
     let mut i = 0;
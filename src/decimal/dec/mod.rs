@@ -7,7 +7,6 @@ mod control_block;
 mod convert;
 mod extra_precision;
 mod extras;
-mod format;
 mod impls;
 mod intrinsics;
 mod parse;
@@ -15,6 +14,7 @@ mod round;
 mod scale;
 mod transmute;
 
+pub(crate) mod format;
 pub(crate) mod math;
 pub(crate) mod utils;
 
@@ -27,15 +27,16 @@ use crate::{
     decimal::{
         dec::{
             consts::consts_impl,
-            intrinsics::{clength, Intrinsics},
+            intrinsics::{clength, Intrinsics, E_LIMIT},
             math::consts::Consts,
             round::round,
         },
         doc,
         signals::Signals,
-        Context, DecimalError, ParseError, RoundingMode, Sign, UnsignedDecimal,
+        Context, DecimalError, FormatStyle, Formatted, Language, NumberFormat, ParseError,
+        ParseErrorDetail, ParseOptions, RoundingMode, Sign, UnsignedDecimal, WordsStyle,
     },
-    int::{math::ilog10, UInt},
+    int::{math::ilog10, radix, UInt},
 };
 
 /// # Decimal
@@ -98,6 +99,165 @@ impl<const N: usize> Decimal<N> {
         parse::from_slice(s.as_bytes(), ctx)
     }
 
+    /// Creates and initializes decimal from string without losing any
+    /// significant digit.
+    ///
+    /// Unlike [`from_str`](Self::from_str), trailing zeros which don't fit
+    /// into the coefficient are accepted and dropped, while any non-zero digit
+    /// which would be discarded is an [`ParseError::Inexact`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// assert_eq!(D128::from_str_exact("-1.2345", ctx), Ok(dec128!(-1.2345)));
+    /// assert_eq!(D128::from_str_exact("0.1234567890123456789012345678901234567890", ctx), Ok(dec128!(0.123456789012345678901234567890123456789)));
+    /// assert_eq!(D128::from_str_exact("0.1234567890123456789012345678901234567891", ctx), Err(ParseError::Inexact));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn from_str_exact(s: &str, ctx: Context) -> Result<Self, ParseError> {
+        parse::from_slice_exact(s.as_bytes(), ctx)
+    }
+
+    /// Creates and initializes decimal from string, reporting the byte
+    /// offset where parsing failed and the kind of token expected there.
+    ///
+    /// The string is parsed as by [`from_str`](Self::from_str), which returns
+    /// the same value or the [`kind`](ParseErrorDetail::kind) of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// assert_eq!(D128::from_str_detailed("-1.2345", ctx), Ok(dec128!(-1.2345)));
+    ///
+    /// let e = D128::from_str_detailed("12.3.4", ctx).unwrap_err();
+    /// assert_eq!(e.kind(), ParseError::InvalidLiteral);
+    /// assert_eq!(e.position(), 4);
+    /// assert_eq!(e.expected(), Some(ExpectedToken::End));
+    ///
+    /// let e = D128::from_str_detailed("-", ctx).unwrap_err();
+    /// assert_eq!((e.kind(), e.position(), e.expected()), (ParseError::Empty, 1, Some(ExpectedToken::Digit)));
+    /// ```
+    #[inline]
+    pub fn from_str_detailed(s: &str, ctx: Context) -> Result<Self, ParseErrorDetail> {
+        parse::from_str_detailed(s, ctx)
+    }
+
+    /// Creates and initializes decimal from the longest prefix of string which
+    /// is a number, returning it with the number of bytes it takes.
+    ///
+    /// The rest of the string is left unparsed, e.g. a unit or another field
+    /// of a record. An `e` which isn't followed by an exponent is not part of
+    /// the number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseErrorDetail] if the string doesn't start with a number,
+    /// or if the number doesn't fit into the decimal type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// assert_eq!(D128::from_str_prefix("12.5kg", ctx), Ok((dec128!(12.5), 4)));
+    /// assert_eq!(D128::from_str_prefix("-3e2,4", ctx), Ok((dec128!(-3e2), 4)));
+    /// assert_eq!(D128::from_str_prefix("1.5em", ctx), Ok((dec128!(1.5), 3)));
+    ///
+    /// let e = D128::from_str_prefix("kg", ctx).unwrap_err();
+    /// assert_eq!((e.kind(), e.position(), e.expected()), (ParseError::InvalidLiteral, 0, Some(ExpectedToken::Sign)));
+    /// ```
+    #[inline]
+    pub fn from_str_prefix(s: &str, ctx: Context) -> Result<(Self, usize), ParseErrorDetail> {
+        parse::from_str_prefix(s, ctx)
+    }
+
+    /// Creates and initializes decimal from string in the forms enabled by
+    /// the [`ParseOptions`]: with grouping separators, another decimal
+    /// separator, surrounding whitespace, accounting negatives, a currency
+    /// symbol or a trailing `%`.
+    ///
+    /// Digits that don't fit into the coefficient are handled as set by
+    /// [`ParseOptions::with_excess_digits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    /// let opts = ParseOptions::new()
+    ///     .with_grouping_separator(',')
+    ///     .allow_parentheses(true)
+    ///     .allow_percent(true);
+    ///
+    /// assert_eq!(D256::from_str_with("1,234,567.89", ctx, &opts), Ok(dec256!(1234567.89)));
+    /// assert_eq!(D256::from_str_with("(123.45)", ctx, &opts), Ok(dec256!(-123.45)));
+    /// assert_eq!(D256::from_str_with("12.5%", ctx, &opts), Ok(dec256!(0.125)));
+    /// assert_eq!(D256::from_str_with("1,2,,3", ctx, &opts), Err(ParseError::InvalidLiteral));
+    ///
+    /// let opts = ParseOptions::new().with_excess_digits(ExcessDigits::Round);
+    /// let d = D128::from_str_with("0.1234567890123456789012345678901234567890123", ctx, &opts).unwrap();
+    ///
+    /// assert_eq!(d, dec128!(0.123456789012345678901234567890123456789));
+    /// assert!(d.is_op_inexact());
+    /// ```
+    #[inline]
+    pub fn from_str_with(s: &str, ctx: Context, opts: &ParseOptions) -> Result<Self, ParseError> {
+        parse::from_str_with(s, ctx, opts)
+    }
+
+    /// Creates and initializes decimal from string in the given `radix`.
+    ///
+    /// The string has an optional sign, followed by digits of the radix with
+    /// an optional `.` and `_` separators, case-insensitive. In radix `16`,
+    /// `8` and `2` the digits may be prefixed with `0x`, `0o` and `0b`. In the
+    /// radixes `2`, `4`, `8` and `16` the digits may be followed by a binary
+    /// exponent `p` (as in C99 hexadecimal floating literals such as
+    /// `0x1.8p3`) which scales the value by a power of two. The strings
+    /// `NaN`, `Inf` and `Infinity` take precedence over the digits of the
+    /// radixes above `23`. In radix `10` this is the same as
+    /// [`from_str`](Self::from_str).
+    ///
+    /// Every digit is taken into account exactly, the value is rounded with
+    /// the rounding mode of the `ctx` only if it doesn't fit into the
+    /// coefficient, e.g. for fraction digits in radix `3`. As with `from_str`,
+    /// more digits than the coefficient can hold are an overflow error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidRadix`] if `radix` is not in the range
+    /// `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// assert_eq!(D128::from_str_radix("0x1.8p3", 16, ctx), Ok(dec128!(12)));
+    /// assert_eq!(D128::from_str_radix("-ff.c", 16, ctx), Ok(dec128!(-255.75)));
+    /// assert_eq!(D128::from_str_radix("0b0.0001p-2", 2, ctx), Ok(dec128!(0.015625)));
+    /// assert_eq!(D128::from_str_radix("0x1p-1074", 16, ctx), Ok(D128::from_f64(f64::from_bits(1))));
+    /// assert_eq!(D128::from_str_radix("0.1", 3, ctx), Ok(dec128!(0.333333333333333333333333333333333333333)));
+    /// assert_eq!(D128::from_str_radix("1", 37, ctx), Err(ParseError::InvalidRadix));
+    /// ```
+    #[inline]
+    pub fn from_str_radix(s: &str, radix: u32, ctx: Context) -> Result<Self, ParseError> {
+        parse::from_str_radix(s, radix, ctx)
+    }
+
     /// Parse decimal from string.
     ///
     /// # Panics
@@ -131,6 +291,39 @@ impl<const N: usize> Decimal<N> {
         }
     }
 
+    /// Parse decimal from string without losing any significant digit, see
+    /// [`from_str_exact`](Self::from_str_exact).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `Decimal<N>` can't be constructed
+    /// from a given string exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(D128::parse_str_exact("1.2345", Context::default()), dec128!(1.2345));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let _ = D128::parse_str_exact("0.1234567890123456789012345678901234567891", Context::default());
+    /// ```
+    #[track_caller]
+    #[must_use]
+    #[inline]
+    pub const fn parse_str_exact(s: &str, ctx: Context) -> Self {
+        match Self::from_str_exact(s, ctx) {
+            Ok(n) => n,
+            Err(e) => {
+                panic!("{}", e.description())
+            }
+        }
+    }
+
     /// Returns the internal big integer, representing the
     /// [_Coefficient_](crate#representation) of a given `Decimal`, including
     /// significant trailing zeros.
@@ -1253,7 +1446,8 @@ impl<const N: usize> Decimal<N> {
     /// a special case of [Newton's](https://en.wikipedia.org/wiki/Newton%27s_method) method.
     ///
     /// Returns [`NaN`](crate#nan) if `self` is a negative number other than
-    /// `-0.0`.
+    /// `-0.0`. An exact square root, like _√2.25 = 1.5_, is returned with the
+    /// ideal exponent and without the [`OP_INEXACT`](crate#inexact) signal.
     #[doc = doc::decimal_inexact!("square root operation")]
     ///
     #[doc = doc::decimal_operation_panics!("square root operation")]
@@ -1268,6 +1462,7 @@ impl<const N: usize> Decimal<N> {
     /// assert_eq!(dec128!(1).sqrt(), dec128!(1));
     /// assert_eq!(dec128!(16).sqrt(), dec128!(4));
     /// assert_eq!(dec128!(2).sqrt(), D128::SQRT_2);
+    /// assert_eq!(dec128!(2.25).sqrt(), dec128!(1.5));
     /// ```
     ///
     /// See more about the [square-root](crate#square-root) operation.
@@ -1280,6 +1475,10 @@ impl<const N: usize> Decimal<N> {
 
     /// Take the cubic root of a decimal number using
     /// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method).
+    ///
+    /// The cubic root of a negative number is negative. An exact cubic root is
+    /// returned with the ideal exponent and without the
+    /// [`OP_INEXACT`](crate#inexact) signal.
     #[doc = doc::decimal_inexact!("cubic root operation")]
     ///
     #[doc = doc::decimal_operation_panics!("cubic root operation")]
@@ -1291,6 +1490,7 @@ impl<const N: usize> Decimal<N> {
     /// use fastnum::*;
     ///
     /// assert_eq!(dec128!(8).cbrt(), dec128!(2));
+    /// assert_eq!(dec128!(-0.001).cbrt(), dec128!(-0.1));
     /// ```
     ///
     /// See more about the [N-th root](crate#n-th-roots) operation.
@@ -1303,6 +1503,11 @@ impl<const N: usize> Decimal<N> {
 
     /// Take the N-th root of the decimal number using
     /// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method).
+    ///
+    /// Returns [`NaN`](crate#nan) if `n` is zero or if `self` is a negative
+    /// number and `n` is even, the odd roots of a negative number are
+    /// negative. An exact root is returned with the ideal exponent and without
+    /// the [`OP_INEXACT`](crate#inexact) signal.
     #[doc = doc::decimal_inexact!("N-th root operation")]
     ///
     #[doc = doc::decimal_operation_panics!("N-th root operation")]
@@ -1314,6 +1519,7 @@ impl<const N: usize> Decimal<N> {
     /// use fastnum::*;
     ///
     /// assert_eq!(dec128!(16).nth_root(4), dec128!(2));
+    /// assert_eq!(dec128!(-32).nth_root(5), dec128!(-2));
     /// ```
     ///
     /// See more about the [N-th root](crate#n-th-roots) operation.
@@ -1349,6 +1555,10 @@ impl<const N: usize> Decimal<N> {
     }
 
     /// Returns _e<sup>self</sup> – 1_.
+    ///
+    /// The result keeps full relative precision even for `self` close to
+    /// zero, where computing `self.exp() - 1` would cancel out most of the
+    /// significant digits.
     #[doc = doc::decimal_inexact!("exponential function")]
     ///
     #[doc = doc::decimal_operation_panics!("exponent calculation")]
@@ -1360,6 +1570,7 @@ impl<const N: usize> Decimal<N> {
     /// use fastnum::*;
     ///
     /// assert_eq!(dec128!(7.0).ln().exp_m1(), D128::SIX);
+    /// assert_eq!(dec128!(1e-9).exp_m1(), dec128!(1.00000000050000000016666666670833333334e-9));
     /// ```
     ///
     /// See more about the [exponential function](crate#exponential-function).
@@ -1417,6 +1628,9 @@ impl<const N: usize> Decimal<N> {
     }
 
     /// Returns _ln(1 + n)_ (natural logarithm).
+    ///
+    /// The result keeps full relative precision even for `self` close to
+    /// zero, where `1 + self` would round away most of its significant digits.
     #[doc = doc::decimal_inexact!("natural logarithm")]
     ///
     #[doc = doc::decimal_operation_panics!("logarithm operation")]
@@ -1428,6 +1642,7 @@ impl<const N: usize> Decimal<N> {
     /// use fastnum::*;
     ///
     /// assert_eq!((D128::E - dec128!(1)).ln_1p(), dec128!(1));
+    /// assert_eq!(dec128!(1e-9).ln_1p(), dec128!(9.9999999950000000033333333308333333353e-10));
     /// ```
     ///
     /// See more about the [logarithm function](crate#logarithm-function).
@@ -1503,6 +1718,66 @@ impl<const N: usize> Decimal<N> {
         math::log10::log10(self).round_extra_precision().check()
     }
 
+    /// Computes _ln(e<sup>self</sup> + e<sup>other</sup>)_ without
+    /// overflowing or underflowing the intermediate exponentials.
+    #[doc = doc::decimal_inexact!("logarithm")]
+    ///
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(0).log_add_exp(dec128!(0)), D128::LN_2);
+    /// assert_eq!(dec128!(100000).log_add_exp(dec128!(100000)), dec128!(100000) + D128::LN_2);
+    /// assert_eq!(D128::NEG_INFINITY.log_add_exp(dec128!(5)), dec128!(5));
+    /// ```
+    ///
+    /// See more about the [logarithm function](crate#logarithm-function).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn log_add_exp(self, other: Self) -> Self {
+        math::log_sum_exp::log_add_exp(self, other)
+            .round_extra_precision()
+            .check()
+    }
+
+    /// Computes _ln Σ e<sup>x</sup>_ over all the decimal numbers of the
+    /// iterator without overflowing or underflowing the intermediate
+    /// exponentials.
+    ///
+    /// The iterator is traversed once. The result for an empty iterator is
+    /// [`NEG_INFINITY`](Self::NEG_INFINITY), the logarithm of an empty sum.
+    #[doc = doc::decimal_inexact!("logarithm")]
+    ///
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// let x = [dec128!(1000), dec128!(1000), dec128!(1000), dec128!(1000)];
+    ///
+    /// assert_eq!(D128::log_sum_exp(x), dec128!(1000) + dec128!(4).ln());
+    /// assert_eq!(D128::log_sum_exp([]), D128::NEG_INFINITY);
+    /// ```
+    ///
+    /// See more about the [logarithm function](crate#logarithm-function).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub fn log_sum_exp<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        math::log_sum_exp::log_sum_exp(iter.into_iter())
+            .round_extra_precision()
+            .check()
+    }
+
     /// Calculate the length of the hypotenuse of a right-angle triangle given
     /// legs of length `x` and `y`.
     #[doc = doc::decimal_operation_panics!("hypotenuse calculate operation")]
@@ -1833,6 +2108,171 @@ impl<const N: usize> Decimal<N> {
         output
     }
 
+    /// Create string of this decimal in plain notation with exactly
+    /// `frac_digits` digits after the decimal point.
+    ///
+    /// Excess fraction digits are rounded with the [RoundingMode] of this
+    /// decimal and missing ones are padded with zeros. Unlike
+    /// [`Display`](fmt::Display) with a precision, the result is never
+    /// written in exponential form, however large or small the number is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(dec256!(5).to_fixed(2), "5.00");
+    /// assert_eq!(dec256!(1.005).to_fixed(2), "1.01");
+    /// assert_eq!(dec256!(1.005).with_rounding_mode(RoundingMode::Down).to_fixed(2), "1.00");
+    /// assert_eq!(dec256!(-2.5e-10).to_fixed(12), "-0.000000000250");
+    /// assert_eq!(dec256!(1.5e20).to_fixed(1), "150000000000000000000.0");
+    /// assert_eq!(dec256!(2.5).to_fixed(0), "3");
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub fn to_fixed(&self, frac_digits: u16) -> String {
+        let mut output = String::new();
+        self.write_fixed(frac_digits, &mut output)
+            .expect("Could not write to string");
+        output
+    }
+
+    /// Create string of this decimal in the given `radix`, e.g. `16` for the
+    /// binary expansion of the value in hexadecimal digits.
+    ///
+    /// The expansion is exact if it terminates, as it does in radix `16` for
+    /// every decimal whose fraction is a sum of powers of two. Otherwise it's
+    /// truncated where a unit in its last place gets smaller than a unit in
+    /// the last place of the decimal, and the truncation is marked by a
+    /// trailing `…`, which [`from_str_radix`](Self::from_str_radix) rejects.
+    /// Letters are lowercase, there's no prefix and no exponent.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec256!(-255.75).to_str_radix(16), "-ff.c");
+    /// assert_eq!(dec256!(0.015625).to_str_radix(2), "0.000001");
+    /// assert_eq!(dec256!(0.1).to_str_radix(16), "0.1…");
+    /// assert_eq!(dec256!(0.10).to_str_radix(16), "0.19…");
+    /// assert_eq!(dec256!(1e3).to_str_radix(8), "1750");
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        format::to_str_radix(self, radix)
+    }
+
+    /// Formats this decimal in the given [`FormatStyle`] into the beginning of
+    /// `buf` without allocating and returns the written string slice.
+    ///
+    /// A buffer of [`MAX_STR_LEN`](Self::MAX_STR_LEN) bytes holds any decimal
+    /// in any style, a smaller one is enough for all but the longest plain
+    /// notations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`fmt::Error`] if `buf` is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt;
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let mut buf = [0; 32];
+    /// let d = dec256!(-1.2345e-7);
+    ///
+    /// assert_eq!(d.format_into(&mut buf, FormatStyle::default()), Ok("-1.2345E-7"));
+    ///
+    /// let mut style = FormatStyle::default();
+    /// style.notation = Notation::Plain;
+    /// assert_eq!(d.format_into(&mut buf, style), Ok("-0.00000012345"));
+    ///
+    /// assert_eq!(d.format_into(&mut buf[..8], style), Err(fmt::Error));
+    /// ```
+    #[inline]
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        style: FormatStyle,
+    ) -> Result<&'a str, fmt::Error> {
+        format::format_into(self, buf, &style)
+    }
+
+    /// Formats this decimal in the locale-specific [`NumberFormat`], with
+    /// grouped integer digits, a custom decimal separator, a bounded number
+    /// of fraction digits and the sign placed as set.
+    ///
+    /// Fraction digits beyond the maximum are rounded with the
+    /// [RoundingMode] of this decimal. The result implements
+    /// [`Display`](fmt::Display) and is written without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let nf = NumberFormat::new()
+    ///     .with_grouping(',')
+    ///     .with_fraction_digits(2)
+    ///     .with_sign_placement(SignPlacement::Parentheses);
+    ///
+    /// assert_eq!(dec256!(1234567.891).format_with(&nf).to_string(), "1,234,567.89");
+    /// assert_eq!(dec256!(-1234.5).format_with(&nf).to_string(), "(1,234.50)");
+    ///
+    /// let d = dec256!(1.005).with_rounding_mode(RoundingMode::Down);
+    /// assert_eq!(d.format_with(&nf).to_string(), "1.00");
+    ///
+    /// let lakh = NumberFormat::new().with_grouping(',').with_group_sizes(3, 2);
+    /// assert_eq!(format!("[{:>12}]", dec256!(1234567).format_with(&lakh)), "[   12,34,567]");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn format_with(&self, nf: &NumberFormat) -> Formatted<N> {
+        Formatted::new(*self, *nf)
+    }
+
+    /// Spells out this decimal in words in the given [`WordsStyle`], as on
+    /// cheques and in legal documents.
+    ///
+    /// The integer part is written as a cardinal number of the
+    /// [`Language`] of the style, and the fraction either digit by digit
+    /// after the word for the decimal point or as a ratio like `56/100`.
+    /// Fraction digits beyond the number set in the style are rounded with
+    /// the [RoundingMode] of this decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let style = WordsStyle::new();
+    /// assert_eq!(dec256!(-0.05).to_words(&style), "minus zero point zero five");
+    /// assert_eq!(dec256!(1e6).to_words(&style), "one million");
+    ///
+    /// let cheque = style
+    ///     .with_fraction_style(FractionStyle::Ratio)
+    ///     .with_fraction_digits(2);
+    /// assert_eq!(
+    ///     dec256!(1234.555).to_words(&cheque),
+    ///     "one thousand two hundred thirty-four and 56/100"
+    /// );
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub fn to_words<L: Language>(&self, style: &WordsStyle<L>) -> String {
+        let mut output = String::new();
+        format::write_words(self, style, &mut output).expect("Could not write to string");
+        output
+    }
+
     /// Transmute the given n-bits decimal number to m-bits decimal number.
     #[doc = doc::decimal_operation_panics!("transmute operation")]
     /// # Examples
@@ -1970,13 +2410,11 @@ impl<const N: usize> Decimal<N> {
         math::tan::tan(self).round_extra_precision().check()
     }
 
-    /// Computes _arcsin(self)_ (trigonometric arcsine of decimal number).
+    /// Computes _cot(self) = cos(self) / sin(self)_ (trigonometric cotangent of a
+    /// number in radians).
+    #[doc = doc::decimal_inexact!("trigonometric cotangent")]
     ///
-    /// Return value is in radians in the range [-π/2, π/2] or `NaN`
-    /// if the number is outside the range [-1, 1].
-    #[doc = doc::decimal_inexact!("trigonometric arcsine")]
-    ///
-    #[doc = doc::decimal_operation_panics!("trigonometric arcsine operation")]
+    #[doc = doc::decimal_operation_panics!("trigonometric cotangent operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -1984,24 +2422,22 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(D128::FRAC_PI_2.sin().asin(), D128::FRAC_PI_2);
+    /// assert_eq!(D128::FRAC_PI_4.cot(), dec128!(1));
     /// ```
     ///
     /// See more about the [trigonometric
     /// functions](crate#trigonometric-functions).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn asin(self) -> Self {
-        math::asin::asin(self).round_extra_precision().check()
+    pub const fn cot(self) -> Self {
+        math::cot::cot(self).round_extra_precision().check()
     }
 
-    /// Computes _arccos(self)_ (trigonometric arccosine of decimal number).
-    ///
-    /// Return value is in radians in the range [0, π] or `NaN`
-    /// if the number is outside the range [-1, 1].
-    #[doc = doc::decimal_inexact!("trigonometric arccosine")]
+    /// Computes _sec(self) = 1 / cos(self)_ (trigonometric secant of a number in
+    /// radians).
+    #[doc = doc::decimal_inexact!("trigonometric secant")]
     ///
-    #[doc = doc::decimal_operation_panics!("trigonometric arccosine operation")]
+    #[doc = doc::decimal_operation_panics!("trigonometric secant operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2009,23 +2445,23 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec256!(1).acos(), dec256!(0));
+    /// assert_eq!(D128::ZERO.sec(), dec128!(1));
+    /// assert_eq!(D128::PI.sec(), dec128!(-1));
     /// ```
     ///
     /// See more about the [trigonometric
     /// functions](crate#trigonometric-functions).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn acos(self) -> Self {
-        math::acos::acos(self).round_extra_precision().check()
+    pub const fn sec(self) -> Self {
+        math::sec::sec(self).round_extra_precision().check()
     }
 
-    /// Computes _arctan(self)_ (trigonometric arctangent of decimal number).
-    ///
-    /// Return value is in radians in the range [-π/2, π/2].
-    #[doc = doc::decimal_inexact!("trigonometric arctangent")]
+    /// Computes _csc(self) = 1 / sin(self)_ (trigonometric cosecant of a number in
+    /// radians).
+    #[doc = doc::decimal_inexact!("trigonometric cosecant")]
     ///
-    #[doc = doc::decimal_operation_panics!("trigonometric arctangent operation")]
+    #[doc = doc::decimal_operation_panics!("trigonometric cosecant operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2033,21 +2469,176 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(D128::ZERO.atan(), D128::ZERO);
-    /// assert_eq!(D128::ONE.atan(), D128::FRAC_PI_4);
-    /// assert_eq!(D128::ONE.neg().atan(), D128::FRAC_PI_4.neg());
+    /// assert_eq!(D128::FRAC_PI_2.csc(), dec128!(1));
     /// ```
     ///
     /// See more about the [trigonometric
     /// functions](crate#trigonometric-functions).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn atan(self) -> Self {
-        math::atan::atan(self).round_extra_precision().check()
+    pub const fn csc(self) -> Self {
+        math::csc::csc(self).round_extra_precision().check()
     }
 
-    /// Computes the [_four quadrant_ arctangent](https://en.wikipedia.org/wiki/Atan2)
-    /// of `self` (`y`) and `other` (`x`).
+    /// Computes _arcsin(self)_ (trigonometric arcsine of decimal number).
+    ///
+    /// Return value is in radians in the range [-π/2, π/2] or `NaN`
+    /// if the number is outside the range [-1, 1].
+    #[doc = doc::decimal_inexact!("trigonometric arcsine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arcsine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::FRAC_PI_2.sin().asin(), D128::FRAC_PI_2);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn asin(self) -> Self {
+        math::asin::asin(self).round_extra_precision().check()
+    }
+
+    /// Computes _arccos(self)_ (trigonometric arccosine of decimal number).
+    ///
+    /// Return value is in radians in the range [0, π] or `NaN`
+    /// if the number is outside the range [-1, 1].
+    #[doc = doc::decimal_inexact!("trigonometric arccosine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arccosine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec256!(1).acos(), dec256!(0));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acos(self) -> Self {
+        math::acos::acos(self).round_extra_precision().check()
+    }
+
+    /// Computes _arctan(self)_ (trigonometric arctangent of decimal number).
+    ///
+    /// Return value is in radians in the range [-π/2, π/2].
+    #[doc = doc::decimal_inexact!("trigonometric arctangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arctangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ZERO.atan(), D128::ZERO);
+    /// assert_eq!(D128::ONE.atan(), D128::FRAC_PI_4);
+    /// assert_eq!(D128::ONE.neg().atan(), D128::FRAC_PI_4.neg());
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn atan(self) -> Self {
+        math::atan::atan(self).round_extra_precision().check()
+    }
+
+    /// Computes _arccot(self) = arctan(1/self)_ (trigonometric arccotangent of
+    /// decimal number).
+    ///
+    /// Return value is in radians in the range (-π/2, π/2], the arccotangent of
+    /// zero is π/2.
+    #[doc = doc::decimal_inexact!("trigonometric arccotangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arccotangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ZERO.acot(), D128::FRAC_PI_2);
+    /// assert_eq!(D128::ONE.acot(), D128::FRAC_PI_4);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acot(self) -> Self {
+        math::acot::acot(self).round_extra_precision().check()
+    }
+
+    /// Computes _arcsec(self) = arccos(1/self)_ (trigonometric arcsecant of
+    /// decimal number).
+    ///
+    /// Return value is in radians in the range [0, π] or `NaN` if the number is
+    /// inside the range (-1, 1).
+    #[doc = doc::decimal_inexact!("trigonometric arcsecant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arcsecant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ONE.asec(), D128::ZERO);
+    /// assert_eq!(dec128!(-1).asec(), D128::PI);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn asec(self) -> Self {
+        math::asec::asec(self).round_extra_precision().check()
+    }
+
+    /// Computes _arccsc(self) = arcsin(1/self)_ (trigonometric arccosecant of
+    /// decimal number).
+    ///
+    /// Return value is in radians in the range [-π/2, π/2] or `NaN` if the
+    /// number is inside the range (-1, 1).
+    #[doc = doc::decimal_inexact!("trigonometric arccosecant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric arccosecant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ONE.acsc(), D128::FRAC_PI_2);
+    /// assert_eq!(dec128!(2).acsc(), D128::FRAC_PI_6);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acsc(self) -> Self {
+        math::acsc::acsc(self).round_extra_precision().check()
+    }
+
+    /// Computes the [_four quadrant_ arctangent](https://en.wikipedia.org/wiki/Atan2)
+    /// of `self` (`y`) and `other` (`x`).
     ///
     /// * `x = 0`, `y = 0`: `0`
     /// * `x >= 0`: `arctan(y/x)` -> `[-π/2, π/2]`
@@ -2055,7 +2646,482 @@ impl<const N: usize> Decimal<N> {
     /// * `y < 0`: `arctan(y/x) - π` -> `(-π, -π/2)`
     #[doc = doc::decimal_inexact!("trigonometric 2-argument arctangent")]
     ///
-    #[doc = doc::decimal_operation_panics!("trigonometric 2-argument arctangent operation")]
+    #[doc = doc::decimal_operation_panics!("trigonometric 2-argument arctangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(-3.0).atan2(dec128!(3.0)), D128::FRAC_PI_4.neg());
+    /// assert_eq!(dec128!(3.0).atan2(dec128!(-3.0)), D128::FRAC_PI_4 * D128::THREE);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn atan2(self, other: Self) -> Self {
+        math::atan2::atan2(self, other)
+            .round_extra_precision()
+            .check()
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`.
+    /// Returns `(sin(x), cos(x))`.
+    #[doc = doc::decimal_inexact!("trigonometric sine and cosine function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric sine and cosine computation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::FRAC_PI_2.sin_cos(), (dec128!(1), dec128!(0)));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = math::sin_cos::sin_cos(self);
+        (
+            sin.round_extra_precision().check(),
+            cos.round_extra_precision().check(),
+        )
+    }
+
+    /// Computes the sine of a number in degrees.
+    ///
+    /// The argument is reduced by exact multiples of 90° instead of being
+    /// converted to radians, so the multiples of 30° give the correctly rounded
+    /// value and the multiples of 90° and the odd multiples of 30° are exact.
+    #[doc = doc::decimal_inexact!("trigonometric sine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric sine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(30).sin_deg(), dec128!(0.5));
+    /// assert_eq!(dec128!(-390).sin_deg(), dec128!(-0.5));
+    /// assert_eq!(dec128!(180).sin_deg(), dec128!(0));
+    /// assert!(!dec128!(150).sin_deg().is_op_inexact());
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sin_deg(self) -> Self {
+        math::deg::sin_deg(self).round_extra_precision().check()
+    }
+
+    /// Computes the cosine of a number in degrees.
+    ///
+    /// The argument is reduced by exact multiples of 90° instead of being
+    /// converted to radians, see [`sin_deg`](Self::sin_deg).
+    #[doc = doc::decimal_inexact!("trigonometric cosine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric cosine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(60).cos_deg(), dec128!(0.5));
+    /// assert_eq!(dec128!(1e30).cos_deg(), dec128!(80).cos_deg());
+    /// assert_eq!(dec128!(45).cos_deg(), D128::FRAC_1_SQRT_2);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cos_deg(self) -> Self {
+        math::deg::cos_deg(self).round_extra_precision().check()
+    }
+
+    /// Computes the tangent of a number in degrees.
+    ///
+    /// The argument is reduced by exact multiples of 90° instead of being
+    /// converted to radians, see [`sin_deg`](Self::sin_deg). At the odd
+    /// multiples of 90° the result is an infinity and a [`Division by
+    /// zero`](crate#division-by-zero) condition is raised.
+    #[doc = doc::decimal_inexact!("trigonometric tangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("trigonometric tangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(45).tan_deg(), dec128!(1));
+    /// assert_eq!(dec128!(-135).tan_deg(), dec128!(1));
+    /// assert_eq!(dec128!(60).tan_deg(), D128::SQRT_3);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn tan_deg(self) -> Self {
+        math::deg::tan_deg(self).round_extra_precision().check()
+    }
+
+    /// Computes _sinh(self)_ (hyperbolic sine of decimal number).
+    #[doc = doc::decimal_inexact!("hyperbolic sine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic sine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).sinh(), (D128::E * D128::E - dec128!(1.0)) / (dec128!(2.0) * D128::E));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sinh(self) -> Self {
+        math::sinh::sinh(self).round_extra_precision().check()
+    }
+
+    /// Computes _cosh(self)_ (hyperbolic cosine of decimal number).
+    #[doc = doc::decimal_inexact!("hyperbolic cosine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic cosine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec256!(1).cosh(), (D256::E * D256::E + dec256!(1.0)) / (dec256!(2.0) * D256::E));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cosh(self) -> Self {
+        math::cosh::cosh(self).round_extra_precision().check()
+    }
+
+    /// Computes _tanh(self)_ (hyperbolic tangent of decimal number).
+    #[doc = doc::decimal_inexact!("hyperbolic tangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic tangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// let e2 = D128::E * D128::E;
+    /// assert_eq!(dec128!(1).tanh(), (e2 - dec128!(1.0)) / (e2 + dec128!(1.0)));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn tanh(self) -> Self {
+        math::tanh::tanh(self).round_extra_precision().check()
+    }
+
+    /// Computes _coth(self) = cosh(self) / sinh(self)_ (hyperbolic cotangent of
+    /// decimal number).
+    #[doc = doc::decimal_inexact!("hyperbolic cotangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic cotangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).coth(), dec128!(1.31303528549933130363616124693084783291));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn coth(self) -> Self {
+        math::coth::coth(self).round_extra_precision().check()
+    }
+
+    /// Computes _sech(self) = 1 / cosh(self)_ (hyperbolic secant of decimal
+    /// number).
+    ///
+    /// Unlike `1 / cosh(self)` it doesn't overflow for large arguments.
+    #[doc = doc::decimal_inexact!("hyperbolic secant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic secant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ZERO.sech(), dec128!(1));
+    /// assert_eq!(dec128!(100000).sech(), dec128!(5.6504764237681719218465155941187727778E-43430));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sech(self) -> Self {
+        math::sech::sech(self).round_extra_precision().check()
+    }
+
+    /// Computes _csch(self) = 1 / sinh(self)_ (hyperbolic cosecant of decimal
+    /// number).
+    #[doc = doc::decimal_inexact!("hyperbolic cosecant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("hyperbolic cosecant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).csch(), dec128!(0.85091812823932154513384276328717528418));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn csch(self) -> Self {
+        math::csch::csch(self).round_extra_precision().check()
+    }
+
+    /// Computes _arsinh(self)_ (inverse hyperbolic sine of decimal number).
+    #[doc = doc::decimal_inexact!("inverse hyperbolic sine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic sine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).sinh().asinh(), dec128!(1));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn asinh(self) -> Self {
+        math::asinh::asinh(self).round_extra_precision().check()
+    }
+
+    /// Computes _arcosh(self)_ (inverse hyperbolic cosine of decimal number).
+    #[doc = doc::decimal_inexact!("inverse hyperbolic cosine")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic cosine operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).cosh().acosh(), dec128!(1));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acosh(self) -> Self {
+        math::acosh::acosh(self).round_extra_precision().check()
+    }
+
+    /// Computes _artanh(self)_ (inverse hyperbolic tangent of decimal number).
+    #[doc = doc::decimal_inexact!("inverse hyperbolic tangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic tangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D256::ZERO.tanh().atanh(), D256::ZERO);
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn atanh(self) -> Self {
+        math::atanh::atanh(self).round_extra_precision().check()
+    }
+
+    /// Computes _arcoth(self) = artanh(1/self)_ (inverse hyperbolic cotangent of
+    /// decimal number).
+    ///
+    /// The argument must lie outside the range (-1, 1).
+    #[doc = doc::decimal_inexact!("inverse hyperbolic cotangent")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic cotangent operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(3).acoth(), dec128!(0.34657359027997265470861606072908828404));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acoth(self) -> Self {
+        math::acoth::acoth(self).round_extra_precision().check()
+    }
+
+    /// Computes _arsech(self) = arcosh(1/self)_ (inverse hyperbolic secant of
+    /// decimal number).
+    ///
+    /// The argument must lie in the range (0, 1].
+    #[doc = doc::decimal_inexact!("inverse hyperbolic secant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic secant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(D128::ONE.asech(), D128::ZERO);
+    /// assert_eq!(dec128!(0.5).asech(), dec128!(2).acosh());
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn asech(self) -> Self {
+        math::asech::asech(self).round_extra_precision().check()
+    }
+
+    /// Computes _arcsch(self) = arsinh(1/self)_ (inverse hyperbolic cosecant of
+    /// decimal number).
+    #[doc = doc::decimal_inexact!("inverse hyperbolic cosecant")]
+    ///
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic cosecant operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1e20).acsch(), dec128!(1.0E-20));
+    /// ```
+    ///
+    /// See more about the [trigonometric
+    /// functions](crate#trigonometric-functions).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn acsch(self) -> Self {
+        math::acsch::acsch(self).round_extra_precision().check()
+    }
+
+    /// Computes _Γ(self)_ (gamma function of decimal number).
+    ///
+    /// For positive integers _Γ(n) = (n − 1)!_ and the result is
+    /// [exact](Self::factorial) when it fits. Zero is a pole of _Γ_, at which
+    /// the result is an infinity of the same sign with the
+    /// [division by zero](crate#signaling-flags-and-trap-enablers) signal, and
+    /// the negative integers are outside its domain.
+    #[doc = doc::decimal_inexact!("gamma function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("gamma function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(5).gamma(), dec128!(24));
+    /// assert_eq!(dec128!(0.5).gamma(), D128::PI.sqrt());
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn gamma(self) -> Self {
+        math::gamma::gamma(self).round_extra_precision().check()
+    }
+
+    /// Computes _ln|Γ(self)|_ (natural logarithm of the absolute value of the
+    /// gamma function of decimal number).
+    ///
+    /// Unlike [`gamma`](Self::gamma) it doesn't overflow for large arguments.
+    /// Zero and the negative integers are poles, at which the result is
+    /// positive infinity with the
+    /// [division by zero](crate#signaling-flags-and-trap-enablers) signal.
+    #[doc = doc::decimal_inexact!("logarithm of the gamma function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("logarithm of the gamma function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(1).ln_gamma(), D128::ZERO);
+    /// assert_eq!(dec128!(5).ln_gamma(), dec128!(24).ln());
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ln_gamma(self) -> Self {
+        math::gamma::ln_gamma(self).round_extra_precision().check()
+    }
+
+    /// Computes _self!_ (factorial of decimal number).
+    ///
+    /// The argument must be a non-negative integer. The result is exact while
+    /// it fits the coefficient and is rounded after that.
+    #[doc = doc::decimal_inexact!("factorial")]
+    ///
+    #[doc = doc::decimal_operation_panics!("factorial operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2063,25 +3129,28 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec128!(-3.0).atan2(dec128!(3.0)), D128::FRAC_PI_4.neg());
-    /// assert_eq!(dec128!(3.0).atan2(dec128!(-3.0)), D128::FRAC_PI_4 * D128::THREE);
+    /// assert_eq!(dec128!(0).factorial(), dec128!(1));
+    /// assert_eq!(dec128!(20).factorial(), dec128!(2432902008176640000));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [gamma function](crate#gamma-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn atan2(self, other: Self) -> Self {
-        math::atan2::atan2(self, other)
+    pub const fn factorial(self) -> Self {
+        math::factorial::factorial(self)
             .round_extra_precision()
             .check()
     }
 
-    /// Simultaneously computes the sine and cosine of the number, `x`.
-    /// Returns `(sin(x), cos(x))`.
-    #[doc = doc::decimal_inexact!("trigonometric sine and cosine function")]
+    /// Computes the binomial coefficient _C(self, k)_, the number of ways to
+    /// choose `k` elements out of `self`.
     ///
-    #[doc = doc::decimal_operation_panics!("trigonometric sine and cosine computation")]
+    /// `self` must be a non-negative integer and `k` an integer, the result is
+    /// zero for _k < 0_ and _k > self_. It is exact while it fits the
+    /// coefficient and is rounded after that.
+    #[doc = doc::decimal_inexact!("binomial coefficient")]
+    ///
+    #[doc = doc::decimal_operation_panics!("binomial coefficient operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2089,25 +3158,27 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(D128::FRAC_PI_2.sin_cos(), (dec128!(1), dec128!(0)));
+    /// assert_eq!(dec128!(10).binomial(dec128!(3)), dec128!(120));
+    /// assert_eq!(dec128!(10).binomial(dec128!(11)), dec128!(0));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [gamma function](crate#gamma-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn sin_cos(self) -> (Self, Self) {
-        let (sin, cos) = math::sin_cos::sin_cos(self);
-        (
-            sin.round_extra_precision().check(),
-            cos.round_extra_precision().check(),
-        )
+    pub const fn binomial(self, k: Self) -> Self {
+        math::binomial::binomial(self, k)
+            .round_extra_precision()
+            .check()
     }
 
-    /// Computes _sinh(self)_ (hyperbolic sine of decimal number).
-    #[doc = doc::decimal_inexact!("hyperbolic sine")]
+    /// Computes _B(self, b) = Γ(self) Γ(b) / Γ(self + b)_ (beta function of
+    /// decimal numbers).
     ///
-    #[doc = doc::decimal_operation_panics!("hyperbolic sine operation")]
+    /// Zero and the negative integers are outside the domain of both
+    /// arguments.
+    #[doc = doc::decimal_inexact!("beta function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("beta function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2115,21 +3186,22 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec128!(1).sinh(), (D128::E * D128::E - dec128!(1.0)) / (dec128!(2.0) * D128::E));
+    /// assert_eq!(dec128!(2).beta(dec128!(3)), dec128!(1) / dec128!(12));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [gamma function](crate#gamma-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn sinh(self) -> Self {
-        math::sinh::sinh(self).round_extra_precision().check()
+    pub const fn beta(self, b: Self) -> Self {
+        math::beta::beta(self, b).round_extra_precision().check()
     }
 
-    /// Computes _cosh(self)_ (hyperbolic cosine of decimal number).
-    #[doc = doc::decimal_inexact!("hyperbolic cosine")]
+    /// Computes _erf(self)_ (error function of decimal number).
     ///
-    #[doc = doc::decimal_operation_panics!("hyperbolic cosine operation")]
+    /// _erf(x) = 2/√π ∫<sub>0</sub><sup>x</sup> e<sup>−t²</sup> dt_.
+    #[doc = doc::decimal_inexact!("error function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("error function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2137,21 +3209,26 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec256!(1).cosh(), (D256::E * D256::E + dec256!(1.0)) / (dec256!(2.0) * D256::E));
+    /// assert_eq!(dec128!(0).erf(), dec128!(0));
+    /// assert_eq!(dec128!(1).erf(), dec128!(0.84270079294971486934122063508260925930));
+    /// assert_eq!(D128::NEG_INFINITY.erf(), dec128!(-1));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [error function](crate#error-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn cosh(self) -> Self {
-        math::cosh::cosh(self).round_extra_precision().check()
+    pub const fn erf(self) -> Self {
+        math::erf::erf(self).round_extra_precision().check()
     }
 
-    /// Computes _tanh(self)_ (hyperbolic tangent of decimal number).
-    #[doc = doc::decimal_inexact!("hyperbolic tangent")]
+    /// Computes _erfc(self) = 1 − erf(self)_ (complementary error function of
+    /// decimal number).
     ///
-    #[doc = doc::decimal_operation_panics!("hyperbolic tangent operation")]
+    /// For large arguments the result is computed directly instead of being
+    /// subtracted from one, so it keeps its full relative precision.
+    #[doc = doc::decimal_inexact!("complementary error function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("complementary error function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2159,22 +3236,25 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// let e2 = D128::E * D128::E;
-    /// assert_eq!(dec128!(1).tanh(), (e2 - dec128!(1.0)) / (e2 + dec128!(1.0)));
+    /// assert_eq!(dec128!(0).erfc(), dec128!(1));
+    /// assert_eq!(dec128!(10).erfc(), dec128!(2.08848758376254475700078629495778861156E-45));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [error function](crate#error-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn tanh(self) -> Self {
-        math::tanh::tanh(self).round_extra_precision().check()
+    pub const fn erfc(self) -> Self {
+        math::erf::erfc(self).round_extra_precision().check()
     }
 
-    /// Computes _arsinh(self)_ (inverse hyperbolic sine of decimal number).
-    #[doc = doc::decimal_inexact!("inverse hyperbolic sine")]
+    /// Computes _Φ(self)_ (cumulative distribution function of the standard
+    /// normal distribution).
     ///
-    #[doc = doc::decimal_operation_panics!("inverse hyperbolic sine operation")]
+    /// _Φ(x) = erfc(−x/√2) / 2_, which keeps its full relative precision in
+    /// the lower tail.
+    #[doc = doc::decimal_inexact!("normal distribution function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("normal distribution function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2182,21 +3262,25 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec128!(1).sinh().asinh(), dec128!(1));
+    /// assert_eq!(dec128!(0).norm_cdf(), dec128!(0.5));
+    /// assert_eq!(dec128!(1.96).norm_cdf(), dec128!(0.97500210485177956586341573095916280998));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [error function](crate#error-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn asinh(self) -> Self {
-        math::asinh::asinh(self).round_extra_precision().check()
+    pub const fn norm_cdf(self) -> Self {
+        math::norm::norm_cdf(self).round_extra_precision().check()
     }
 
-    /// Computes _arcosh(self)_ (inverse hyperbolic cosine of decimal number).
-    #[doc = doc::decimal_inexact!("inverse hyperbolic cosine")]
+    /// Computes _Φ<sup>−1</sup>(self)_ (quantile function of the standard
+    /// normal distribution), the inverse of [`norm_cdf`](Self::norm_cdf).
     ///
-    #[doc = doc::decimal_operation_panics!("inverse hyperbolic cosine operation")]
+    /// The argument is a probability, _0 <= self <= 1_. The quantiles of `0`
+    /// and `1` are the negative and positive infinity respectively.
+    #[doc = doc::decimal_inexact!("normal quantile function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("normal quantile function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2204,21 +3288,24 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(dec128!(1).cosh().acosh(), dec128!(1));
+    /// assert_eq!(dec128!(0.5).norm_ppf(), dec128!(0));
+    /// assert_eq!(dec128!(0.975).norm_ppf(), dec128!(1.95996398454005423552459443052055152796));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [error function](crate#error-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn acosh(self) -> Self {
-        math::acosh::acosh(self).round_extra_precision().check()
+    pub const fn norm_ppf(self) -> Self {
+        math::norm::norm_ppf(self).round_extra_precision().check()
     }
 
-    /// Computes _artanh(self)_ (inverse hyperbolic tangent of decimal number).
-    #[doc = doc::decimal_inexact!("inverse hyperbolic tangent")]
+    /// Computes _W<sub>0</sub>(self)_ (principal branch of the Lambert W
+    /// function), the solution _w >= −1_ of _w e<sup>w</sup> = self_.
     ///
-    #[doc = doc::decimal_operation_panics!("inverse hyperbolic tangent operation")]
+    /// The argument must be at least _−1/e_.
+    #[doc = doc::decimal_inexact!("Lambert W function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("Lambert W function operation")]
     /// # Examples
     ///
     /// Basic usage:
@@ -2226,15 +3313,45 @@ impl<const N: usize> Decimal<N> {
     /// ```
     /// use fastnum::*;
     ///
-    /// assert_eq!(D256::ZERO.tanh().atanh(), D256::ZERO);
+    /// assert_eq!(dec128!(0).lambert_w0(), dec128!(0));
+    /// assert_eq!(D128::E.lambert_w0(), dec128!(1));
+    /// assert_eq!(dec128!(1).lambert_w0(), dec128!(0.56714329040978387299996866221035554975));
     /// ```
     ///
-    /// See more about the [trigonometric
-    /// functions](crate#trigonometric-functions).
+    /// See more about the [Lambert W function](crate#lambert-w-function).
     #[must_use = doc::must_use_op!()]
     #[inline]
-    pub const fn atanh(self) -> Self {
-        math::atanh::atanh(self).round_extra_precision().check()
+    pub const fn lambert_w0(self) -> Self {
+        math::lambert_w::lambert_w0(self)
+            .round_extra_precision()
+            .check()
+    }
+
+    /// Computes _W<sub>−1</sub>(self)_ (lower branch of the Lambert W
+    /// function), the solution _w <= −1_ of _w e<sup>w</sup> = self_.
+    ///
+    /// The argument must lie in _−1/e <= self < 0_. The result tends to the
+    /// negative infinity as the argument tends to zero.
+    #[doc = doc::decimal_inexact!("Lambert W function")]
+    ///
+    #[doc = doc::decimal_operation_panics!("Lambert W function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(dec128!(-0.1).lambert_wm1(), dec128!(-3.5771520639572972184093919635119948804));
+    /// ```
+    ///
+    /// See more about the [Lambert W function](crate#lambert-w-function).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn lambert_wm1(self) -> Self {
+        math::lambert_w::lambert_wm1(self)
+            .round_extra_precision()
+            .check()
     }
 
     /// Converts from [UnsignedDecimal] to a signed [Decimal] number.
@@ -2270,6 +3387,255 @@ impl<const N: usize> Decimal<N> {
     }
 }
 
+/// # Correctly rounded functions
+///
+/// Variants of the transcendental functions whose results are correctly
+/// rounded under the active [RoundingMode]. They take the width `M` of the
+/// intermediate decimal as a const generic parameter.
+impl<const N: usize> Decimal<N> {
+    /// Returns _e<sup>self</sup>_ correctly rounded, see [`exp`](Self::exp).
+    #[doc = doc::decimal_correctly_rounded!("exponential function")]
+    #[doc = doc::decimal_operation_panics!("exponent calculation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::RoundingMode};
+    ///
+    /// let x = dec128!(1).with_rounding_mode(RoundingMode::Floor);
+    ///
+    /// assert_eq!(x.exp_correctly_rounded::<4>(), dec128!(2.71828182845904523536028747135266249775));
+    /// assert_eq!(dec128!(1).exp_correctly_rounded::<4>(), D128::E);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn exp_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::exp::<N, M>(self).check()
+    }
+
+    /// Returns _e<sup>self</sup> – 1_ correctly rounded, see [`exp_m1`](Self::exp_m1).
+    #[doc = doc::decimal_correctly_rounded!("exponential function")]
+    #[doc = doc::decimal_operation_panics!("exponent calculation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn exp_m1_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::exp_m1::<N, M>(self).check()
+    }
+
+    /// Returns _2<sup>self</sup>_ correctly rounded, see [`exp2`](Self::exp2).
+    #[doc = doc::decimal_correctly_rounded!("binary exponential function")]
+    #[doc = doc::decimal_operation_panics!("binary exponential function")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn exp2_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::exp2::<N, M>(self).check()
+    }
+
+    /// Returns the natural logarithm of the decimal number correctly rounded, see [`ln`](Self::ln).
+    #[doc = doc::decimal_correctly_rounded!("natural logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn ln_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::ln::<N, M>(self).check()
+    }
+
+    /// Returns _ln(1 + self)_ correctly rounded, see [`ln_1p`](Self::ln_1p).
+    #[doc = doc::decimal_correctly_rounded!("natural logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn ln_1p_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::ln_1p::<N, M>(self).check()
+    }
+
+    /// Returns the binary logarithm of the decimal number correctly rounded, see [`log2`](Self::log2).
+    #[doc = doc::decimal_correctly_rounded!("logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn log2_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::log2::<N, M>(self).check()
+    }
+
+    /// Returns the decimal logarithm of the decimal number correctly rounded, see [`log10`](Self::log10).
+    #[doc = doc::decimal_correctly_rounded!("logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn log10_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::log10::<N, M>(self).check()
+    }
+
+    /// Returns the _base_ logarithm of the decimal number correctly rounded, see [`log`](Self::log).
+    #[doc = doc::decimal_correctly_rounded!("logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn log_correctly_rounded<const M: usize>(self, base: Self) -> Self {
+        math::correctly_rounded::log::<N, M>(self, base).check()
+    }
+
+    /// Returns the decimal number raised to the power of `n` correctly rounded, see [`pow`](Self::pow).
+    #[doc = doc::decimal_correctly_rounded!("power operation")]
+    #[doc = doc::decimal_operation_panics!("power operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn pow_correctly_rounded<const M: usize>(self, n: Self) -> Self {
+        math::correctly_rounded::pow::<N, M>(self, n).check()
+    }
+
+    /// Returns the square root of the decimal number correctly rounded, see [`sqrt`](Self::sqrt).
+    #[doc = doc::decimal_correctly_rounded!("square root operation")]
+    #[doc = doc::decimal_operation_panics!("square root operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn sqrt_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::sqrt::<N, M>(self).check()
+    }
+
+    /// Returns the cubic root of the decimal number correctly rounded, see [`cbrt`](Self::cbrt).
+    #[doc = doc::decimal_correctly_rounded!("cubic root operation")]
+    #[doc = doc::decimal_operation_panics!("cubic root operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn cbrt_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::cbrt::<N, M>(self).check()
+    }
+
+    /// Returns _sin(self)_ correctly rounded, see [`sin`](Self::sin).
+    #[doc = doc::decimal_correctly_rounded!("trigonometric sine")]
+    #[doc = doc::decimal_operation_panics!("trigonometric sine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn sin_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::sin::<N, M>(self).check()
+    }
+
+    /// Returns _cos(self)_ correctly rounded, see [`cos`](Self::cos).
+    #[doc = doc::decimal_correctly_rounded!("trigonometric cosine")]
+    #[doc = doc::decimal_operation_panics!("trigonometric cosine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn cos_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::cos::<N, M>(self).check()
+    }
+
+    /// Returns _tan(self)_ correctly rounded, see [`tan`](Self::tan).
+    #[doc = doc::decimal_correctly_rounded!("trigonometric tangent")]
+    #[doc = doc::decimal_operation_panics!("trigonometric tangent operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn tan_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::tan::<N, M>(self).check()
+    }
+
+    /// Returns _arcsin(self)_ correctly rounded, see [`asin`](Self::asin).
+    #[doc = doc::decimal_correctly_rounded!("arcsine")]
+    #[doc = doc::decimal_operation_panics!("trigonometric arcsine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn asin_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::asin::<N, M>(self).check()
+    }
+
+    /// Returns _arccos(self)_ correctly rounded, see [`acos`](Self::acos).
+    #[doc = doc::decimal_correctly_rounded!("arccosine")]
+    #[doc = doc::decimal_operation_panics!("trigonometric arccosine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn acos_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::acos::<N, M>(self).check()
+    }
+
+    /// Returns _arctan(self)_ correctly rounded, see [`atan`](Self::atan).
+    #[doc = doc::decimal_correctly_rounded!("arctangent")]
+    #[doc = doc::decimal_operation_panics!("trigonometric arctangent operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn atan_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::atan::<N, M>(self).check()
+    }
+
+    /// Returns _sinh(self)_ correctly rounded, see [`sinh`](Self::sinh).
+    #[doc = doc::decimal_correctly_rounded!("hyperbolic sine")]
+    #[doc = doc::decimal_operation_panics!("hyperbolic sine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn sinh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::sinh::<N, M>(self).check()
+    }
+
+    /// Returns _cosh(self)_ correctly rounded, see [`cosh`](Self::cosh).
+    #[doc = doc::decimal_correctly_rounded!("hyperbolic cosine")]
+    #[doc = doc::decimal_operation_panics!("hyperbolic cosine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn cosh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::cosh::<N, M>(self).check()
+    }
+
+    /// Returns _tanh(self)_ correctly rounded, see [`tanh`](Self::tanh).
+    #[doc = doc::decimal_correctly_rounded!("hyperbolic tangent")]
+    #[doc = doc::decimal_operation_panics!("hyperbolic tangent operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn tanh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::tanh::<N, M>(self).check()
+    }
+
+    /// Returns _arsinh(self)_ correctly rounded, see [`asinh`](Self::asinh).
+    #[doc = doc::decimal_correctly_rounded!("inverse hyperbolic sine")]
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic sine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn asinh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::asinh::<N, M>(self).check()
+    }
+
+    /// Returns _arcosh(self)_ correctly rounded, see [`acosh`](Self::acosh).
+    #[doc = doc::decimal_correctly_rounded!("inverse hyperbolic cosine")]
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic cosine operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn acosh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::acosh::<N, M>(self).check()
+    }
+
+    /// Returns _artanh(self)_ correctly rounded, see [`atanh`](Self::atanh).
+    #[doc = doc::decimal_correctly_rounded!("inverse hyperbolic tangent")]
+    #[doc = doc::decimal_operation_panics!("inverse hyperbolic tangent operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn atanh_correctly_rounded<const M: usize>(self) -> Self {
+        math::correctly_rounded::atanh::<N, M>(self).check()
+    }
+}
+
 impl<const N: usize> Decimal<N> {
     pub(crate) const SIGNALING_NAN: Self = Self::new(UInt::ZERO, ControlBlock::SIGNALING_NAN, Context::DEFAULT);
 
@@ -2409,13 +3775,14 @@ impl<const N: usize> Decimal<N> {
             return w.write_str("Inf");
         }
 
-        if self.is_zero() {
-            return w.write_str("0e0");
-        }
-
-        let digits = self.digits.to_str_radix(10);
-        let scale = self.cb.get_scale();
-        format::write_scientific_notation(digits, scale, w)
+        let mut buf = [[0; 20]; N];
+        let digits = format::ascii_digits(&self.digits, &mut buf);
+        format::write_scientific_notation(
+            digits,
+            self.cb.get_scale(),
+            &self.ctx.exponent_style(),
+            w,
+        )
     }
 
     /// Write unsigned decimal in engineering notation to writer `w`.
@@ -2432,12 +3799,22 @@ impl<const N: usize> Decimal<N> {
             return w.write_str("Inf");
         }
 
+        let exp_style = &self.ctx.exponent_style();
+
         if self.is_zero() {
-            return w.write_str("0e0");
+            return format::write_engineering_notation("0", 0, exp_style, w);
         }
 
-        let digits = self.digits.to_str_radix(10);
-        let scale = self.cb.get_scale();
-        format::write_engineering_notation(digits, scale, w)
+        let mut buf = [[0; 20]; N];
+        let digits = radix::to_str_radix_into(&self.digits, buf.as_flattened_mut(), 10)
+            .ok_or(fmt::Error)?;
+        format::write_engineering_notation(digits, self.cb.get_scale(), exp_style, w)
+    }
+
+    /// Write decimal in plain notation with exactly `frac_digits` digits
+    /// after the decimal point to writer `w`, see [`to_fixed`](Self::to_fixed).
+    pub fn write_fixed<W: fmt::Write>(&self, frac_digits: u16, w: &mut W) -> fmt::Result {
+        let nf = NumberFormat::new().with_fraction_digits(frac_digits);
+        format::write_number_format(self, &nf, w)
     }
 }
@@ -1,9 +1,15 @@
 mod from_float;
 mod from_int;
 mod from_str;
+mod from_str_detailed;
+mod from_str_radix;
+mod from_str_with;
 mod from_uint;
 
 pub(crate) use from_float::{from_f32, from_f64};
 pub(crate) use from_int::*;
-pub(crate) use from_str::from_slice;
+pub(crate) use from_str::{from_slice, from_slice_exact, into_result};
+pub(crate) use from_str_detailed::{from_str_detailed, from_str_prefix};
+pub(crate) use from_str_radix::from_str_radix;
+pub(crate) use from_str_with::from_str_with;
 pub(crate) use from_uint::*;
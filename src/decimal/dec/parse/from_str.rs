@@ -2,7 +2,10 @@ use core::str::from_utf8_unchecked;
 
 use crate::{
     decimal::{
-        dec::{construct::construct_with_clength, ExtraPrecision},
+        dec::{
+            construct::{construct, construct_with_clength},
+            ExtraPrecision,
+        },
         signals::Signals,
         Context, Decimal, DecimalError, ParseError, Sign,
     },
@@ -174,6 +177,109 @@ pub const fn from_slice<const N: usize>(
         clength,
     );
 
+    into_result(dec)
+}
+
+/// Creates and initializes a Decimal from string, failing with
+/// [ParseError::Inexact] only if non-zero digits don't fit into the
+/// coefficient.
+#[inline]
+pub const fn from_slice_exact<const N: usize>(
+    buf: &[u8],
+    ctx: Context,
+) -> Result<Decimal<N>, ParseError> {
+    let error = match from_slice(buf, ctx) {
+        Err(e @ (ParseError::PosOverflow | ParseError::NegOverflow)) => e,
+        res => return res,
+    };
+
+    let len = buf.len();
+
+    let mut end = 0;
+    while end < len && !matches!(buf[end], b'e' | b'E') {
+        end += 1;
+    }
+
+    // Parse the significand up to the last non-zero digit, then add as many
+    // of the trailing zeros as fit.
+    let mut cut = end;
+    while cut > 0 && matches!(buf[cut - 1], b'0' | b'.' | b'_') {
+        cut -= 1;
+    }
+
+    let (head, _) = buf.split_at(cut);
+
+    let dec: Decimal<N> = match from_slice(head, ctx) {
+        Ok(dec) => dec,
+        Err(ParseError::PosOverflow | ParseError::NegOverflow) => {
+            return Err(ParseError::Inexact);
+        }
+        Err(_) => return Err(error),
+    };
+
+    let mut value = dec.digits;
+    let mut exp = dec.cb.get_exponent();
+    let mut fraction = contains_dot(head);
+    let mut ovf;
+
+    let mut i = cut;
+    while i < end {
+        match buf[i] {
+            b'0' => {
+                let next;
+                (next, ovf) = overflowing_mul10(value, 1);
+
+                if !ovf {
+                    value = next;
+                }
+
+                // A kept fraction zero lowers the exponent, a dropped integer
+                // zero raises it.
+                let inc = match (ovf, fraction) {
+                    (false, true) => -1,
+                    (true, false) => 1,
+                    _ => 0,
+                };
+
+                let Some(next) = exp.checked_add(inc) else {
+                    return Err(ParseError::ExponentOverflow);
+                };
+                exp = next;
+            }
+            b'.' => {
+                fraction = true;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if end < len {
+        let exponent_value = match parse_exp(buf, end + 1) {
+            Ok(exp) => exp,
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        let Some(next) = exp.checked_add(exponent_value) else {
+            return Err(ParseError::ExponentOverflow);
+        };
+        exp = next;
+    }
+
+    into_result(construct(
+        value,
+        exp,
+        dec.sign(),
+        Signals::empty(),
+        ctx,
+        ExtraPrecision::new(),
+    ))
+}
+
+#[inline]
+pub(crate) const fn into_result<const N: usize>(dec: Decimal<N>) -> Result<Decimal<N>, ParseError> {
     if dec.is_nan() {
         return Err(ParseError::Unknown);
     } else if dec.is_infinite() {
@@ -190,6 +296,18 @@ pub const fn from_slice<const N: usize>(
     }
 }
 
+#[inline]
+const fn contains_dot(buf: &[u8]) -> bool {
+    let mut i = 0;
+    while i < buf.len() {
+        if buf[i] == b'.' {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
 #[inline]
 const fn checked_u32_i32(u: u32) -> Option<i32> {
     let max = i32::MAX as u32;
@@ -0,0 +1,171 @@
+use crate::decimal::{
+    dec::parse::from_slice, Context, Decimal, ExpectedToken, ParseError, ParseErrorDetail,
+};
+
+/// Creates and initializes a Decimal from string, reporting where parsing
+/// failed.
+pub(crate) fn from_str_detailed<const N: usize>(
+    s: &str,
+    ctx: Context,
+) -> Result<Decimal<N>, ParseErrorDetail> {
+    let buf = s.as_bytes();
+    from_slice(buf, ctx).map_err(|kind| locate::<N>(buf, kind, ctx))
+}
+
+/// Creates and initializes a Decimal from the longest prefix of string which
+/// is a number, returning it with the number of bytes it takes.
+pub(crate) fn from_str_prefix<const N: usize>(
+    s: &str,
+    ctx: Context,
+) -> Result<(Decimal<N>, usize), ParseErrorDetail> {
+    let buf = s.as_bytes();
+    let scan = scan(buf);
+
+    if scan.end == 0 {
+        let kind = match from_slice::<N>(buf, ctx) {
+            Err(kind) => kind,
+            Ok(_) => ParseError::InvalidLiteral,
+        };
+        return Err(ParseErrorDetail::new(kind, scan.stop, Some(scan.expected)));
+    }
+
+    let head = &buf[..scan.end];
+
+    match from_slice(head, ctx) {
+        Ok(dec) => Ok((dec, scan.end)),
+        Err(kind) => Err(locate::<N>(head, kind, ctx)),
+    }
+}
+
+/// Result of scanning a string for the syntax accepted by
+/// [from_slice].
+struct Scan {
+    /// Length of the longest prefix which is a number.
+    end: usize,
+
+    /// Position where scanning stopped and the token expected there.
+    stop: usize,
+    expected: ExpectedToken,
+
+    /// Position of the significand, after the sign.
+    significand: usize,
+
+    /// Position of the exponent, after `e`.
+    exponent: Option<usize>,
+}
+
+/// Attaches the position to the error `kind` of parsing `buf`.
+fn locate<const N: usize>(buf: &[u8], kind: ParseError, ctx: Context) -> ParseErrorDetail {
+    let scan = scan(buf);
+
+    if scan.end == 0 || scan.end < buf.len() {
+        return ParseErrorDetail::new(kind, scan.stop, Some(scan.expected));
+    }
+
+    // The string is well-formed, so its value doesn't fit. The overflow is
+    // caused by the exponent if the significand alone fits.
+    let position = match (kind, scan.exponent) {
+        (ParseError::ExponentOverflow, Some(exponent)) => exponent,
+        (ParseError::PosOverflow | ParseError::NegOverflow, Some(exponent))
+            if from_slice::<N>(&buf[..exponent - 1], ctx).is_ok() =>
+        {
+            exponent
+        }
+        _ => scan.significand,
+    };
+
+    ParseErrorDetail::new(kind, position, None)
+}
+
+fn scan(buf: &[u8]) -> Scan {
+    let len = buf.len();
+
+    let mut scan = Scan {
+        end: 0,
+        stop: 0,
+        expected: ExpectedToken::Sign,
+        significand: 0,
+        exponent: None,
+    };
+
+    if starts_with_ci(buf, b"nan") {
+        return scan.complete(3);
+    }
+
+    let mut i = 0;
+
+    if i < len && matches!(buf[i], b'+' | b'-') {
+        i += 1;
+    }
+
+    scan.significand = i;
+
+    let rest = &buf[i..];
+    if starts_with_ci(rest, b"infinity") {
+        return scan.complete(i + 8);
+    } else if starts_with_ci(rest, b"inf") {
+        return scan.complete(i + 3);
+    }
+
+    let mut digits = false;
+    let mut dot = false;
+
+    while i < len {
+        match buf[i] {
+            b'0'..=b'9' => digits = true,
+            b'_' => {}
+            b'.' if !dot => dot = true,
+            _ => break,
+        }
+        i += 1;
+    }
+
+    if !digits {
+        scan.stop = i;
+        if i > 0 {
+            scan.expected = ExpectedToken::Digit;
+        }
+        return scan;
+    }
+
+    scan = scan.complete(i);
+
+    if i < len && matches!(buf[i], b'e' | b'E') {
+        let exponent = i + 1;
+        let mut j = exponent;
+
+        if j < len && matches!(buf[j], b'+' | b'-') {
+            j += 1;
+        }
+
+        let start = j;
+        while j < len && buf[j].is_ascii_digit() {
+            j += 1;
+        }
+
+        if j == start {
+            scan.stop = j;
+            scan.expected = ExpectedToken::Exponent;
+        } else {
+            scan = scan.complete(j);
+            scan.exponent = Some(exponent);
+        }
+    }
+
+    scan
+}
+
+impl Scan {
+    #[inline]
+    fn complete(mut self, end: usize) -> Self {
+        self.end = end;
+        self.stop = end;
+        self.expected = ExpectedToken::End;
+        self
+    }
+}
+
+#[inline]
+fn starts_with_ci(buf: &[u8], prefix: &[u8]) -> bool {
+    buf.len() >= prefix.len() && buf[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
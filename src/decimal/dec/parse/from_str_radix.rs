@@ -0,0 +1,219 @@
+use crate::{
+    decimal::{
+        dec::{
+            construct::construct,
+            math::{div::div, mul::mul},
+            parse::from_str::{from_slice, into_result},
+            ExtraPrecision,
+        },
+        signals::Signals,
+        Context, Decimal, ParseError, Sign,
+    },
+    int::{math::div_rem_digit, UInt},
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Largest power of two by which the value is scaled at once.
+const POW2_CHUNK: u32 = 63;
+
+/// Largest power of five by which the value is scaled at once, _5<sup>27</sup>
+/// < 2<sup>63</sup>_.
+const POW5_CHUNK: u32 = 27;
+
+/// Creates and initializes a Decimal from string in the given `radix`.
+pub(crate) fn from_str_radix<const N: usize>(
+    s: &str,
+    radix: u32,
+    ctx: Context,
+) -> Result<D<N>, ParseError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseError::InvalidRadix);
+    }
+
+    if radix == 10 {
+        return from_slice(s.as_bytes(), ctx);
+    }
+
+    let (sign, s) = match s.as_bytes().first() {
+        Some(b'+') => (Sign::Plus, &s[1..]),
+        Some(b'-') => (Sign::Minus, &s[1..]),
+        _ => (Sign::Plus, s),
+    };
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    if s.eq_ignore_ascii_case("nan") {
+        return match sign {
+            Sign::Plus => Ok(D::NAN),
+            Sign::Minus => Err(ParseError::InvalidLiteral),
+        };
+    }
+
+    if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
+        return Ok(D::INFINITY.set_ctx(ctx).set_sign(sign));
+    }
+
+    let s = strip_prefix(s, radix);
+
+    // The binary exponent is only accepted where `p` isn't a digit and scales
+    // the value exactly.
+    let pow2 = radix.is_power_of_two() && radix <= 16;
+
+    let (significand, exponent) = match s.find(['p', 'P']) {
+        Some(i) if pow2 => (&s[..i], Some(&s[i + 1..])),
+        _ => (s, None),
+    };
+
+    let mut value = UInt::<N>::ZERO;
+    let mut fraction_digits: i64 = 0;
+    let mut has_digits = false;
+    let mut fraction = false;
+
+    for c in significand.chars() {
+        match c {
+            '.' if !fraction => {
+                fraction = true;
+            }
+            '_' => {}
+            _ => {
+                let Some(digit) = c.to_digit(radix) else {
+                    return Err(ParseError::InvalidLiteral);
+                };
+
+                value = value
+                    .checked_mul(UInt::from_digit(radix as _))
+                    .and_then(|value| value.checked_add(UInt::from_digit(digit as _)))
+                    .ok_or(overflow(sign))?;
+
+                if fraction {
+                    fraction_digits += 1;
+                }
+                has_digits = true;
+            }
+        }
+    }
+
+    if !has_digits {
+        return Err(ParseError::Empty);
+    }
+
+    let exponent = match exponent {
+        Some(exponent) => parse_exp(exponent)?,
+        None => 0,
+    };
+
+    // Trailing zeros of the fraction would become trailing zeros of the
+    // decimal.
+    let mut exp;
+
+    if pow2 {
+        exp = exponent - fraction_digits * radix.trailing_zeros() as i64;
+
+        if exp < 0 && !value.is_zero() {
+            let shift = (value.trailing_zeros() as i64).min(-exp);
+            value = value.shr(shift as u32);
+            exp += shift;
+        }
+    } else {
+        exp = fraction_digits;
+
+        while exp > 0 && !value.is_zero() {
+            let (q, r) = div_rem_digit(value, radix as _);
+            if r != 0 {
+                break;
+            }
+            value = q;
+            exp -= 1;
+        }
+    }
+
+    let d = construct(value, 0, sign, Signals::empty(), ctx, ExtraPrecision::new());
+
+    let d = if pow2 {
+        mul_pow2(d, exp, ctx)
+    } else {
+        div_pow(d, radix, exp, ctx)
+    };
+
+    into_result(d.round_extra_precision())
+}
+
+/// Multiplies `d` by _2<sup>exp</sup>_.
+fn mul_pow2<const N: usize>(mut d: D<N>, mut exp: i64, ctx: Context) -> D<N> {
+    while exp > 0 && !d.is_zero() && d.is_finite() {
+        let n = exp.min(POW2_CHUNK as i64) as u32;
+        d = mul(d, factor(1 << n, 0, ctx));
+        exp -= n as i64;
+    }
+
+    // 2^-n = 5^n * 10^-n
+    while exp < 0 && !d.is_zero() && d.is_finite() {
+        let n = (-exp).min(POW5_CHUNK as i64) as u32;
+        d = mul(d, factor(5u64.pow(n), -(n as i32), ctx));
+        exp += n as i64;
+    }
+
+    d
+}
+
+/// Divides `d` by _radix<sup>exp</sup>_.
+fn div_pow<const N: usize>(mut d: D<N>, radix: u32, mut exp: i64, ctx: Context) -> D<N> {
+    // radix^chunk < 2^64
+    let chunk = 64 / (radix.ilog2() + 1);
+
+    while exp > 0 && !d.is_zero() {
+        let n = exp.min(chunk as i64) as u32;
+        d = div(d, factor((radix as u64).pow(n), 0, ctx));
+        exp -= n as i64;
+    }
+
+    d
+}
+
+#[inline]
+fn factor<const N: usize>(digits: u64, exp: i32, ctx: Context) -> D<N> {
+    construct(
+        UInt::from_digit(digits),
+        exp,
+        Sign::Plus,
+        Signals::empty(),
+        ctx,
+        ExtraPrecision::new(),
+    )
+}
+
+#[inline]
+fn strip_prefix(s: &str, radix: u32) -> &str {
+    let prefix = match radix {
+        2 => ["0b", "0B"],
+        8 => ["0o", "0O"],
+        16 => ["0x", "0X"],
+        _ => return s,
+    };
+
+    prefix
+        .iter()
+        .find_map(|prefix| s.strip_prefix(prefix))
+        .unwrap_or(s)
+}
+
+#[inline]
+fn parse_exp(s: &str) -> Result<i64, ParseError> {
+    s.parse::<i32>()
+        .map(|exp| exp as i64)
+        .map_err(|e| match ParseError::from(e) {
+            ParseError::PosOverflow | ParseError::NegOverflow => ParseError::ExponentOverflow,
+            e => e,
+        })
+}
+
+#[inline(always)]
+const fn overflow(sign: Sign) -> ParseError {
+    match sign {
+        Sign::Minus => ParseError::NegOverflow,
+        Sign::Plus => ParseError::PosOverflow,
+    }
+}
@@ -0,0 +1,379 @@
+use crate::{
+    decimal::{
+        dec::{
+            construct::construct,
+            parse::{from_slice_exact, into_result},
+            ExtraPrecision,
+        },
+        round::round_pair_digits,
+        signals::Signals,
+        Context, Decimal, ExcessDigits, ParseError, ParseOptions, RoundingMode, Sign,
+    },
+    int::{
+        math::{div_rem_digit, overflowing_mul10},
+        UInt,
+    },
+};
+
+/// Creates and initializes a Decimal from string in the forms enabled by
+/// `opts`.
+///
+/// The string is rewritten into the syntax of [from_slice](super::from_slice)
+/// and parsed by [from_slice_exact], only the digits which don't fit into the
+/// coefficient are rounded off here if `opts` says so.
+pub(crate) fn from_str_with<const N: usize>(
+    s: &str,
+    ctx: Context,
+    opts: &ParseOptions,
+) -> Result<Decimal<N>, ParseError> {
+    let mut s = trim(s, opts);
+    let mut sign = None;
+
+    if opts.parentheses {
+        if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            s = trim(inner, opts);
+            sign = Some(Sign::Minus);
+        }
+    }
+
+    let mut percent = false;
+
+    if opts.percent {
+        if let Some(rest) = s.strip_suffix('%') {
+            s = trim(rest, opts);
+            percent = true;
+        }
+    }
+
+    if opts.trailing_sign {
+        if let Some((rest, trailing)) = split_sign(s, false) {
+            (s, sign) = (trim(rest, opts), Some(merge_sign(sign, trailing)?));
+        }
+    }
+
+    // the sign may stand on either side of a leading currency symbol
+    if let Some((rest, leading)) = split_sign(s, true) {
+        (s, sign) = (trim(rest, opts), Some(merge_sign(sign, leading)?));
+    }
+
+    if opts.currency_symbol {
+        s = strip_currency_symbol(s, opts);
+
+        if let Some((rest, leading)) = split_sign(s, true) {
+            (s, sign) = (trim(rest, opts), Some(merge_sign(sign, leading)?));
+        }
+    }
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut buf = Vec::with_capacity(s.len() + 1);
+
+    match sign {
+        Some(Sign::Plus) => buf.push(b'+'),
+        Some(Sign::Minus) => buf.push(b'-'),
+        None => {}
+    }
+
+    let start = buf.len();
+    let special = ["nan", "inf", "infinity"]
+        .iter()
+        .any(|special| s.eq_ignore_ascii_case(special));
+
+    if special {
+        // a percentage is a number
+        if percent {
+            return Err(ParseError::InvalidLiteral);
+        }
+        buf.extend_from_slice(s.as_bytes());
+    } else {
+        normalize(s, opts, &mut buf)?;
+    }
+
+    let dec = match from_slice_exact(&buf, ctx) {
+        Err(ParseError::Inexact) => match opts.excess_digits {
+            ExcessDigits::Error => return Err(ParseError::Inexact),
+            ExcessDigits::Round => round_excess(&buf[start..], sign, ctx, ctx.rounding_mode())?,
+            ExcessDigits::Truncate => round_excess(&buf[start..], sign, ctx, RoundingMode::Down)?,
+        },
+        res => res?,
+    };
+
+    if !percent {
+        return Ok(dec);
+    }
+
+    let exp = dec
+        .cb
+        .get_exponent()
+        .checked_sub(2)
+        .ok_or(ParseError::ExponentOverflow)?;
+
+    into_result(construct(
+        dec.digits,
+        exp,
+        dec.sign(),
+        dec.cb.get_signals(),
+        ctx,
+        ExtraPrecision::new(),
+    ))
+}
+
+/// Rewrites the number `s` into `buf` in the syntax of
+/// [from_slice](super::from_slice): without grouping separators and with `.`
+/// as the decimal separator.
+fn normalize(s: &str, opts: &ParseOptions, buf: &mut Vec<u8>) -> Result<(), ParseError> {
+    let mut has_digits = false;
+    let mut fraction = false;
+    let mut after_group_separator = false;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '0'..='9' => {
+                buf.push(c as u8);
+                has_digits = true;
+                after_group_separator = false;
+            }
+            _ if c == opts.decimal_separator && !fraction && !after_group_separator => {
+                buf.push(b'.');
+                fraction = true;
+            }
+            _ if Some(c) == opts.grouping_separator
+                && !fraction
+                && has_digits
+                && !after_group_separator =>
+            {
+                after_group_separator = true;
+            }
+            '_' if opts.underscores => buf.push(b'_'),
+            'e' | 'E' if !after_group_separator => {
+                // the exponent is already in the syntax of `from_slice`
+                buf.extend_from_slice(&s.as_bytes()[i..]);
+                return Ok(());
+            }
+            _ => return Err(ParseError::InvalidLiteral),
+        }
+    }
+
+    if after_group_separator {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    Ok(())
+}
+
+/// Parses the normalized number `buf`, whose non-zero digits don't all fit
+/// into the coefficient, rounding off the excess digits.
+fn round_excess<const N: usize>(
+    buf: &[u8],
+    sign: Option<Sign>,
+    ctx: Context,
+    rounding_mode: RoundingMode,
+) -> Result<Decimal<N>, ParseError> {
+    let sign = sign.unwrap_or(Sign::Plus);
+    let Number { value, exp, excess } = parse_number(buf)?;
+
+    let (value, exp) = match excess {
+        Some(excess) => round(value, exp, excess, sign, rounding_mode)?,
+        None => (value, exp),
+    };
+
+    into_result(construct(
+        value,
+        exp,
+        sign,
+        Signals::OP_ROUNDED.combine(Signals::OP_INEXACT),
+        ctx,
+        ExtraPrecision::new(),
+    ))
+}
+
+/// Coefficient and exponent of a parsed number.
+struct Number<const N: usize> {
+    value: UInt<N>,
+    exp: i32,
+
+    /// The first of the digits that didn't fit into the coefficient and
+    /// whether all others are zeros.
+    excess: Option<(u8, bool)>,
+}
+
+/// Parses the digits of a normalized number which
+/// [from_slice_exact] has already checked.
+fn parse_number<const N: usize>(buf: &[u8]) -> Result<Number<N>, ParseError> {
+    let mut number = Number {
+        value: UInt::ZERO,
+        exp: 0,
+        excess: None,
+    };
+
+    let mut fraction = false;
+
+    for (i, &c) in buf.iter().enumerate() {
+        match c {
+            b'0'..=b'9' => push_digit(&mut number, c - b'0', fraction)?,
+            b'.' => fraction = true,
+            b'e' | b'E' => {
+                let exponent = parse_exp(&buf[i + 1..])?;
+                number.exp = number
+                    .exp
+                    .checked_add(exponent)
+                    .ok_or(ParseError::ExponentOverflow)?;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(number)
+}
+
+#[inline]
+fn push_digit<const N: usize>(
+    number: &mut Number<N>,
+    digit: u8,
+    fraction: bool,
+) -> Result<(), ParseError> {
+    match &mut number.excess {
+        None => {
+            let (value, ovf) = overflowing_mul10(number.value, 1);
+            let (value, carry) = value.overflowing_add(UInt::from_digit(digit as _));
+
+            if !ovf && !carry {
+                number.value = value;
+                if fraction {
+                    number.exp = number
+                        .exp
+                        .checked_sub(1)
+                        .ok_or(ParseError::ExponentOverflow)?;
+                }
+                return Ok(());
+            }
+
+            number.excess = Some((digit, true));
+        }
+        Some((_, trailing_zeros)) => {
+            *trailing_zeros &= digit == 0;
+        }
+    }
+
+    // the integer digits that don't fit shift the coefficient
+    if !fraction {
+        number.exp = number
+            .exp
+            .checked_add(1)
+            .ok_or(ParseError::ExponentOverflow)?;
+    }
+
+    Ok(())
+}
+
+/// Rounds the coefficient with the `excess` digits dropped.
+fn round<const N: usize>(
+    value: UInt<N>,
+    exp: i32,
+    excess: (u8, bool),
+    sign: Sign,
+    rounding_mode: RoundingMode,
+) -> Result<(UInt<N>, i32), ParseError> {
+    let (_, last) = div_rem_digit(value, 10);
+    let (first, trailing_zeros) = excess;
+
+    if round_pair_digits((last as u8, first), sign, rounding_mode, trailing_zeros) == last as u8 {
+        return Ok((value, exp));
+    }
+
+    match value.overflowing_add(UInt::ONE) {
+        (value, false) => Ok((value, exp)),
+        _ => {
+            // the coefficient is `UInt::MAX`, which doesn't end with a 9
+            let (value, _) = div_rem_digit(value, 10);
+            let exp = exp.checked_add(1).ok_or(ParseError::ExponentOverflow)?;
+            Ok((value.strict_add(UInt::ONE), exp))
+        }
+    }
+}
+
+#[inline]
+fn parse_exp(buf: &[u8]) -> Result<i32, ParseError> {
+    let s = core::str::from_utf8(buf).map_err(|_| ParseError::InvalidLiteral)?;
+    s.parse::<i32>().map_err(|e| match ParseError::from(e) {
+        ParseError::PosOverflow | ParseError::NegOverflow => ParseError::ExponentOverflow,
+        e => e,
+    })
+}
+
+/// Splits a leading or a trailing `+` or `-` from `s`.
+#[inline]
+fn split_sign(s: &str, leading: bool) -> Option<(&str, Sign)> {
+    let (rest, c) = if leading {
+        let c = s.chars().next()?;
+        (&s[c.len_utf8()..], c)
+    } else {
+        let c = s.chars().next_back()?;
+        (&s[..s.len() - c.len_utf8()], c)
+    };
+
+    match c {
+        '+' => Some((rest, Sign::Plus)),
+        '-' => Some((rest, Sign::Minus)),
+        _ => None,
+    }
+}
+
+#[inline]
+fn merge_sign(sign: Option<Sign>, other: Sign) -> Result<Sign, ParseError> {
+    match sign {
+        None => Ok(other),
+        Some(_) => Err(ParseError::InvalidLiteral),
+    }
+}
+
+#[inline]
+fn strip_currency_symbol<'a>(s: &'a str, opts: &ParseOptions) -> &'a str {
+    if let Some(c) = s.chars().next().filter(|&c| is_currency_symbol(c)) {
+        trim(&s[c.len_utf8()..], opts)
+    } else if let Some(c) = s.chars().next_back().filter(|&c| is_currency_symbol(c)) {
+        trim(&s[..s.len() - c.len_utf8()], opts)
+    } else {
+        s
+    }
+}
+
+#[inline]
+fn trim<'a>(s: &'a str, opts: &ParseOptions) -> &'a str {
+    if opts.whitespace {
+        s.trim()
+    } else {
+        s
+    }
+}
+
+/// Characters of the Unicode category _Currency Symbol_ (Sc).
+#[inline]
+const fn is_currency_symbol(c: char) -> bool {
+    matches!(
+        c,
+        '$' | '\u{A2}'..='\u{A5}'
+            | '\u{58F}'
+            | '\u{60B}'
+            | '\u{7FE}'..='\u{7FF}'
+            | '\u{9F2}'..='\u{9F3}'
+            | '\u{9FB}'
+            | '\u{AF1}'
+            | '\u{BF9}'
+            | '\u{E3F}'
+            | '\u{17DB}'
+            | '\u{20A0}'..='\u{20C0}'
+            | '\u{A838}'
+            | '\u{FDFC}'
+            | '\u{FE69}'
+            | '\u{FF04}'
+            | '\u{FFE0}'..='\u{FFE1}'
+            | '\u{FFE5}'..='\u{FFE6}'
+            | '\u{11FDD}'..='\u{11FE0}'
+            | '\u{1E2FF}'
+            | '\u{1ECB0}'
+    )
+}
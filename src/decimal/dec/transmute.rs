@@ -1,34 +1,25 @@
 use crate::{
     decimal::{dec::scale::rescale, Decimal},
-    int::UInt,
+    int::resize,
 };
 
 type D<const N: usize> = Decimal<N>;
 
 #[inline]
 pub(crate) const fn transmute<const N: usize, const M: usize>(mut d: D<N>) -> D<M> {
-    let mut digits = [0; M];
-    let mut i = 0;
-
     if M >= N {
-        while i < N {
-            digits[i] = d.digits.digits()[i];
-            i += 1;
-        }
-    } else {
-        // FIXME
-        if UInt::<N>::BITS - d.digits.leading_zeros() > UInt::<M>::BITS {
-            while UInt::<N>::BITS - d.digits.leading_zeros() > UInt::<M>::BITS {
+        return D::new(resize::widen(d.digits), d.cb, d.ctx);
+    }
+
+    // Move the least significant digits into the extra precision until the
+    // coefficient fits.
+    loop {
+        match resize::try_narrow(d.digits) {
+            Some(digits) => return D::new(digits, d.cb, d.ctx),
+            None => {
                 let scale = d.cb.get_scale() - 1;
                 rescale(&mut d, scale);
             }
         }
-
-        while i < M {
-            digits[i] = d.digits.digits()[i];
-            i += 1;
-        }
     }
-
-    D::new(UInt::from_digits(digits), d.cb, d.ctx)
 }
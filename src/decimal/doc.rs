@@ -53,3 +53,28 @@ macro_rules! decimal_inexact {
 }
 
 pub(crate) use decimal_inexact;
+
+macro_rules! decimal_correctly_rounded {
+    ($op: literal) => {
+        concat!(
+            "\n\n# Precision\n\n",
+            "The ",
+            $op,
+            " is evaluated in the wider `Decimal<M>` and rounded once to the precision of `Self` ",
+            "under the active [`RoundingMode`](crate::decimal::RoundingMode).\n\n",
+            "When the wide result lies within _10<sup>-k/2</sup>_ units in the last place of a ",
+            "rounding boundary, where _k_ is the number of digits it holds beyond `Self`, the ",
+            "rounding can't be decided from it. The value on the boundary is then checked for ",
+            "being the exact result, otherwise the operation is evaluated again in those of ",
+            "`Decimal<8>`, `Decimal<16>`, … `Decimal<128>` which are wider than `Decimal<M>`, until ",
+            "the rounding is decided. A result which can't be decided in `Decimal<128>` is `NaN` ",
+            "with the [`Invalid`](crate#invalid-operation) signal. `M = 2·N` is a reasonable ",
+            "choice.\n\n",
+            "# Panics:\n\n",
+            "This method will panic if `M <= N`.",
+            "\n\n"
+        )
+    };
+}
+
+pub(crate) use decimal_correctly_rounded;
@@ -2,4 +2,4 @@ pub(crate) mod decimal;
 pub(crate) mod parse;
 
 pub use decimal::DecimalError;
-pub use parse::ParseError;
+pub use parse::{ExpectedToken, ParseError, ParseErrorDetail};
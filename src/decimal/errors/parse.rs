@@ -44,6 +44,14 @@ pub enum ParseError {
     /// Exponent is too large to store in decimal type.
     ExponentOverflow,
 
+    /// The number has more significant digits than the decimal type can
+    /// hold.
+    ///
+    /// This variant will be emitted by the exact parsing functions, such as
+    /// [`Decimal::from_str_exact`](crate::decimal::Decimal::from_str_exact),
+    /// instead of discarding non-zero digits.
+    Inexact,
+
     /// Value was Signed
     ///
     /// This variant will be emitted when the parsing string has a sign literal,
@@ -66,8 +74,9 @@ impl ParseError {
             PosOverflow => "number too large to fit in target type",
             NegOverflow => "number too small to fit in target type",
             Signed => "number would be signed for unsigned type",
-            InvalidRadix => "radix for decimal must be 10",
+            InvalidRadix => "radix for decimal must be in the range 2..=36",
             ExponentOverflow => "exponent is too large to fit in target type",
+            Inexact => "number cannot be represented exactly in target type",
             Unknown => "unknown error",
         }
     }
@@ -110,6 +119,135 @@ impl core::error::Error for ParseError {
     }
 }
 
+/// Kind of token a parser expected where parsing a decimal failed, see
+/// [ParseErrorDetail].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExpectedToken {
+    /// The start of a number: a sign, a digit, a decimal point, `NaN` or
+    /// `Inf`.
+    Sign,
+
+    /// A digit of the significand, e.g. after a lone sign or decimal point.
+    Digit,
+
+    /// The exponent after `e` or `E`: digits with an optional sign.
+    Exponent,
+
+    /// The end of the string after a complete number.
+    End,
+}
+
+impl ExpectedToken {
+    pub(crate) const fn description(&self) -> &str {
+        use ExpectedToken::*;
+        match self {
+            Sign => "sign or digit",
+            Digit => "digit",
+            Exponent => "exponent",
+            End => "end of string",
+        }
+    }
+}
+
+impl Display for ExpectedToken {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Error of parsing a decimal with the position in the string where it
+/// occurred.
+///
+/// Emitted by [`Decimal::from_str_detailed`](crate::decimal::Decimal::from_str_detailed)
+/// and [`Decimal::from_str_prefix`](crate::decimal::Decimal::from_str_prefix).
+/// The [position](Self::position) is the byte offset of the offending
+/// character. If the value doesn't fit into the target type, it is the
+/// offset of the exponent when the significand alone fits, and of the number
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// use fastnum::{*, decimal::*};
+///
+/// let e = D128::from_str_detailed("1.5e+x", Context::default()).unwrap_err();
+///
+/// assert_eq!(e.kind(), ParseError::InvalidLiteral);
+/// assert_eq!(e.position(), 5);
+/// assert_eq!(e.expected(), Some(ExpectedToken::Exponent));
+/// assert_eq!(e.to_string(), "(fastnum) invalid literal found in string at byte 5, expected exponent");
+/// ```
+#[derive(Copy, Clone, PartialEq)]
+pub struct ParseErrorDetail {
+    kind: ParseError,
+    position: usize,
+    expected: Option<ExpectedToken>,
+}
+
+impl ParseErrorDetail {
+    #[inline]
+    pub(crate) const fn new(
+        kind: ParseError,
+        position: usize,
+        expected: Option<ExpectedToken>,
+    ) -> Self {
+        Self {
+            kind,
+            position,
+            expected,
+        }
+    }
+
+    /// Get the [ParseError] which [`from_str`](crate::decimal::Decimal::from_str)
+    /// returns for the same string.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ParseError {
+        self.kind
+    }
+
+    /// Get the byte offset in the string where parsing failed.
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the kind of token expected at the [position](Self::position), or
+    /// `None` if the string is well-formed but its value is not accepted.
+    #[must_use]
+    #[inline]
+    pub const fn expected(&self) -> Option<ExpectedToken> {
+        self.expected
+    }
+}
+
+impl Display for ParseErrorDetail {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.position)?;
+        if let Some(expected) = self.expected {
+            write!(f, ", expected {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ParseErrorDetail {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+impl From<ParseErrorDetail> for ParseError {
+    #[inline]
+    fn from(e: ParseErrorDetail) -> ParseError {
+        e.kind
+    }
+}
+
+impl core::error::Error for ParseErrorDetail {}
+
 #[allow(dead_code)]
 pub(crate) fn pretty_error_msg(ty: &str, e: ParseError) -> String {
     use ParseError::*;
@@ -119,8 +257,9 @@ pub(crate) fn pretty_error_msg(ty: &str, e: ParseError) -> String {
         PosOverflow => "overflow",
         NegOverflow => "negative overflow",
         Signed => "does not support negative values",
-        InvalidRadix => "radix MUST be 10",
+        InvalidRadix => "radix MUST be in the range 2..=36",
         ExponentOverflow => "exponent overflow",
+        Inexact => "inexact",
         Unknown => "decimal unknown error",
     };
 
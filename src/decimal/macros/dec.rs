@@ -37,13 +37,17 @@ macro_rules! macro_impl {
         #[doc = concat!("const N: ", stringify!($DEC), " = ", stringify!($name), "!(A1.23456789);")]
         /// ```
         ///
+        /// Literals are parsed exactly: trailing zeros which don't fit into the
+        /// coefficient are dropped, but a literal with more significant digits
+        /// than the type can hold fails to compile.
+        ///
         /// This allows you to perform all the necessary checks such as potentialy overflow or calculation accuracy loss and others at the compile time.
         /// Protect from unexpected errors in runtime.
         ///
         macro_rules! $name {
             ($d($d body:tt)*) => {{
                 const __CTX: $crate::decimal::Context = $crate::decimal::Context::default();
-                const __DECIMAL: $crate::$DEC = $crate::$DEC::parse_str(concat!($d(stringify!($d body)),*), __CTX);
+                const __DECIMAL: $crate::$DEC = $crate::$DEC::parse_str_exact(concat!($d(stringify!($d body)),*), __CTX);
                 __DECIMAL
             }};
         }
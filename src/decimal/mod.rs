@@ -13,8 +13,11 @@ pub(crate) mod round;
 pub(crate) mod udec;
 
 mod context;
+mod number_format;
+mod parse_options;
 mod sign;
 mod signals;
+mod words;
 
 #[cfg(debug_assertions)]
 mod assertions;
@@ -27,12 +30,15 @@ pub(crate) mod errors;
 #[macro_use]
 mod macros;
 
-pub use context::{Context, Notation, FormatStyle, RoundingMode, SignalsTraps};
+pub use context::{Context, ExponentStyle, Notation, FormatStyle, RoundingMode, SignalsTraps};
 pub use dec::Decimal;
-pub use errors::{DecimalError, ParseError};
+pub use errors::{DecimalError, ExpectedToken, ParseError, ParseErrorDetail};
+pub use number_format::{Formatted, NumberFormat, SignPlacement};
+pub use parse_options::{ExcessDigits, ParseOptions};
 pub use sign::Sign;
 pub use signals::Signals;
 pub use udec::UnsignedDecimal;
+pub use words::{English, FractionStyle, Language, WordsStyle};
 
 use crate::decimal::doc::decimal_type_doc;
 
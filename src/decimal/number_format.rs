@@ -0,0 +1,245 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    decimal::{dec::format, Decimal},
+    utils::err_msg,
+};
+
+/// Placement of the sign in a number formatted with a [NumberFormat].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SignPlacement {
+    /// Minus sign before negative numbers: `-1,234.50`.
+    #[default]
+    Leading,
+
+    /// Minus sign before negative numbers and plus sign before positive ones:
+    /// `+1,234.50`.
+    LeadingAlways,
+
+    /// Minus sign after negative numbers: `1,234.50-`.
+    Trailing,
+
+    /// Negative numbers enclosed in parentheses, as in accounting:
+    /// `(1,234.50)`.
+    Parentheses,
+}
+
+/// # Number Format
+///
+/// Locale-specific format of a decimal number: the grouping of the integer
+/// digits, the decimal separator, the number of fraction digits and the
+/// placement of the sign.
+///
+/// Numbers are always written in plain notation. Fraction digits beyond
+/// [`max_fraction_digits`](Self::max_fraction_digits) are rounded with the
+/// [RoundingMode](crate::decimal::RoundingMode) of the decimal, then zeros are
+/// appended up to [`min_fraction_digits`](Self::min_fraction_digits). Trailing
+/// zeros of the decimal in between are kept. A negative number whose written
+/// digits are all zeros, like `-0.001` with two fraction digits, is written
+/// without its sign.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{*, decimal::*};
+///
+/// let d = dec128!(-1234567.891);
+///
+/// let en = NumberFormat::new()
+///     .with_grouping(',')
+///     .with_max_fraction_digits(2);
+/// assert_eq!(d.format_with(&en).to_string(), "-1,234,567.89");
+///
+/// let de = en.with_grouping('.').with_decimal_separator(',');
+/// assert_eq!(d.format_with(&de).to_string(), "-1.234.567,89");
+///
+/// let fr = en.with_grouping(' ').with_decimal_separator(',');
+/// assert_eq!(d.format_with(&fr).to_string(), "-1 234 567,89");
+///
+/// let lakh = en.with_group_sizes(3, 2);
+/// assert_eq!(d.format_with(&lakh).to_string(), "-12,34,567.89");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    grouping_separator: Option<char>,
+    primary_group_size: u8,
+    secondary_group_size: u8,
+    decimal_separator: char,
+    min_fraction_digits: u16,
+    max_fraction_digits: Option<u16>,
+    sign_placement: SignPlacement,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl NumberFormat {
+    /// The default `NumberFormat`: no grouping, `.` as the decimal separator
+    /// and all fraction digits of the decimal.
+    pub const DEFAULT: Self = Self {
+        grouping_separator: None,
+        primary_group_size: 3,
+        secondary_group_size: 3,
+        decimal_separator: '.',
+        min_fraction_digits: 0,
+        max_fraction_digits: None,
+        sign_placement: SignPlacement::Leading,
+    };
+
+    /// Returns the [default](Self::DEFAULT) `NumberFormat`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Groups the integer digits with the given `separator`, by three digits
+    /// unless [other group sizes](Self::with_group_sizes) are set.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_grouping(mut self, separator: char) -> Self {
+        self.grouping_separator = Some(separator);
+        self
+    }
+
+    /// Writes the integer digits without grouping.
+    #[must_use]
+    #[inline(always)]
+    pub const fn without_grouping(mut self) -> Self {
+        self.grouping_separator = None;
+        self
+    }
+
+    /// Sets the size of the `primary` group, next to the decimal separator,
+    /// and of the `secondary` groups before it, e.g. `(3, 2)` for the Indian
+    /// `12,34,567` and `(4, 4)` for the Chinese `123,4567`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a group size is zero.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_group_sizes(mut self, primary: u8, secondary: u8) -> Self {
+        if primary == 0 || secondary == 0 {
+            panic!(err_msg!("group sizes should be greater than zero!"));
+        }
+        self.primary_group_size = primary;
+        self.secondary_group_size = secondary;
+        self
+    }
+
+    /// Sets the `separator` between the integer and the fraction digits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Pads the fraction digits with zeros to at least `digits`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_min_fraction_digits(mut self, digits: u16) -> Self {
+        self.min_fraction_digits = digits;
+        self
+    }
+
+    /// Rounds the fraction digits to at most `digits`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_max_fraction_digits(mut self, digits: u16) -> Self {
+        self.max_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Sets both the minimum and the maximum number of fraction digits to
+    /// `digits`, as for amounts of money.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_fraction_digits(self, digits: u16) -> Self {
+        self.with_min_fraction_digits(digits)
+            .with_max_fraction_digits(digits)
+    }
+
+    /// Sets the [SignPlacement].
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_sign_placement(mut self, sign_placement: SignPlacement) -> Self {
+        self.sign_placement = sign_placement;
+        self
+    }
+
+    /// Get the grouping separator, if the integer digits are grouped.
+    #[must_use]
+    #[inline(always)]
+    pub const fn grouping_separator(&self) -> Option<char> {
+        self.grouping_separator
+    }
+
+    /// Get the sizes of the primary and the secondary digit groups.
+    #[must_use]
+    #[inline(always)]
+    pub const fn group_sizes(&self) -> (u8, u8) {
+        (self.primary_group_size, self.secondary_group_size)
+    }
+
+    /// Get the decimal separator.
+    #[must_use]
+    #[inline(always)]
+    pub const fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Get the minimum number of fraction digits, never more than the
+    /// maximum.
+    #[must_use]
+    #[inline(always)]
+    pub const fn min_fraction_digits(&self) -> u16 {
+        match self.max_fraction_digits {
+            Some(max) if max < self.min_fraction_digits => max,
+            _ => self.min_fraction_digits,
+        }
+    }
+
+    /// Get the maximum number of fraction digits, if limited.
+    #[must_use]
+    #[inline(always)]
+    pub const fn max_fraction_digits(&self) -> Option<u16> {
+        self.max_fraction_digits
+    }
+
+    /// Get the [SignPlacement].
+    #[must_use]
+    #[inline(always)]
+    pub const fn sign_placement(&self) -> SignPlacement {
+        self.sign_placement
+    }
+}
+
+/// Decimal number formatted with a [NumberFormat], see
+/// [`Decimal::format_with`].
+///
+/// Writing it doesn't allocate. Width, fill and alignment of the formatter
+/// are applied to the whole number.
+#[derive(Copy, Clone, Debug)]
+pub struct Formatted<const N: usize> {
+    value: Decimal<N>,
+    format: NumberFormat,
+}
+
+impl<const N: usize> Formatted<N> {
+    #[inline(always)]
+    pub(crate) const fn new(value: Decimal<N>, format: NumberFormat) -> Self {
+        Self { value, format }
+    }
+}
+
+impl<const N: usize> Display for Formatted<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        format::pad_number_format(&self.value, &self.format, f)
+    }
+}
@@ -0,0 +1,173 @@
+/// What to do with the digits of a string that don't fit into the coefficient
+/// of the decimal type, see [ParseOptions].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExcessDigits {
+    /// Fail with [ParseError::Inexact](crate::decimal::ParseError::Inexact)
+    /// unless all of them are zeros, as
+    /// [`Decimal::from_str_exact`](crate::decimal::Decimal::from_str_exact).
+    #[default]
+    Error,
+
+    /// Round them off with the [RoundingMode](crate::decimal::RoundingMode)
+    /// of the [Context](crate::decimal::Context), raising the `Rounded` and
+    /// `Inexact` signals.
+    Round,
+
+    /// Drop them, raising the `Rounded` and `Inexact` signals.
+    Truncate,
+}
+
+/// # Parse Options
+///
+/// Forms of decimal number strings accepted by
+/// [`Decimal::from_str_with`](crate::decimal::Decimal::from_str_with) beyond
+/// the strict syntax of
+/// [`Decimal::from_str`](crate::decimal::Decimal::from_str).
+///
+/// The string is rewritten into the strict syntax and parsed as by
+/// [`Decimal::from_str_exact`](crate::decimal::Decimal::from_str_exact), so
+/// the default options give the same result as `from_str_exact`. This is the
+/// result of `from_str`, except that digits which don't fit into the
+/// coefficient are dropped if they are zeros and fail with
+/// [ParseError::Inexact](crate::decimal::ParseError::Inexact) otherwise,
+/// where `from_str` fails with an overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{*, decimal::*};
+///
+/// let opts = ParseOptions::new()
+///     .with_grouping_separator('.')
+///     .with_decimal_separator(',')
+///     .allow_whitespace(true)
+///     .allow_parentheses(true)
+///     .allow_currency_symbol(true);
+///
+/// assert_eq!(D128::from_str_with(" 1.234.567,89 € ", Context::default(), &opts), Ok(dec128!(1234567.89)));
+/// assert_eq!(D128::from_str_with("(€ 12,50)", Context::default(), &opts), Ok(dec128!(-12.50)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub(crate) grouping_separator: Option<char>,
+    pub(crate) decimal_separator: char,
+    pub(crate) underscores: bool,
+    pub(crate) whitespace: bool,
+    pub(crate) parentheses: bool,
+    pub(crate) trailing_sign: bool,
+    pub(crate) currency_symbol: bool,
+    pub(crate) percent: bool,
+    pub(crate) excess_digits: ExcessDigits,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ParseOptions {
+    /// The default `ParseOptions`: the syntax of
+    /// [`Decimal::from_str`](crate::decimal::Decimal::from_str), parsed as by
+    /// [`Decimal::from_str_exact`](crate::decimal::Decimal::from_str_exact).
+    pub const DEFAULT: Self = Self {
+        grouping_separator: None,
+        decimal_separator: '.',
+        underscores: true,
+        whitespace: false,
+        parentheses: false,
+        trailing_sign: false,
+        currency_symbol: false,
+        percent: false,
+        excess_digits: ExcessDigits::Error,
+    };
+
+    /// Returns the [default](Self::DEFAULT) `ParseOptions`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Skips the given `separator` between the integer digits, e.g. `,` in
+    /// `1,234,567`. The size of the digit groups isn't checked.
+    ///
+    /// The [decimal separator](Self::with_decimal_separator) takes precedence
+    /// if both are the same.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = Some(separator);
+        self
+    }
+
+    /// Sets the `separator` between the integer and the fraction digits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Whether `_` is skipped between the digits, as by
+    /// [`Decimal::from_str`](crate::decimal::Decimal::from_str). Allowed by
+    /// default.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_underscores(mut self, allow: bool) -> Self {
+        self.underscores = allow;
+        self
+    }
+
+    /// Whether whitespace around the number, its sign and its currency symbol
+    /// is skipped.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_whitespace(mut self, allow: bool) -> Self {
+        self.whitespace = allow;
+        self
+    }
+
+    /// Whether a number enclosed in parentheses, as in accounting, is
+    /// negative: `(123.45)`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_parentheses(mut self, allow: bool) -> Self {
+        self.parentheses = allow;
+        self
+    }
+
+    /// Whether the sign may follow the number: `123.45-`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_trailing_sign(mut self, allow: bool) -> Self {
+        self.trailing_sign = allow;
+        self
+    }
+
+    /// Whether a currency symbol such as `$`, `€` or `₹` is skipped before or
+    /// after the number.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_currency_symbol(mut self, allow: bool) -> Self {
+        self.currency_symbol = allow;
+        self
+    }
+
+    /// Whether a trailing `%` is accepted after a finite number, which divides
+    /// it by `100`. `NaN%` and `Inf%` are invalid.
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_percent(mut self, allow: bool) -> Self {
+        self.percent = allow;
+        self
+    }
+
+    /// Sets what to do with the digits that don't fit into the coefficient.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_excess_digits(mut self, excess_digits: ExcessDigits) -> Self {
+        self.excess_digits = excess_digits;
+        self
+    }
+}
@@ -24,6 +24,10 @@ macro_rules! consts_impl {
             /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
             pub const EPSILON: Self = Self::new(Decimal::EPSILON);
 
+            /// The length of the longest string this decimal type is formatted to
+            /// by [`format_into`](Self::format_into).
+            pub const MAX_STR_LEN: usize = Decimal::<N>::MAX_STR_LEN;
+
             consts_impl!(CONSTS ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
 
             /// Euler's number (e).
@@ -84,6 +88,32 @@ macro_rules! consts_impl {
 
             /// log<sub>2</sub>(10).
             pub const LOG2_10: Self = Self::new(Decimal::LOG2_10);
+
+            /// 1/sqrt(π).
+            pub const FRAC_1_SQRT_PI: Self = Self::new(Decimal::FRAC_1_SQRT_PI);
+
+            /// sqrt(3).
+            pub const SQRT_3: Self = Self::new(Decimal::SQRT_3);
+
+            /// sqrt(5).
+            pub const SQRT_5: Self = Self::new(Decimal::SQRT_5);
+
+            /// The golden ratio (φ)
+            ///
+            /// Equal to (1 + sqrt(5))/2.
+            pub const PHI: Self = Self::new(Decimal::PHI);
+
+            /// ln(3).
+            pub const LN_3: Self = Self::new(Decimal::LN_3);
+
+            /// The Euler–Mascheroni constant (γ).
+            pub const EULER_GAMMA: Self = Self::new(Decimal::EULER_GAMMA);
+
+            /// Catalan's constant (G).
+            pub const CATALAN: Self = Self::new(Decimal::CATALAN);
+
+            /// Apéry's constant ζ(3).
+            pub const APERY: Self = Self::new(Decimal::APERY);
         }
     };
     (CONSTS $($name: ident $num: literal), *) => {
@@ -36,10 +36,7 @@ impl<const N: usize> Num for UnsignedDecimal<N> {
 
     #[inline]
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        if radix != 10 {
-            return Err(ParseError::InvalidRadix);
-        }
-        Self::from_str(str, Context::default())
+        Self::from_str_radix(str, radix, Context::default())
     }
 }
 
@@ -2,12 +2,13 @@ mod consts;
 mod extras;
 mod impls;
 
-use core::{cmp::Ordering, num::FpCategory};
+use core::{cmp::Ordering, fmt, num::FpCategory};
 
 use crate::{
     decimal::{
         doc, signals::Signals, udec::consts::consts_impl, Context, Decimal, DecimalError,
-        ParseError, RoundingMode, Sign,
+        FormatStyle, Formatted, Language, NumberFormat, ParseError, ParseErrorDetail,
+        ParseOptions, RoundingMode, Sign, WordsStyle,
     },
     int::UInt,
 };
@@ -62,6 +63,128 @@ impl<const N: usize> UnsignedDecimal<N> {
         }
     }
 
+    /// Creates and initializes an unsigned decimal from string without losing
+    /// any significant digit, see [`Decimal::from_str_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// assert_eq!(UD128::from_str_exact("1.2345", ctx), Ok(udec128!(1.2345)));
+    /// assert_eq!(UD128::from_str_exact("0.1234567890123456789012345678901234567891", ctx), Err(ParseError::Inexact));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn from_str_exact(s: &str, ctx: Context) -> Result<Self, ParseError> {
+        match Decimal::<N>::from_str_exact(s, ctx) {
+            Ok(d) => {
+                if d.is_negative() {
+                    Err(ParseError::Signed)
+                } else {
+                    Ok(Self::new(d))
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned decimal from string, reporting
+    /// where parsing failed, see [`Decimal::from_str_detailed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(UD128::from_str_detailed("1.2345", Context::default()), Ok(udec128!(1.2345)));
+    ///
+    /// let e = UD128::from_str_detailed("-1.2345", Context::default()).unwrap_err();
+    /// assert_eq!((e.kind(), e.position(), e.expected()), (ParseError::Signed, 0, None));
+    /// ```
+    #[inline]
+    pub fn from_str_detailed(s: &str, ctx: Context) -> Result<Self, ParseErrorDetail> {
+        match Decimal::<N>::from_str_detailed(s, ctx) {
+            Ok(d) if d.is_negative() => Err(ParseErrorDetail::new(ParseError::Signed, 0, None)),
+            Ok(d) => Ok(Self::new(d)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned decimal from the longest prefix of
+    /// string which is a number, returning it with the number of bytes it
+    /// takes, see [`Decimal::from_str_prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(UD128::from_str_prefix("12.5kg", Context::default()), Ok((udec128!(12.5), 4)));
+    /// ```
+    #[inline]
+    pub fn from_str_prefix(s: &str, ctx: Context) -> Result<(Self, usize), ParseErrorDetail> {
+        match Decimal::<N>::from_str_prefix(s, ctx) {
+            Ok((d, _)) if d.is_negative() => {
+                Err(ParseErrorDetail::new(ParseError::Signed, 0, None))
+            }
+            Ok((d, len)) => Ok((Self::new(d), len)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned decimal from string in the forms
+    /// enabled by the [`ParseOptions`], see [`Decimal::from_str_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let opts = ParseOptions::new()
+    ///     .with_grouping_separator('.')
+    ///     .with_decimal_separator(',')
+    ///     .allow_parentheses(true);
+    ///
+    /// assert_eq!(UD256::from_str_with("1.234,5", Context::default(), &opts), Ok(udec256!(1234.5)));
+    /// assert_eq!(UD256::from_str_with("(1.234,5)", Context::default(), &opts), Err(ParseError::Signed));
+    /// ```
+    #[inline]
+    pub fn from_str_with(s: &str, ctx: Context, opts: &ParseOptions) -> Result<Self, ParseError> {
+        match Decimal::<N>::from_str_with(s, ctx, opts) {
+            Ok(d) if d.is_negative() => Err(ParseError::Signed),
+            Ok(d) => Ok(Self::new(d)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned decimal from string in the given
+    /// `radix`, see [`Decimal::from_str_radix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidRadix`] if `radix` is not in the range
+    /// `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(UD128::from_str_radix("0x1.8p3", 16, Context::default()), Ok(udec128!(12)));
+    /// assert_eq!(UD128::from_str_radix("-0x1.8p3", 16, Context::default()), Err(ParseError::Signed));
+    /// ```
+    #[inline]
+    pub fn from_str_radix(s: &str, radix: u32, ctx: Context) -> Result<Self, ParseError> {
+        match Decimal::<N>::from_str_radix(s, radix, ctx) {
+            Ok(d) if d.is_negative() => Err(ParseError::Signed),
+            Ok(d) => Ok(Self::new(d)),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Parse an unsigned decimal from string.
     ///
     /// # Panics
@@ -96,6 +219,31 @@ impl<const N: usize> UnsignedDecimal<N> {
         }
     }
 
+    /// Parse an unsigned decimal from string without losing any significant
+    /// digit, see [`Decimal::from_str_exact`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `UnsignedDecimal<N>` can't be constructed
+    /// from a given string exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// assert_eq!(UD128::parse_str_exact("1.2345", Context::default()), udec128!(1.2345));
+    /// ```
+    #[track_caller]
+    #[must_use]
+    #[inline]
+    pub const fn parse_str_exact(s: &str, ctx: Context) -> Self {
+        match Self::from_str_exact(s, ctx) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
     /// Returns the internal big integer, representing the
     /// [_Coefficient_](crate#representation) of a given `UnsignedDecimal`,
     /// including significant trailing zeros.
@@ -1034,6 +1182,146 @@ impl<const N: usize> UnsignedDecimal<N> {
         self.0.ln()
     }
 
+    /// Returns _Γ(self)_ (the gamma function).
+    #[doc = doc::decimal_operation_panics!("gamma function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec128!(5).gamma(), udec128!(24));
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn gamma(self) -> Self {
+        Self::new(self.0.gamma())
+    }
+
+    /// Returns _ln Γ(self)_ (the natural logarithm of the gamma function).
+    #[doc = doc::decimal_operation_panics!("logarithm of the gamma function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec128!(3).ln_gamma(), D128::LN_2);
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn ln_gamma(self) -> Decimal<N> {
+        self.0.ln_gamma()
+    }
+
+    /// Returns _self!_ (the factorial of the unsigned decimal number).
+    #[doc = doc::decimal_operation_panics!("factorial operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec128!(6).factorial(), udec128!(720));
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn factorial(self) -> Self {
+        Self::new(self.0.factorial())
+    }
+
+    /// Returns the binomial coefficient _C(self, k)_.
+    #[doc = doc::decimal_operation_panics!("binomial coefficient operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec128!(10).binomial(udec128!(3)), udec128!(120));
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn binomial(self, k: Self) -> Self {
+        Self::new(self.0.binomial(k.0))
+    }
+
+    /// Returns _B(self, b)_ (the beta function).
+    #[doc = doc::decimal_operation_panics!("beta function operation")]
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec128!(1).beta(udec128!(4)), udec128!(0.25));
+    /// ```
+    ///
+    /// See more about the [gamma function](crate#gamma-function).
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn beta(self, b: Self) -> Self {
+        Self::new(self.0.beta(b.0))
+    }
+
+    /// Returns the unsigned decimal number raised to the power of `n` correctly rounded, see [`pow`](Self::pow).
+    #[doc = doc::decimal_correctly_rounded!("power operation")]
+    #[doc = doc::decimal_operation_panics!("power operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn pow_correctly_rounded<const M: usize>(self, n: Decimal<N>) -> Self {
+        Self::new(self.0.pow_correctly_rounded::<M>(n))
+    }
+
+    /// Returns the square root of the unsigned decimal number correctly rounded, see [`sqrt`](Self::sqrt).
+    #[doc = doc::decimal_correctly_rounded!("square root operation")]
+    #[doc = doc::decimal_operation_panics!("sqrt operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn sqrt_correctly_rounded<const M: usize>(self) -> Self {
+        Self::new(self.0.sqrt_correctly_rounded::<M>())
+    }
+
+    /// Returns _e<sup>self</sup>_ correctly rounded, see [`exp`](Self::exp).
+    #[doc = doc::decimal_correctly_rounded!("exponential function")]
+    #[doc = doc::decimal_operation_panics!("exponent operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn exp_correctly_rounded<const M: usize>(self) -> Self {
+        Self::new(self.0.exp_correctly_rounded::<M>())
+    }
+
+    /// Returns the natural logarithm of the unsigned decimal number correctly rounded, see [`ln`](Self::ln).
+    #[doc = doc::decimal_correctly_rounded!("natural logarithm")]
+    #[doc = doc::decimal_operation_panics!("logarithm operation")]
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub const fn ln_correctly_rounded<const M: usize>(self) -> Decimal<N> {
+        self.0.ln_correctly_rounded::<M>()
+    }
+
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding
     /// error, yielding a more accurate result than an unfused multiply-add.
     #[doc = doc::decimal_operation_panics!("multiply-add operation")]
@@ -1266,6 +1554,122 @@ impl<const N: usize> UnsignedDecimal<N> {
         self.0.to_engineering_notation()
     }
 
+    /// Create a string of this unsigned decimal in plain notation with
+    /// exactly `frac_digits` digits after the decimal point, see
+    /// [`Decimal::to_fixed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::udec256;
+    ///
+    /// assert_eq!(udec256!(5).to_fixed(2), "5.00");
+    /// assert_eq!(udec256!(1.005).to_fixed(2), "1.01");
+    /// ```
+    #[inline]
+    pub fn to_fixed(&self, frac_digits: u16) -> String {
+        self.0.to_fixed(frac_digits)
+    }
+
+    /// Write unsigned decimal in plain notation with exactly `frac_digits`
+    /// digits after the decimal point to writer `w`, see
+    /// [`Decimal::to_fixed`].
+    #[inline]
+    pub fn write_fixed<W: fmt::Write>(&self, frac_digits: u16, w: &mut W) -> fmt::Result {
+        self.0.write_fixed(frac_digits, w)
+    }
+
+    /// Create string of this unsigned decimal in the given `radix`, see
+    /// [`Decimal::to_str_radix`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::*;
+    ///
+    /// assert_eq!(udec256!(255.75).to_str_radix(16), "ff.c");
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[track_caller]
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.0.to_str_radix(radix)
+    }
+
+    /// Formats this unsigned decimal in the given [`FormatStyle`] into the
+    /// beginning of `buf` without allocating and returns the written string
+    /// slice.
+    ///
+    /// A buffer of [`MAX_STR_LEN`](Self::MAX_STR_LEN) bytes holds any decimal
+    /// in any style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`fmt::Error`] if `buf` is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let mut buf = [0; UD128::MAX_STR_LEN];
+    ///
+    /// assert_eq!(udec128!(12.5e3).format_into(&mut buf, FormatStyle::default()), Ok("12500"));
+    /// ```
+    #[inline]
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        style: FormatStyle,
+    ) -> Result<&'a str, fmt::Error> {
+        self.0.format_into(buf, style)
+    }
+
+    /// Formats this unsigned decimal in the locale-specific
+    /// [`NumberFormat`], see [`Decimal::format_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let nf = NumberFormat::new()
+    ///     .with_grouping('.')
+    ///     .with_decimal_separator(',')
+    ///     .with_fraction_digits(2);
+    ///
+    /// assert_eq!(udec256!(1234567.891).format_with(&nf).to_string(), "1.234.567,89");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn format_with(&self, nf: &NumberFormat) -> Formatted<N> {
+        self.0.format_with(nf)
+    }
+
+    /// Spells out this unsigned decimal in words in the given
+    /// [`WordsStyle`], see [`Decimal::to_words`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{*, decimal::*};
+    ///
+    /// let cheque = WordsStyle::new()
+    ///     .with_fraction_style(FractionStyle::Ratio)
+    ///     .with_fraction_digits(2);
+    ///
+    /// assert_eq!(udec256!(1000000.5).to_words(&cheque), "one million and 50/100");
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub fn to_words<L: Language>(&self, style: &WordsStyle<L>) -> String {
+        self.0.to_words(style)
+    }
+
     /// Converts the given unsigned decimal to a signed decimal number.
     ///
     /// # Examples
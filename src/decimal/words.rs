@@ -0,0 +1,366 @@
+use core::fmt::{self, Write};
+
+/// Way the fraction digits of a number are spelled out with a [WordsStyle].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FractionStyle {
+    /// Each fraction digit is spelled out after the word for the decimal
+    /// point: `one point five six`.
+    #[default]
+    Point,
+
+    /// The fraction digits are written as the numerator of a power of ten,
+    /// as on cheques and in contracts: `one and 56/100`.
+    Ratio,
+}
+
+/// Language in which numbers are spelled out, see [WordsStyle].
+///
+/// A language spells out whole numbers and single digits and provides the
+/// words joining them. The number is put together from these words,
+/// separated by spaces, as `[minus] <integer> [point <digit> …]` or
+/// `[minus] <integer> [and <numerator>/<denominator>]`.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::{self, Write};
+/// use fastnum::{*, decimal::*};
+///
+/// struct German;
+///
+/// impl Language for German {
+///     fn write_cardinal<W: Write>(&self, digits: &[u8], w: &mut W) -> fmt::Result {
+///         match digits {
+///             b"0" => w.write_str("null"),
+///             b"1" => w.write_str("eins"),
+///             b"2" => w.write_str("zwei"),
+///             _ => w.write_str("viele"),
+///         }
+///     }
+///
+///     fn minus(&self) -> &str { "minus" }
+///     fn point(&self) -> &str { "Komma" }
+///     fn and(&self) -> &str { "und" }
+///     fn infinity(&self) -> &str { "unendlich" }
+///     fn nan(&self) -> &str { "keine Zahl" }
+/// }
+///
+/// let style = WordsStyle::new().with_language(German);
+/// assert_eq!(dec128!(-2.01).to_words(&style), "minus zwei Komma null eins");
+/// ```
+pub trait Language {
+    /// Writes the whole number given by its decimal `digits`: ASCII digits
+    /// without leading zeros, or a single `0`.
+    fn write_cardinal<W: Write>(&self, digits: &[u8], w: &mut W) -> fmt::Result;
+
+    /// Writes a single decimal `digit`, in `0..=9`, as read after the
+    /// decimal point.
+    ///
+    /// By default it's spelled out as a whole number.
+    #[inline]
+    fn write_digit<W: Write>(&self, digit: u8, w: &mut W) -> fmt::Result {
+        self.write_cardinal(&[b'0' + digit], w)
+    }
+
+    /// Word for the sign of negative numbers.
+    fn minus(&self) -> &str;
+
+    /// Word for the decimal point.
+    fn point(&self) -> &str;
+
+    /// Word between the integer part and the fraction in the
+    /// [`Ratio`](FractionStyle::Ratio) style.
+    fn and(&self) -> &str;
+
+    /// Word for infinity.
+    fn infinity(&self) -> &str;
+
+    /// Words for NaN.
+    fn nan(&self) -> &str;
+}
+
+/// English, with the cardinal numbers of the short scale as written in
+/// American English: `one thousand two hundred thirty-four`.
+///
+/// Numbers beyond the largest scale name, vigintillion (_10<sup>63</sup>_),
+/// count vigintillions: `one thousand vigintillion`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct English;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Names of the powers _1000<sup>i</sup>_.
+const SCALES: [&str; 22] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+    "tredecillion",
+    "quattuordecillion",
+    "quindecillion",
+    "sexdecillion",
+    "septendecillion",
+    "octodecillion",
+    "novemdecillion",
+    "vigintillion",
+];
+
+impl English {
+    /// Writes the non-zero groups of three `digits` with their scale names,
+    /// separated by spaces unless `first`.
+    fn write_groups<W: Write>(digits: &[u8], w: &mut W, first: &mut bool) -> fmt::Result {
+        const MAX_SCALE: usize = SCALES.len() - 1;
+
+        if digits.len() > SCALES.len() * 3 {
+            let (high, low) = digits.split_at(digits.len() - MAX_SCALE * 3);
+            Self::write_groups(high, w, first)?;
+            w.write_char(' ')?;
+            w.write_str(SCALES[MAX_SCALE])?;
+            return Self::write_groups(low, w, first);
+        }
+
+        // the leading group holds what is left over by the full groups
+        let mut start = 0;
+        let mut end = match digits.len() % 3 {
+            0 => 3.min(digits.len()),
+            leading => leading,
+        };
+
+        while start < digits.len() {
+            let group = digits[start..end]
+                .iter()
+                .fold(0, |group, &c| group * 10 + (c - b'0') as usize);
+
+            if group != 0 {
+                if !*first {
+                    w.write_char(' ')?;
+                }
+                *first = false;
+
+                Self::write_hundreds(group, w)?;
+
+                let scale = (digits.len() - end) / 3;
+                if scale > 0 {
+                    w.write_char(' ')?;
+                    w.write_str(SCALES[scale])?;
+                }
+            }
+
+            start = end;
+            end += 3;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a number in `1..1000`.
+    fn write_hundreds<W: Write>(n: usize, w: &mut W) -> fmt::Result {
+        let (hundreds, rest) = (n / 100, n % 100);
+
+        if hundreds > 0 {
+            w.write_str(ONES[hundreds])?;
+            w.write_str(" hundred")?;
+
+            if rest > 0 {
+                w.write_char(' ')?;
+            }
+        }
+
+        match rest {
+            0 => Ok(()),
+            1..20 => w.write_str(ONES[rest]),
+            _ => {
+                w.write_str(TENS[rest / 10])?;
+
+                if rest % 10 > 0 {
+                    w.write_char('-')?;
+                    w.write_str(ONES[rest % 10])?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Language for English {
+    fn write_cardinal<W: Write>(&self, digits: &[u8], w: &mut W) -> fmt::Result {
+        let leading_zeros = digits.iter().take_while(|&&c| c == b'0').count();
+        let digits = &digits[leading_zeros..];
+
+        if digits.is_empty() {
+            return w.write_str(ONES[0]);
+        }
+
+        Self::write_groups(digits, w, &mut true)
+    }
+
+    #[inline]
+    fn write_digit<W: Write>(&self, digit: u8, w: &mut W) -> fmt::Result {
+        w.write_str(ONES[digit as usize])
+    }
+
+    #[inline]
+    fn minus(&self) -> &str {
+        "minus"
+    }
+
+    #[inline]
+    fn point(&self) -> &str {
+        "point"
+    }
+
+    #[inline]
+    fn and(&self) -> &str {
+        "and"
+    }
+
+    #[inline]
+    fn infinity(&self) -> &str {
+        "infinity"
+    }
+
+    #[inline]
+    fn nan(&self) -> &str {
+        "not a number"
+    }
+}
+
+/// # Words Style
+///
+/// Style in which a decimal number is spelled out in words, as on cheques
+/// and in legal documents: the [Language], the [FractionStyle] and the
+/// number of fraction digits.
+///
+/// Fraction digits beyond [`fraction_digits`](Self::fraction_digits) are
+/// rounded with the [RoundingMode](crate::decimal::RoundingMode) of the
+/// decimal, and zeros are appended up to it. By default all fraction digits
+/// of the decimal are spelled out, including its trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{*, decimal::*};
+///
+/// let d = dec128!(1234.56);
+///
+/// let style = WordsStyle::new();
+/// assert_eq!(d.to_words(&style), "one thousand two hundred thirty-four point five six");
+///
+/// let cheque = style
+///     .with_fraction_style(FractionStyle::Ratio)
+///     .with_fraction_digits(2);
+/// assert_eq!(d.to_words(&cheque), "one thousand two hundred thirty-four and 56/100");
+/// assert_eq!(dec128!(-17).to_words(&cheque), "minus seventeen and 00/100");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WordsStyle<L: Language = English> {
+    language: L,
+    fraction_style: FractionStyle,
+    fraction_digits: Option<u16>,
+}
+
+impl Default for WordsStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WordsStyle {
+    /// Returns the default `WordsStyle`: [English], the
+    /// [`Point`](FractionStyle::Point) style and all fraction digits of the
+    /// decimal.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            language: English,
+            fraction_style: FractionStyle::Point,
+            fraction_digits: None,
+        }
+    }
+}
+
+impl<L: Language> WordsStyle<L> {
+    /// Spells out numbers in the given [Language].
+    #[must_use]
+    #[inline(always)]
+    pub fn with_language<M: Language>(self, language: M) -> WordsStyle<M> {
+        WordsStyle {
+            language,
+            fraction_style: self.fraction_style,
+            fraction_digits: self.fraction_digits,
+        }
+    }
+
+    /// Sets the [FractionStyle].
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_fraction_style(mut self, fraction_style: FractionStyle) -> Self {
+        self.fraction_style = fraction_style;
+        self
+    }
+
+    /// Rounds or pads the fraction to exactly `digits`, e.g. `2` for cents.
+    #[must_use]
+    #[inline(always)]
+    pub const fn with_fraction_digits(mut self, digits: u16) -> Self {
+        self.fraction_digits = Some(digits);
+        self
+    }
+
+    /// Get the [Language].
+    #[must_use]
+    #[inline(always)]
+    pub const fn language(&self) -> &L {
+        &self.language
+    }
+
+    /// Get the [FractionStyle].
+    #[must_use]
+    #[inline(always)]
+    pub const fn fraction_style(&self) -> FractionStyle {
+        self.fraction_style
+    }
+
+    /// Get the number of fraction digits, if fixed.
+    #[must_use]
+    #[inline(always)]
+    pub const fn fraction_digits(&self) -> Option<u16> {
+        self.fraction_digits
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    int::{
+        math::div_rem,
+        montgomery::{add_mod, sub_mod},
+        UInt,
+    },
+    utils::err_msg,
+};
+
+type U<const N: usize> = UInt<N>;
+
+/// # Barrett context
+///
+/// Precomputed context for repeated modular arithmetic under a fixed modulus
+/// using [Barrett reduction](https://en.wikipedia.org/wiki/Barrett_reduction).
+///
+/// Unlike [`MontgomeryCtx`](crate::int::MontgomeryCtx), Barrett reduction
+/// works with ordinary residues and any modulus (even ones included), so no
+/// conversion is needed: [`mul`](Self::mul), [`square`](Self::square) and
+/// [`pow`](Self::pow) take and return values reduced modulo `m`.
+///
+/// All operations are `const` and can be used to build contexts for fixed
+/// moduli at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{int::BarrettCtx, U256};
+///
+/// // secp256k1 group order
+/// const Q: U256 = U256::parse_str_radix("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 16);
+/// const CTX: BarrettCtx<4> = BarrettCtx::new(Q);
+///
+/// let x = U256::from_digit(12345678901234567890);
+/// let inv = CTX.pow(x, Q.sub(U256::from_digit(2)));
+///
+/// assert_eq!(CTX.mul(x, inv), U256::from_digit(1));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BarrettCtx<const N: usize> {
+    modulus: U<N>,
+
+    /// Bit length _k_ of the modulus.
+    k: u32,
+
+    /// _⌊4<sup>k</sup> / m⌋ - 2<sup>k</sup>_, or zero when the modulus is a
+    /// power of two and reduction is a plain mask.
+    mu: U<N>,
+}
+
+impl<const N: usize> BarrettCtx<N> {
+    /// Creates a Barrett context for the given `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus` is zero.
+    #[track_caller]
+    #[must_use]
+    #[inline]
+    pub const fn new(modulus: UInt<N>) -> Self {
+        if modulus.is_zero() {
+            panic!(err_msg!("Barrett modulus must be non-zero"));
+        }
+
+        let k = modulus.bits();
+
+        let mu = if modulus.is_power_of_two() {
+            U::ZERO
+        } else {
+            // ⌊4^k / m⌋ = 2^k + ⌊2^k · (2^k - m) / m⌋
+            let d = if k == U::<N>::BITS {
+                modulus.wrapping_neg()
+            } else {
+                U::ONE.shl(k).sub(modulus)
+            };
+            shl_div(d, k, modulus)
+        };
+
+        Self { modulus, k, mu }
+    }
+
+    /// Returns the modulus of this context.
+    #[must_use]
+    #[inline]
+    pub const fn modulus(&self) -> UInt<N> {
+        self.modulus
+    }
+
+    /// Reduces `x` modulo `m`.
+    #[must_use]
+    #[inline]
+    pub const fn reduce(&self, x: UInt<N>) -> UInt<N> {
+        if x.lt(&self.modulus) {
+            x
+        } else if x.bits() <= 2 * self.k {
+            self.reduce_wide(x, U::ZERO)
+        } else {
+            div_rem(x, self.modulus).1
+        }
+    }
+
+    /// Modular multiplication _a·b mod m_ of two reduced values.
+    #[must_use]
+    #[inline]
+    pub const fn mul(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        debug_assert!(a.lt(&self.modulus) && b.lt(&self.modulus));
+        let (low, high) = a.widening_mul(b);
+        self.reduce_wide(low, high)
+    }
+
+    /// Modular square _a<sup>2</sup> mod m_ of a reduced value.
+    #[must_use]
+    #[inline]
+    pub const fn square(&self, a: UInt<N>) -> UInt<N> {
+        self.mul(a, a)
+    }
+
+    /// Modular exponentiation _base<sup>exp</sup> mod m_ of a reduced `base`.
+    #[must_use]
+    #[inline]
+    pub const fn pow(&self, base: UInt<N>, exp: UInt<N>) -> UInt<N> {
+        let mut result = self.reduce(U::ONE);
+        let mut i = exp.bits();
+
+        while i > 0 {
+            i -= 1;
+            result = self.square(result);
+            if exp.bit(i) {
+                result = self.mul(result, base);
+            }
+        }
+
+        result
+    }
+
+    /// Modular addition _a + b mod m_ of two reduced values.
+    #[must_use]
+    #[inline]
+    pub const fn add(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        add_mod(a, b, self.modulus)
+    }
+
+    /// Modular subtraction _a - b mod m_ of two reduced values.
+    #[must_use]
+    #[inline]
+    pub const fn sub(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        sub_mod(a, b, self.modulus)
+    }
+
+    /// Reduces the double-width value _x = high·2<sup>64·N</sup> + low_,
+    /// where _x < 4<sup>k</sup>_.
+    #[inline]
+    const fn reduce_wide(&self, low: U<N>, high: U<N>) -> U<N> {
+        if self.mu.is_zero() {
+            return low.bitand(self.modulus.sub(U::ONE));
+        }
+
+        // q = ⌊⌊x / 2^k⌋ · ⌊4^k / m⌋ / 2^k⌋ underestimates ⌊x / m⌋ by at most 3.
+        let t = shr_wide(low, high, self.k);
+        let (p_low, p_high) = t.widening_mul(self.mu);
+        let q = t.add(shr_wide(p_low, p_high, self.k));
+
+        let (qm_low, qm_high) = q.widening_mul(self.modulus);
+        let (mut r_low, borrow) = low.overflowing_sub(qm_low);
+        let mut r_high = high.wrapping_sub(qm_high).wrapping_sub(U::from_digit(borrow as u64));
+
+        while !r_high.is_zero() || r_low.ge(&self.modulus) {
+            let borrow;
+            (r_low, borrow) = r_low.overflowing_sub(self.modulus);
+            r_high = r_high.wrapping_sub(U::from_digit(borrow as u64));
+        }
+
+        r_low
+    }
+}
+
+/// _(high·2<sup>64·N</sup> + low) >> shift_, `0 < shift <= 64·N`.
+#[inline]
+const fn shr_wide<const N: usize>(low: U<N>, high: U<N>, shift: u32) -> U<N> {
+    if shift == U::<N>::BITS {
+        high
+    } else {
+        low.shr(shift).bitor(high.shl(U::<N>::BITS - shift))
+    }
+}
+
+/// _⌊x · 2<sup>shift</sup> / m⌋_ for _x < m_ and a quotient below
+/// _2<sup>64·N</sup>_, by binary long division. Unlike `div_rem_wide` it
+/// doesn't recurse, so it stays within the limits of constant evaluation.
+#[inline]
+const fn shl_div<const N: usize>(x: U<N>, shift: u32, m: U<N>) -> U<N> {
+    let mut quotient = U::ZERO;
+    let mut remainder = x;
+    let mut i = 0;
+
+    while i < shift {
+        // 2r < 2m, so the bit shifted out is part of a value below 2m.
+        let carry = remainder.bit(U::<N>::BITS - 1);
+        remainder = remainder.shl(1);
+        quotient = quotient.shl(1);
+
+        if carry || !remainder.lt(&m) {
+            remainder = remainder.wrapping_sub(m);
+            quotient = quotient.bitor(U::ONE);
+        }
+
+        i += 1;
+    }
+
+    quotient
+}
@@ -0,0 +1,105 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    hint::black_box,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use crate::int::{ct::limb, intrinsics::Digit};
+
+/// # Choice
+///
+/// Constant-time boolean, holding either `0` or `1`.
+///
+/// Results of constant-time comparisons are returned as a `Choice` rather than
+/// a `bool` so that they can be combined with `&`, `|`, `^`, `!` and passed to
+/// [`ConstTime::select`](super::ConstTime::select) without branching. Converting
+/// a `Choice` into a `bool` is the point where the secret becomes public.
+///
+/// With the `subtle` feature `Choice` converts to and from
+/// [`subtle::Choice`](https://docs.rs/subtle/latest/subtle/struct.Choice.html).
+///
+/// Its `Debug` output is redacted to `Choice(..)`.
+#[derive(Copy, Clone)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Constant-time `true`.
+    pub const TRUE: Self = Self(1);
+
+    /// Constant-time `false`.
+    pub const FALSE: Self = Self(0);
+
+    /// Returns the underlying `0` or `1`.
+    #[must_use]
+    #[inline]
+    pub const fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+
+    #[inline(always)]
+    pub(super) fn mask(self) -> Digit {
+        limb::mask(self.0)
+    }
+}
+
+impl Debug for Choice {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Choice(..)")
+    }
+}
+
+impl From<u8> for Choice {
+    /// Creates a `Choice` from `0` or `1`.
+    ///
+    /// The value is passed through an optimization barrier, so the compiler
+    /// can't specialise the code that consumes it on its value.
+    #[inline]
+    fn from(value: u8) -> Self {
+        debug_assert!(value <= 1);
+        Self(black_box(value))
+    }
+}
+
+impl From<Choice> for bool {
+    #[inline]
+    fn from(choice: Choice) -> Self {
+        choice.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
@@ -0,0 +1,56 @@
+//! Branch-free single-limb primitives.
+//!
+//! Every constant-time operation in [`ct`](super) is composed exclusively of
+//! these functions, which use only arithmetic and bitwise instructions on
+//! their arguments. Each call is recorded by the [`trace`](super::trace)
+//! harness.
+
+use crate::int::{
+    ct::trace::{tick, Op},
+    intrinsics::{Digit, DoubleDigit, BITS, BITS_MINUS_1},
+};
+
+/// Expands a `0`/`1` flag into an all-zeros/all-ones mask.
+#[inline(always)]
+pub(super) fn mask(flag: u8) -> Digit {
+    tick(Op::Mask);
+    (0 as Digit).wrapping_sub(flag as Digit)
+}
+
+/// _a + b + carry_, returns `(sum, carry)`.
+#[inline(always)]
+pub(super) fn adc(a: Digit, b: Digit, carry: Digit) -> (Digit, Digit) {
+    tick(Op::Adc);
+    let t = a as DoubleDigit + b as DoubleDigit + carry as DoubleDigit;
+    (t as Digit, (t >> BITS) as Digit)
+}
+
+/// _a - b - borrow_, returns `(difference, borrow)`.
+#[inline(always)]
+pub(super) fn sbb(a: Digit, b: Digit, borrow: Digit) -> (Digit, Digit) {
+    tick(Op::Sbb);
+    let t = (a as DoubleDigit).wrapping_sub(b as DoubleDigit + borrow as DoubleDigit);
+    (t as Digit, (t >> (DoubleDigit::BITS - 1)) as Digit)
+}
+
+/// _acc + a·b + carry_, returns `(low, high)`.
+#[inline(always)]
+pub(super) fn mac(acc: Digit, a: Digit, b: Digit, carry: Digit) -> (Digit, Digit) {
+    tick(Op::Mac);
+    let t = acc as DoubleDigit + (a as DoubleDigit) * (b as DoubleDigit) + carry as DoubleDigit;
+    (t as Digit, (t >> BITS) as Digit)
+}
+
+/// Returns `a` if `mask` is all zeros and `b` if it is all ones.
+#[inline(always)]
+pub(super) fn select(a: Digit, b: Digit, mask: Digit) -> Digit {
+    tick(Op::Select);
+    a ^ (mask & (a ^ b))
+}
+
+/// Returns `1` if `x` is non-zero and `0` otherwise.
+#[inline(always)]
+pub(super) fn is_nonzero(x: Digit) -> u8 {
+    tick(Op::NonZero);
+    ((x | x.wrapping_neg()) >> BITS_MINUS_1) as u8
+}
@@ -0,0 +1,305 @@
+//! # Constant-time arithmetic
+//!
+//! [`ConstTime`] wraps a [`UInt`] and provides arithmetic, comparison and
+//! selection whose control flow and memory access pattern don't depend on the
+//! wrapped values, which makes it suitable for handling secrets (keys, nonces,
+//! scalars) where timing side channels matter.
+//!
+//! All operations are built from a small set of branch-free limb primitives
+//! and always visit every limb of their operands, so execution time depends
+//! only on the integer width `N`. Results of comparisons are returned as a
+//! [`Choice`] instead of a `bool` so they can drive [`ConstTime::select`] and
+//! [`ConstTime::conditional_swap`] without branching.
+//!
+//! With the `subtle` feature [`ConstTime`] implements `ConstantTimeEq`,
+//! `ConstantTimeGreater`, `ConstantTimeLess` and `ConditionallySelectable` from
+//! the [subtle](https://docs.rs/subtle/latest/subtle/) crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastnum::{int::ct::ConstTime, U256};
+//!
+//! let a = ConstTime::new(U256::from_digit(5));
+//! let b = ConstTime::new(U256::from_digit(7));
+//!
+//! let lt = a.ct_lt(&b);
+//! let min = ConstTime::select(&b, &a, lt);
+//!
+//! assert_eq!(min.get(), U256::from_digit(5));
+//! assert!(bool::from(a.wrapping_add(b).ct_eq(&ConstTime::new(U256::from_digit(12)))));
+//! ```
+
+mod choice;
+mod limb;
+#[cfg(feature = "subtle")]
+mod subtle;
+
+#[cfg(feature = "test-util")]
+#[doc(hidden)]
+pub mod trace;
+
+#[cfg(not(feature = "test-util"))]
+pub(crate) mod trace;
+
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::{Add, Mul, Sub},
+};
+
+use crate::int::{intrinsics::Digit, UInt};
+
+pub use choice::Choice;
+
+/// # Constant-time wrapper
+///
+/// Wrapper whose operations execute in time independent of the wrapped
+/// value. See the [module level documentation](self) for details.
+///
+/// `ConstTime` deliberately doesn't implement `PartialEq`, `Ord` or `Hash`:
+/// use [`ct_eq`](Self::ct_eq), [`ct_lt`](Self::ct_lt) and
+/// [`ct_gt`](Self::ct_gt) instead. Arithmetic operators `+`, `-` and `*` are
+/// wrapping. Its `Debug` output is redacted to `ConstTime(..)`, so the secret
+/// doesn't leak into logs or panic messages.
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct ConstTime<T>(T);
+
+impl<const N: usize> ConstTime<UInt<N>> {
+    /// Wraps the given `value`.
+    #[must_use]
+    #[inline]
+    pub const fn new(value: UInt<N>) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> UInt<N> {
+        self.0
+    }
+
+    /// Calculates `self + rhs` and returns the wrapped sum along with the
+    /// carry.
+    #[must_use]
+    #[inline]
+    pub fn overflowing_add(self, rhs: Self) -> (Self, Choice) {
+        let a = self.0.digits();
+        let b = rhs.0.digits();
+
+        let mut res = [0; N];
+        let mut carry = 0;
+        let mut i = 0;
+        while i < N {
+            (res[i], carry) = limb::adc(a[i], b[i], carry);
+            i += 1;
+        }
+
+        (Self(UInt::from_digits(res)), Choice::from(carry as u8))
+    }
+
+    /// Wrapping (modular) addition.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Calculates `self - rhs` and returns the wrapped difference along with
+    /// the borrow.
+    #[must_use]
+    #[inline]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, Choice) {
+        let a = self.0.digits();
+        let b = rhs.0.digits();
+
+        let mut res = [0; N];
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < N {
+            (res[i], borrow) = limb::sbb(a[i], b[i], borrow);
+            i += 1;
+        }
+
+        (Self(UInt::from_digits(res)), Choice::from(borrow as u8))
+    }
+
+    /// Wrapping (modular) subtraction.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping (modular) multiplication, i.e. the low half of the full
+    /// product.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        let a = self.0.digits();
+        let b = rhs.0.digits();
+
+        let mut res = [0; N];
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0;
+            let mut j = 0;
+            while i + j < N {
+                (res[i + j], carry) = limb::mac(res[i + j], a[j], b[i], carry);
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(UInt::from_digits(res))
+    }
+
+    /// Calculates the full product `self * rhs` and returns it as a
+    /// `(low, high)` pair.
+    #[must_use]
+    #[inline]
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let a = self.0.digits();
+        let b = rhs.0.digits();
+
+        let mut low = [0; N];
+        let mut high = [0; N];
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                let k = i + j;
+                let acc = if k < N { &mut low[k] } else { &mut high[k - N] };
+                (*acc, carry) = limb::mac(*acc, a[j], b[i], carry);
+                j += 1;
+            }
+            high[i] = carry;
+            i += 1;
+        }
+
+        (Self(UInt::from_digits(low)), Self(UInt::from_digits(high)))
+    }
+
+    /// Returns [`Choice::TRUE`] if `self` is zero.
+    #[must_use]
+    #[inline]
+    pub fn ct_is_zero(&self) -> Choice {
+        let d = self.0.digits();
+
+        let mut acc: Digit = 0;
+        let mut i = 0;
+        while i < N {
+            acc |= d[i];
+            i += 1;
+        }
+
+        !Choice::from(limb::is_nonzero(acc))
+    }
+
+    /// Returns [`Choice::TRUE`] if `self == other`.
+    #[must_use]
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        let a = self.0.digits();
+        let b = other.0.digits();
+
+        let mut acc: Digit = 0;
+        let mut i = 0;
+        while i < N {
+            acc |= a[i] ^ b[i];
+            i += 1;
+        }
+
+        !Choice::from(limb::is_nonzero(acc))
+    }
+
+    /// Returns [`Choice::TRUE`] if `self < other`.
+    #[must_use]
+    #[inline]
+    pub fn ct_lt(&self, other: &Self) -> Choice {
+        self.overflowing_sub(*other).1
+    }
+
+    /// Returns [`Choice::TRUE`] if `self > other`.
+    #[must_use]
+    #[inline]
+    pub fn ct_gt(&self, other: &Self) -> Choice {
+        other.ct_lt(self)
+    }
+
+    /// Returns `a` if `choice` is [`Choice::FALSE`] and `b` if it is
+    /// [`Choice::TRUE`].
+    #[must_use]
+    #[inline]
+    pub fn select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let x = a.0.digits();
+        let y = b.0.digits();
+        let mask = choice.mask();
+
+        let mut res = [0; N];
+        let mut i = 0;
+        while i < N {
+            res[i] = limb::select(x[i], y[i], mask);
+            i += 1;
+        }
+
+        Self(UInt::from_digits(res))
+    }
+
+    /// Assigns `other` to `self` if `choice` is [`Choice::TRUE`].
+    #[inline]
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::select(self, other, choice);
+    }
+
+    /// Swaps `a` and `b` if `choice` is [`Choice::TRUE`].
+    #[inline]
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let x = *a;
+        *a = Self::select(&x, b, choice);
+        *b = Self::select(b, &x, choice);
+    }
+}
+
+impl<T> Debug for ConstTime<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("ConstTime(..)")
+    }
+}
+
+impl<const N: usize> From<UInt<N>> for ConstTime<UInt<N>> {
+    #[inline]
+    fn from(value: UInt<N>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> Add for ConstTime<UInt<N>> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl<const N: usize> Sub for ConstTime<UInt<N>> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl<const N: usize> Mul for ConstTime<UInt<N>> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
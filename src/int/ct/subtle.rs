@@ -0,0 +1,53 @@
+use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+use crate::int::{
+    ct::{Choice, ConstTime},
+    UInt,
+};
+
+impl From<Choice> for subtle::Choice {
+    #[inline]
+    fn from(choice: Choice) -> Self {
+        subtle::Choice::from(choice.unwrap_u8())
+    }
+}
+
+impl From<subtle::Choice> for Choice {
+    #[inline]
+    fn from(choice: subtle::Choice) -> Self {
+        Choice::from(choice.unwrap_u8())
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for ConstTime<UInt<N>> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        ConstTime::ct_eq(self, other).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeGreater for ConstTime<UInt<N>> {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> subtle::Choice {
+        ConstTime::ct_gt(self, other).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeLess for ConstTime<UInt<N>> {}
+
+impl<const N: usize> ConditionallySelectable for ConstTime<UInt<N>> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self::select(a, b, choice.into())
+    }
+
+    #[inline]
+    fn conditional_assign(&mut self, other: &Self, choice: subtle::Choice) {
+        ConstTime::conditional_assign(self, other, choice.into())
+    }
+
+    #[inline]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: subtle::Choice) {
+        ConstTime::conditional_swap(a, b, choice.into())
+    }
+}
@@ -0,0 +1,63 @@
+//! Counting harness for the branch-free limb primitives.
+//!
+//! With the `test-util` feature every limb primitive used by
+//! [`ConstTime`](super::ConstTime) is recorded in a thread-local trace, so
+//! tests can check that an operation executes the exact same sequence of
+//! primitives for every input. Without the feature recording compiles to
+//! nothing.
+
+#[cfg(feature = "test-util")]
+use core::cell::Cell;
+
+/// Limb primitive kind.
+#[derive(Copy, Clone)]
+pub(crate) enum Op {
+    Mask = 1,
+    Adc,
+    Sbb,
+    Mac,
+    Select,
+    NonZero,
+}
+
+/// Summary of the primitives executed inside [`record`].
+#[cfg(feature = "test-util")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Trace {
+    /// Total number of executed limb primitives.
+    pub ops: usize,
+
+    /// Order-sensitive fingerprint of the executed primitive kinds.
+    pub fingerprint: u64,
+}
+
+#[cfg(feature = "test-util")]
+std::thread_local! {
+    static TRACE: Cell<(usize, u64)> = const { Cell::new((0, 0)) };
+}
+
+/// Runs `f` and returns its result together with the trace of limb
+/// primitives it executed on the current thread.
+#[cfg(feature = "test-util")]
+pub fn record<R>(f: impl FnOnce() -> R) -> (R, Trace) {
+    let saved = TRACE.with(|trace| trace.replace((0, 0)));
+    let result = f();
+    let (ops, fingerprint) = TRACE.with(|trace| trace.replace(saved));
+
+    (result, Trace { ops, fingerprint })
+}
+
+#[inline(always)]
+pub(crate) fn tick(op: Op) {
+    #[cfg(feature = "test-util")]
+    TRACE.with(|trace| {
+        let (ops, fingerprint) = trace.get();
+        trace.set((
+            ops + 1,
+            fingerprint.wrapping_mul(0x100000001b3).wrapping_add(op as u64),
+        ));
+    });
+
+    #[cfg(not(feature = "test-util"))]
+    let _ = op;
+}
@@ -0,0 +1,302 @@
+use crate::int::{math, radix, resize, Int, ParseError, UInt};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<const N: usize> Sealed for crate::int::UInt<N> {}
+    impl<const N: usize> Sealed for crate::int::Int<N> {}
+}
+
+/// # Unsigned integer extension
+///
+/// Number-theoretic, radix conversion and width-related methods for [`UInt`] that are not
+/// provided by the underlying big integer backend.
+///
+/// This trait is sealed and implemented for every [`UInt<N>`](UInt) only.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{int::UIntExt, U256};
+///
+/// let x = U256::from_digit(1_000_000_007);
+///
+/// assert!(x.is_prime());
+/// assert_eq!(x.isqrt(), U256::from_digit(31622));
+/// assert_eq!(U256::from_digit(1000).icbrt(), U256::from_digit(10));
+/// ```
+pub trait UIntExt: sealed::Sealed + Sized {
+    /// Returns the integer square root _⌊√self⌋_.
+    #[must_use]
+    fn isqrt(self) -> Self;
+
+    /// Returns the integer cube root _⌊∛self⌋_.
+    #[must_use]
+    fn icbrt(self) -> Self;
+
+    /// Returns the integer `n`-th root _⌊self<sup>1/n</sup>⌋_.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `n` is zero.
+    #[must_use]
+    fn inth_root(self, n: u32) -> Self;
+
+    /// Returns `true` if `self` is the square of an integer.
+    #[must_use]
+    fn is_perfect_square(&self) -> bool;
+
+    /// Returns `true` if `self` is prime.
+    ///
+    /// The test is deterministic Miller–Rabin for values below
+    /// _2<sup>81</sup>_ and
+    /// [Baillie–PSW](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test)
+    /// for larger ones, for which no counterexample is known.
+    #[must_use]
+    fn is_prime(&self) -> bool;
+
+    /// Returns the smallest prime strictly greater than `self`, or `None` if
+    /// it is not representable.
+    #[must_use]
+    fn next_prime(self) -> Option<Self>;
+
+    /// Writes the digits of `self` in the given `radix` into the beginning
+    /// of `buf` without allocating and returns them as a string slice, or
+    /// `None` if `buf` is too small.
+    ///
+    /// Digits above `9` are lowercase, as in
+    /// [`to_str_radix`](UInt::to_str_radix). A buffer of
+    /// [`BITS`](UInt::BITS) bytes is always large enough.
+    ///
+    /// Radix `10` uses subquadratic divide-and-conquer conversion, which is
+    /// considerably faster than [`to_str_radix`](UInt::to_str_radix) for wide
+    /// types.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{int::UIntExt, U256};
+    ///
+    /// let mut buf = [0; 78];
+    ///
+    /// assert_eq!(U256::from_digit(1234).to_str_radix_into(&mut buf, 10), Some("1234"));
+    /// assert_eq!(U256::from_digit(255).to_str_radix_into(&mut buf, 16), Some("ff"));
+    /// assert_eq!(U256::MAX.to_str_radix_into(&mut buf[..10], 10), None);
+    /// ```
+    fn to_str_radix_into<'a>(&self, buf: &'a mut [u8], radix: u32) -> Option<&'a str>;
+
+    /// Parses an integer from a string slice with digits in the given
+    /// `radix`.
+    ///
+    /// Accepts the same input as [`from_str_radix`](UInt::from_str_radix),
+    /// but radix `10` uses subquadratic divide-and-conquer conversion.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{int::{ParseError, UIntExt}, U256};
+    ///
+    /// assert_eq!(U256::from_radix_str("1234", 10), Ok(U256::from_digit(1234)));
+    /// assert_eq!(U256::from_radix_str("ff", 16), Ok(U256::from_digit(255)));
+    /// assert_eq!(U256::from_radix_str("12a", 10), Err(ParseError::InvalidDigit));
+    /// ```
+    fn from_radix_str(s: &str, radix: u32) -> Result<Self, ParseError>;
+
+    /// Converts `self` to the wider type [`UInt<M>`](UInt) by zero
+    /// extension.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `M` is less than the width of `self`. Use
+    /// [`try_narrow`](UIntExt::try_narrow) for conversions to narrower types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{int::UIntExt, U128, U256};
+    ///
+    /// assert_eq!(U128::MAX.widen::<4>(), U256::from(u128::MAX));
+    /// ```
+    #[must_use]
+    fn widen<const M: usize>(self) -> UInt<M>;
+
+    /// Converts `self` to [`UInt<M>`](UInt) of any width, or returns `None`
+    /// if the value doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{int::UIntExt, U128, U256};
+    ///
+    /// assert_eq!(U256::from_digit(42).try_narrow::<2>(), Some(U128::from_digit(42)));
+    /// assert_eq!(U256::MAX.try_narrow::<2>(), None);
+    /// ```
+    #[must_use]
+    fn try_narrow<const M: usize>(self) -> Option<UInt<M>>;
+
+    /// Returns the full product `self * rhs` as [`UInt<M>`](UInt).
+    ///
+    /// The product as a `(low, high)` pair of `Self` is available as
+    /// [`widening_mul`](UInt::widening_mul).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `M` is less than twice the width of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{int::UIntExt, U128, U256};
+    ///
+    /// let p: U256 = U128::MAX.widening_mul_to(U128::MAX);
+    ///
+    /// assert_eq!(p, U256::from(u128::MAX) * U256::from(u128::MAX));
+    /// ```
+    #[must_use]
+    fn widening_mul_to<const M: usize>(self, rhs: Self) -> UInt<M>;
+}
+
+impl<const N: usize> UIntExt for UInt<N> {
+    #[inline]
+    fn isqrt(self) -> Self {
+        math::isqrt(self)
+    }
+
+    #[inline]
+    fn icbrt(self) -> Self {
+        math::inth_root(self, 3)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn inth_root(self, n: u32) -> Self {
+        math::inth_root(self, n)
+    }
+
+    #[inline]
+    fn is_perfect_square(&self) -> bool {
+        math::is_perfect_square(*self)
+    }
+
+    #[inline]
+    fn is_prime(&self) -> bool {
+        math::is_prime(*self)
+    }
+
+    #[inline]
+    fn next_prime(self) -> Option<Self> {
+        math::next_prime(self)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn to_str_radix_into<'a>(&self, buf: &'a mut [u8], radix: u32) -> Option<&'a str> {
+        radix::to_str_radix_into(self, buf, radix)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn from_radix_str(s: &str, radix: u32) -> Result<Self, ParseError> {
+        radix::from_str_radix(s, radix)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn widen<const M: usize>(self) -> UInt<M> {
+        resize::widen(self)
+    }
+
+    #[inline]
+    fn try_narrow<const M: usize>(self) -> Option<UInt<M>> {
+        resize::try_narrow(self)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn widening_mul_to<const M: usize>(self, rhs: Self) -> UInt<M> {
+        resize::widening_mul(self, rhs)
+    }
+}
+
+/// # Signed integer extension
+///
+/// Width-related methods for [`Int`] that are not provided by the underlying
+/// big integer backend.
+///
+/// This trait is sealed and implemented for every [`Int<N>`](Int) only.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{int::IntExt, I128, I256};
+///
+/// let x = I128::from(-5);
+///
+/// assert_eq!(x.widen::<4>(), I256::from(-5));
+/// assert_eq!(x.widening_mul_to::<4>(I128::MAX), I256::from(-5) * I256::from(i128::MAX));
+/// ```
+pub trait IntExt: sealed::Sealed + Sized {
+    /// The unsigned integer type of the same width.
+    type Unsigned;
+
+    /// Converts `self` to the wider type [`Int<M>`](Int) by sign extension.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `M` is less than the width of `self`. Use
+    /// [`try_narrow`](IntExt::try_narrow) for conversions to narrower types.
+    #[must_use]
+    fn widen<const M: usize>(self) -> Int<M>;
+
+    /// Converts `self` to [`Int<M>`](Int) of any width, or returns `None`
+    /// if the value doesn't fit.
+    #[must_use]
+    fn try_narrow<const M: usize>(self) -> Option<Int<M>>;
+
+    /// Returns the full product `self * rhs` as the two's complement low
+    /// half and the signed high half.
+    #[must_use]
+    fn widening_mul(self, rhs: Self) -> (Self::Unsigned, Self);
+
+    /// Returns the full product `self * rhs` as [`Int<M>`](Int).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `M` is less than twice the width of `self`.
+    #[must_use]
+    fn widening_mul_to<const M: usize>(self, rhs: Self) -> Int<M>;
+}
+
+impl<const N: usize> IntExt for Int<N> {
+    type Unsigned = UInt<N>;
+
+    #[track_caller]
+    #[inline]
+    fn widen<const M: usize>(self) -> Int<M> {
+        resize::widen_signed(self)
+    }
+
+    #[inline]
+    fn try_narrow<const M: usize>(self) -> Option<Int<M>> {
+        resize::try_narrow_signed(self)
+    }
+
+    #[inline]
+    fn widening_mul(self, rhs: Self) -> (UInt<N>, Self) {
+        resize::widening_mul_signed(self, rhs)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn widening_mul_to<const M: usize>(self, rhs: Self) -> Int<M> {
+        resize::widening_mul_signed_to(self, rhs)
+    }
+}
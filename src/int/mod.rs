@@ -8,8 +8,12 @@
 #[cfg(debug_assertions)]
 mod assertions;
 
+mod barrett;
 mod doc;
 mod error;
+mod ext;
+mod montgomery;
+pub(crate) mod resize;
 mod uint;
 
 use doc::int_type_doc;
@@ -17,9 +21,14 @@ use doc::int_type_doc;
 #[doc(hidden)]
 pub mod parse;
 
+pub mod ct;
+
 pub(crate) use uint::*;
 
+pub use barrett::BarrettCtx;
 pub use error::ParseError;
+pub use ext::{IntExt, UIntExt};
+pub use montgomery::MontgomeryCtx;
 
 /// Big unsigned integer generic type, of fixed size which must be known at compile time.
 pub type UInt<const N: usize> = bnum::BUint<N>;
@@ -46,7 +55,7 @@ macro_rules! _macro_impl {
         ///
         macro_rules! $macro_name {
             ($lit:expr) => {{
-                const __INT: $int = $crate::int::parse::$sign::parse_str(stringify!($lit));
+                const __INT: $crate::$type_name = $crate::int::parse::$sign::parse_str(stringify!($lit));
                 __INT
             }};
         }
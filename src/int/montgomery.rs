@@ -0,0 +1,255 @@
+use crate::{
+    int::{
+        intrinsics::{Digit, Digits},
+        math::{carrying_mul, mul_div_rem_wide},
+        UInt,
+    },
+    utils::err_msg,
+};
+
+type U<const N: usize> = UInt<N>;
+
+/// # Montgomery context
+///
+/// Precomputed context for repeated modular arithmetic under a fixed odd
+/// modulus using
+/// [Montgomery multiplication](https://en.wikipedia.org/wiki/Montgomery_modular_multiplication).
+///
+/// Values are kept in _Montgomery form_ _x·R mod m_, where _R =
+/// 2<sup>64·N</sup>_. Conversion in and out of this form is done by
+/// [`to_montgomery`](Self::to_montgomery) and
+/// [`from_montgomery`](Self::from_montgomery), while
+/// [`mul`](Self::mul), [`square`](Self::square) and [`pow`](Self::pow) operate
+/// on values that are already in Montgomery form, which makes long chains of
+/// multiplications (e.g. modular exponentiation) avoid any division.
+///
+/// All operations are `const` and can be used to build contexts for fixed
+/// moduli at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{int::MontgomeryCtx, U256};
+///
+/// // secp256k1 field prime
+/// const P: U256 = U256::parse_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16);
+/// const CTX: MontgomeryCtx<4> = MontgomeryCtx::new(P);
+///
+/// let x = CTX.to_montgomery(U256::from_digit(3));
+/// let y = CTX.pow(x, P.sub(U256::from_digit(2)));
+///
+/// // Fermat's little theorem: 3 · 3^(p-2) ≡ 1 (mod p)
+/// assert_eq!(CTX.from_montgomery(CTX.mul(x, y)), U256::from_digit(1));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MontgomeryCtx<const N: usize> {
+    modulus: U<N>,
+
+    /// _-m<sup>-1</sup> mod 2<sup>64</sup>_
+    m_inv: Digit,
+
+    /// _R mod m_ (Montgomery form of one).
+    one: U<N>,
+
+    /// _R<sup>2</sup> mod m_
+    r2: U<N>,
+}
+
+impl<const N: usize> MontgomeryCtx<N> {
+    /// Creates a Montgomery context for the given `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus` is even or less than `3`.
+    #[track_caller]
+    #[must_use]
+    #[inline]
+    pub const fn new(modulus: UInt<N>) -> Self {
+        if modulus.digits()[0] & 1 == 0 || modulus.le(&UInt::ONE) {
+            panic!(err_msg!("Montgomery modulus must be odd and greater than one"));
+        }
+
+        let one = U::MAX.rem(modulus).add(U::ONE);
+        let one = if one.eq(&modulus) { U::ZERO } else { one };
+        let (_, r2) = mul_div_rem_wide(one, one, modulus);
+
+        Self {
+            modulus,
+            m_inv: neg_inv(modulus.digits()[0]),
+            one,
+            r2,
+        }
+    }
+
+    /// Returns the modulus of this context.
+    #[must_use]
+    #[inline]
+    pub const fn modulus(&self) -> UInt<N> {
+        self.modulus
+    }
+
+    /// Returns the Montgomery form of `1`.
+    #[must_use]
+    #[inline]
+    pub const fn one(&self) -> UInt<N> {
+        self.one
+    }
+
+    /// Converts `x` into Montgomery form _x·R mod m_.
+    ///
+    /// `x` doesn't have to be reduced modulo `m`.
+    #[must_use]
+    #[inline]
+    pub const fn to_montgomery(&self, x: UInt<N>) -> UInt<N> {
+        let x = if x.ge(&self.modulus) {
+            x.rem(self.modulus)
+        } else {
+            x
+        };
+        self.mul(x, self.r2)
+    }
+
+    /// Converts `x` from Montgomery form back to the ordinary residue _x·R<sup>-1</sup>
+    /// mod m_.
+    #[must_use]
+    #[inline]
+    pub const fn from_montgomery(&self, x: UInt<N>) -> UInt<N> {
+        self.mul(x, U::ONE)
+    }
+
+    /// Montgomery product _a·b·R<sup>-1</sup> mod m_.
+    ///
+    /// If both `a` and `b` are in Montgomery form, the result is the Montgomery
+    /// form of their product. Both arguments must be less than the modulus.
+    #[must_use]
+    #[inline]
+    pub const fn mul(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        debug_assert!(a.lt(&self.modulus) && b.lt(&self.modulus));
+        mont_mul(a.digits(), b.digits(), self.modulus, self.m_inv)
+    }
+
+    /// Montgomery square _a<sup>2</sup>·R<sup>-1</sup> mod m_.
+    #[must_use]
+    #[inline]
+    pub const fn square(&self, a: UInt<N>) -> UInt<N> {
+        self.mul(a, a)
+    }
+
+    /// Raises `base` (in Montgomery form) to the power of `exp` (ordinary
+    /// integer) and returns the result in Montgomery form.
+    #[must_use]
+    #[inline]
+    pub const fn pow(&self, base: UInt<N>, exp: UInt<N>) -> UInt<N> {
+        let mut result = self.one;
+        let mut i = exp.bits();
+
+        while i > 0 {
+            i -= 1;
+            result = self.square(result);
+            if exp.bit(i) {
+                result = self.mul(result, base);
+            }
+        }
+
+        result
+    }
+
+    /// Modular addition _a + b mod m_ of two reduced values (in Montgomery
+    /// form or not).
+    #[must_use]
+    #[inline]
+    pub const fn add(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        add_mod(a, b, self.modulus)
+    }
+
+    /// Modular subtraction _a - b mod m_ of two reduced values (in Montgomery
+    /// form or not).
+    #[must_use]
+    #[inline]
+    pub const fn sub(&self, a: UInt<N>, b: UInt<N>) -> UInt<N> {
+        sub_mod(a, b, self.modulus)
+    }
+}
+
+#[inline]
+pub(crate) const fn add_mod<const N: usize>(a: U<N>, b: U<N>, m: U<N>) -> U<N> {
+    debug_assert!(a.lt(&m) && b.lt(&m));
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum.ge(&m) {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+#[inline]
+pub(crate) const fn sub_mod<const N: usize>(a: U<N>, b: U<N>, m: U<N>) -> U<N> {
+    debug_assert!(a.lt(&m) && b.lt(&m));
+    let (diff, overflow) = a.overflowing_sub(b);
+    if overflow {
+        diff.wrapping_add(m)
+    } else {
+        diff
+    }
+}
+
+/// Computes _-m<sup>-1</sup> mod 2<sup>64</sup>_ for odd _m_ by Newton–Hensel
+/// lifting (each step doubles the number of correct low bits).
+#[inline]
+const fn neg_inv(m: Digit) -> Digit {
+    debug_assert!(m & 1 == 1);
+
+    let mut inv: Digit = 1;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul((2 as Digit).wrapping_sub(m.wrapping_mul(inv)));
+        i += 1;
+    }
+
+    inv.wrapping_neg()
+}
+
+/// Coarsely Integrated Operand Scanning (CIOS) Montgomery multiplication.
+#[inline]
+const fn mont_mul<const N: usize>(a: &Digits<N>, b: &Digits<N>, modulus: U<N>, m_inv: Digit) -> U<N> {
+    let m = modulus.digits();
+
+    let mut t = [0; N];
+    let mut t_hi: Digit = 0;
+    let mut i = 0;
+
+    while i < N {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            (t[j], carry) = carrying_mul::<N>(a[j], b[i], carry, t[j]);
+            j += 1;
+        }
+
+        let (hi, overflow) = t_hi.overflowing_add(carry);
+        let t_top = overflow as Digit;
+
+        let u = t[0].wrapping_mul(m_inv);
+        let (_, mut carry) = carrying_mul::<N>(u, m[0], 0, t[0]);
+
+        j = 1;
+        while j < N {
+            (t[j - 1], carry) = carrying_mul::<N>(u, m[j], carry, t[j]);
+            j += 1;
+        }
+
+        let overflow;
+        (t[N - 1], overflow) = hi.overflowing_add(carry);
+        t_hi = t_top + overflow as Digit;
+
+        i += 1;
+    }
+
+    // t < 2m
+    let t = U::from_digits(t);
+    if t_hi != 0 || t.ge(&modulus) {
+        t.wrapping_sub(modulus)
+    } else {
+        t
+    }
+}
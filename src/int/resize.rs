@@ -0,0 +1,166 @@
+use crate::{
+    int::{
+        intrinsics::{Digit, DoubleDigit, Digits, BITS},
+        Int, UInt,
+    },
+    utils::err_msg,
+};
+
+/// Copies the low `min(N, M)` digits and fills the remaining high digits
+/// with `fill`.
+#[inline(always)]
+const fn resize<const N: usize, const M: usize>(digits: &Digits<N>, fill: Digit) -> Digits<M> {
+    let mut res = [fill; M];
+    let mut i = 0;
+    while i < N && i < M {
+        res[i] = digits[i];
+        i += 1;
+    }
+    res
+}
+
+#[track_caller]
+#[inline(always)]
+const fn assert_not_narrower<const N: usize, const M: usize>() {
+    if M < N {
+        panic!(err_msg!("target width is narrower than the source"));
+    }
+}
+
+#[track_caller]
+#[inline(always)]
+const fn assert_full_product<const N: usize, const M: usize>() {
+    if M < 2 * N {
+        panic!(err_msg!("target width is too narrow for the full product"));
+    }
+}
+
+/// Zero-extends `n` to `M` digits.
+///
+/// # Panics
+///
+/// This function will panic if `M < N`.
+#[track_caller]
+#[inline]
+pub const fn widen<const N: usize, const M: usize>(n: UInt<N>) -> UInt<M> {
+    assert_not_narrower::<N, M>();
+    UInt::from_digits(resize(n.digits(), 0))
+}
+
+/// Converts `n` to `M` digits if its value fits.
+#[inline]
+pub const fn try_narrow<const N: usize, const M: usize>(n: UInt<N>) -> Option<UInt<M>> {
+    if n.bits() > UInt::<M>::BITS {
+        None
+    } else {
+        Some(UInt::from_digits(resize(n.digits(), 0)))
+    }
+}
+
+/// Sign-extends `n` to `M` digits.
+///
+/// # Panics
+///
+/// This function will panic if `M < N`.
+#[track_caller]
+#[inline]
+pub const fn widen_signed<const N: usize, const M: usize>(n: Int<N>) -> Int<M> {
+    assert_not_narrower::<N, M>();
+    Int::from_bits(UInt::from_digits(resize(n.to_bits().digits(), sign_fill(n))))
+}
+
+/// Converts `n` to `M` digits if its value fits.
+#[inline]
+pub const fn try_narrow_signed<const N: usize, const M: usize>(n: Int<N>) -> Option<Int<M>> {
+    let fill = sign_fill(n);
+    let res = Int::<M>::from_bits(UInt::from_digits(resize(n.to_bits().digits(), fill)));
+
+    // The value fits iff sign-extending the result gives it back.
+    let back = UInt::<N>::from_digits(resize(res.to_bits().digits(), sign_fill(res)));
+    if back.eq(&n.to_bits()) {
+        Some(res)
+    } else {
+        None
+    }
+}
+
+/// Full product of `lhs` and `rhs` in `M` digits.
+///
+/// # Panics
+///
+/// This function will panic if `M < 2 * N`.
+#[track_caller]
+#[inline]
+pub const fn widening_mul<const N: usize, const M: usize>(lhs: UInt<N>, rhs: UInt<N>) -> UInt<M> {
+    assert_full_product::<N, M>();
+
+    let a = lhs.digits();
+    let b = rhs.digits();
+    let mut res = [0; M];
+
+    let mut i = 0;
+    while i < N {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            let t = (a[j] as DoubleDigit) * (b[i] as DoubleDigit)
+                + res[i + j] as DoubleDigit
+                + carry as DoubleDigit;
+            res[i + j] = t as Digit;
+            carry = (t >> BITS) as Digit;
+            j += 1;
+        }
+        res[i + N] = carry;
+        i += 1;
+    }
+
+    UInt::from_digits(res)
+}
+
+/// Full signed product of `lhs` and `rhs` as its two's complement low half
+/// and signed high half.
+#[inline]
+pub const fn widening_mul_signed<const N: usize>(lhs: Int<N>, rhs: Int<N>) -> (UInt<N>, Int<N>) {
+    let (mut low, mut high) = lhs.unsigned_abs().widening_mul(rhs.unsigned_abs());
+
+    if lhs.is_negative() != rhs.is_negative() {
+        // Two's complement negation of the double-width value.
+        low = low.not().wrapping_add(UInt::ONE);
+        high = high.not();
+        if low.is_zero() {
+            high = high.wrapping_add(UInt::ONE);
+        }
+    }
+
+    (low, Int::from_bits(high))
+}
+
+/// Full signed product of `lhs` and `rhs` in `M` digits.
+///
+/// # Panics
+///
+/// This function will panic if `M < 2 * N`.
+#[track_caller]
+#[inline]
+pub const fn widening_mul_signed_to<const N: usize, const M: usize>(
+    lhs: Int<N>,
+    rhs: Int<N>,
+) -> Int<M> {
+    let res = Int::from_bits(widening_mul::<N, M>(lhs.unsigned_abs(), rhs.unsigned_abs()));
+
+    // |lhs·rhs| <= 2^(2·BITS - 2), so the negation can't overflow.
+    if lhs.is_negative() != rhs.is_negative() {
+        res.wrapping_neg()
+    } else {
+        res
+    }
+}
+
+#[inline(always)]
+const fn sign_fill<const N: usize>(n: Int<N>) -> Digit {
+    if n.is_negative() {
+        Digit::MAX
+    } else {
+        0
+    }
+}
@@ -23,12 +23,14 @@ impl<const N: usize> PowersOf10<N> {
         let mut res = [[UInt::ZERO; POWER as usize + 1]; N];
         res[0][0] = UInt::ONE;
 
+        let max = UInt::<N>::MAX.div(UInt::TEN);
+
         let mut v;
         let mut j = 0;
         let mut i = 1;
         v = UInt::ONE;
-        while v.le(&UInt::<N>::MAX.div(UInt::TEN)) {
-            v = v.strict_mul(UInt::TEN);
+        while v.le(&max) {
+            v = mul_ten(v);
             res[j][i] = v;
             i += 1;
 
@@ -54,11 +56,28 @@ impl<const N: usize> PowersOf10<N> {
     }
 }
 
+/// _v·10_ by a single pass over the digits, which keeps building the table
+/// for the wide types cheap in const evaluation.
+#[inline]
+const fn mul_ten<const N: usize>(v: UInt<N>) -> UInt<N> {
+    let mut digits = *v.digits();
+    let mut carry = 0;
+    let mut i = 0;
+
+    while i < N {
+        let t = digits[i] as DoubleDigit * 10 + carry as DoubleDigit;
+        digits[i] = t as Digit;
+        carry = (t >> BITS) as Digit;
+        i += 1;
+    }
+
+    UInt::from_digits(digits)
+}
+
 pub struct Intrinsics<const N: usize>;
 
 impl<const N: usize> Intrinsics<N> {
     pub const MAX_POWER_OF_TEN: u32 = (POWER + 1) * (N as u32);
 
-    #[allow(long_running_const_eval)]
     pub const POWERS_OF_TEN: PowersOf10<N> = PowersOf10::new();
 }
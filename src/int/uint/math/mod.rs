@@ -1,8 +1,12 @@
 mod ilog;
 mod mul;
+mod prime;
+mod root;
 
 pub use ilog::ilog10;
 pub use mul::{overflowing_mul10, strict_mul10};
+pub use prime::{is_prime, next_prime};
+pub use root::{inth_root, is_perfect_square, isqrt};
 
 use core::cmp::Ordering;
 
@@ -267,7 +271,7 @@ const fn carrying_add(a: Digit, b: Digit, carry: bool) -> (Digit, bool) {
 }
 
 #[inline]
-const fn carrying_mul<const N: usize>(
+pub const fn carrying_mul<const N: usize>(
     a: Digit,
     b: Digit,
     carry: Digit,
@@ -0,0 +1,255 @@
+use crate::int::{
+    intrinsics::Digit,
+    math::{div_rem_digit, is_perfect_square},
+    MontgomeryCtx, UInt,
+};
+
+type U<const N: usize> = UInt<N>;
+
+/// Primes used for trial division and as Miller–Rabin bases.
+const SMALL_PRIMES: [Digit; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Product of all [`SMALL_PRIMES`], fits into a single digit.
+const PRIMORIAL: Digit = 614_889_782_588_491_410;
+
+/// Every number below _47<sup>2</sup>_ that survives trial division is prime.
+const TRIAL_DIVISION_LIMIT: Digit = 47 * 47;
+
+/// Miller–Rabin with the first `13` primes as bases is deterministic for
+/// _n < 3.3·10<sup>24</sup>_, which covers every _n < 2<sup>81</sup>_
+/// (Sorenson & Webster, 2015).
+const DETERMINISTIC_MR_BITS: u32 = 81;
+const DETERMINISTIC_MR_BASES: usize = 13;
+
+/// Primality test.
+///
+/// Trial division by small primes is followed by the deterministic
+/// Miller–Rabin test for _n < 2<sup>81</sup>_ and by the
+/// [Baillie–PSW](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test)
+/// test (strong base-2 Miller–Rabin and strong Lucas test) above that. No
+/// Baillie–PSW pseudoprime is known.
+#[inline]
+pub const fn is_prime<const N: usize>(n: U<N>) -> bool {
+    let (_, r) = div_rem_digit(n, PRIMORIAL);
+
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        if r % SMALL_PRIMES[i] == 0 {
+            return n.eq(&U::from_digit(SMALL_PRIMES[i]));
+        }
+        i += 1;
+    }
+
+    if n.lt(&U::from_digit(TRIAL_DIVISION_LIMIT)) {
+        return n.gt(&U::ONE);
+    }
+
+    let ctx = MontgomeryCtx::new(n);
+
+    if n.bits() <= DETERMINISTIC_MR_BITS {
+        let mut i = 0;
+        while i < DETERMINISTIC_MR_BASES {
+            if !miller_rabin(&ctx, SMALL_PRIMES[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    } else {
+        miller_rabin(&ctx, 2) && strong_lucas(&ctx)
+    }
+}
+
+/// Returns the smallest prime strictly greater than `n`, or `None` if it
+/// doesn't fit into `UInt<N>`.
+#[inline]
+pub const fn next_prime<const N: usize>(n: U<N>) -> Option<U<N>> {
+    if n.le(&U::ONE) {
+        return Some(U::from_digit(2));
+    }
+
+    let mut candidate = if n.digits()[0] & 1 == 0 {
+        n.add(U::ONE)
+    } else {
+        match n.checked_add(U::from_digit(2)) {
+            Some(c) => c,
+            None => return None,
+        }
+    };
+
+    loop {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+
+        candidate = match candidate.checked_add(U::from_digit(2)) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+}
+
+/// Strong probable prime test to the given `base`.
+#[inline]
+const fn miller_rabin<const N: usize>(ctx: &MontgomeryCtx<N>, base: Digit) -> bool {
+    let n_1 = ctx.modulus().sub(U::ONE);
+    let s = n_1.trailing_zeros();
+    let d = n_1.shr(s);
+
+    let one = ctx.one();
+    let minus_one = ctx.sub(U::ZERO, one);
+
+    let mut x = ctx.pow(ctx.to_montgomery(U::from_digit(base)), d);
+    if x.eq(&one) || x.eq(&minus_one) {
+        return true;
+    }
+
+    let mut i = 1;
+    while i < s {
+        x = ctx.square(x);
+        if x.eq(&minus_one) {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Strong Lucas probable prime test with Selfridge's parameters: _D_ is the
+/// first of _5, -7, 9, -11, …_ with Jacobi symbol _(D/n) = -1_, _P = 1_ and
+/// _Q = (1 - D) / 4_.
+#[inline]
+const fn strong_lucas<const N: usize>(ctx: &MontgomeryCtx<N>) -> bool {
+    let n = ctx.modulus();
+
+    // Selfridge's search doesn't terminate for perfect squares.
+    if is_perfect_square(n) {
+        return false;
+    }
+
+    let mut d_abs: Digit = 5;
+    let mut d_neg = false;
+    loop {
+        match jacobi(d_abs, d_neg, n) {
+            -1 => break,
+            0 => return false,
+            _ => {}
+        }
+        d_abs += 2;
+        d_neg = !d_neg;
+    }
+
+    let d = ctx.to_montgomery(U::from_digit(d_abs));
+    let (d, q) = if d_neg {
+        (
+            ctx.sub(U::ZERO, d),
+            ctx.to_montgomery(U::from_digit((d_abs + 1) / 4)),
+        )
+    } else {
+        (
+            d,
+            ctx.sub(U::ZERO, ctx.to_montgomery(U::from_digit((d_abs - 1) / 4))),
+        )
+    };
+
+    // n + 1 = k·2^s, n < MAX since MAX is divisible by 3.
+    let n_1 = n.add(U::ONE);
+    let s = n_1.trailing_zeros();
+    let k = n_1.shr(s);
+
+    let mut u = ctx.one();
+    let mut v = ctx.one();
+    let mut q_k = q;
+
+    let mut i = k.bits() - 1;
+    while i > 0 {
+        i -= 1;
+
+        // (U_2j, V_2j) = (U_j·V_j, V_j^2 - 2Q^j)
+        u = ctx.mul(u, v);
+        v = ctx.sub(ctx.square(v), ctx.add(q_k, q_k));
+        q_k = ctx.square(q_k);
+
+        if k.bit(i) {
+            // (U_j+1, V_j+1) = ((P·U_j + V_j) / 2, (D·U_j + P·V_j) / 2)
+            let u_next = half(ctx.add(u, v), n);
+            v = half(ctx.add(ctx.mul(d, u), v), n);
+            u = u_next;
+            q_k = ctx.mul(q_k, q);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    let mut r = 1;
+    while r < s {
+        v = ctx.sub(ctx.square(v), ctx.add(q_k, q_k));
+        if v.is_zero() {
+            return true;
+        }
+        q_k = ctx.square(q_k);
+        r += 1;
+    }
+
+    false
+}
+
+/// _x / 2 mod n_ for odd `n` and `x < n`.
+#[inline]
+const fn half<const N: usize>(x: U<N>, n: U<N>) -> U<N> {
+    if x.digits()[0] & 1 == 0 {
+        x.shr(1)
+    } else {
+        // (x + n) / 2 without overflow
+        x.shr(1).add(n.shr(1)).add(U::ONE)
+    }
+}
+
+/// Jacobi symbol _(±a / n)_ for odd `a` and odd `n`.
+#[inline]
+const fn jacobi<const N: usize>(a: Digit, negative: bool, n: U<N>) -> i32 {
+    let n_mod_4 = n.digits()[0] & 3;
+
+    // (-1 / n) = (-1)^((n - 1) / 2)
+    let mut t = if negative && n_mod_4 == 3 { -1 } else { 1 };
+
+    // Quadratic reciprocity: (a / n) = (n / a)·(-1)^((a - 1)(n - 1) / 4)
+    if a & 3 == 3 && n_mod_4 == 3 {
+        t = -t;
+    }
+
+    let (_, r) = div_rem_digit(n, a);
+    t * jacobi_digit(r, a)
+}
+
+/// Jacobi symbol _(a / n)_ for odd `n`.
+#[inline]
+const fn jacobi_digit(mut a: Digit, mut n: Digit) -> i32 {
+    let mut t = 1;
+    a %= n;
+
+    while a != 0 {
+        while a & 1 == 0 {
+            a >>= 1;
+            let r = n & 7;
+            if r == 3 || r == 5 {
+                t = -t;
+            }
+        }
+
+        (a, n) = (n, a);
+        if a & 3 == 3 && n & 3 == 3 {
+            t = -t;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        t
+    } else {
+        0
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    int::{math::div_rem, UInt},
+    utils::err_msg,
+};
+
+type U<const N: usize> = UInt<N>;
+
+/// Quadratic residues modulo `64`: bit `i` is set iff `i` is a square mod
+/// `64`.
+const SQUARES_MOD_64: u64 = 0x0202021202030213;
+
+/// Integer square root _⌊√n⌋_.
+///
+/// Newton's iteration _x<sub>k+1</sub> = ⌊(x<sub>k</sub> + ⌊n /
+/// x<sub>k</sub>⌋) / 2⌋_ started from a power of two not less than the root
+/// decreases monotonically until it reaches the exact floor.
+#[inline]
+pub const fn isqrt<const N: usize>(n: U<N>) -> U<N> {
+    if n.le(&U::ONE) {
+        return n;
+    }
+
+    let mut x = U::ONE.shl(n.bits().div_ceil(2));
+
+    loop {
+        let y = x.add(div_rem(n, x).0).shr(1);
+        if y.ge(&x) {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Integer `k`-th root _⌊n<sup>1/k</sup>⌋_.
+///
+/// # Panics
+///
+/// This function will panic if `k` is zero.
+#[track_caller]
+#[inline]
+pub const fn inth_root<const N: usize>(n: U<N>, k: u32) -> U<N> {
+    if k == 0 {
+        panic!(err_msg!("zeroth root is undefined"));
+    }
+
+    if k == 1 || n.le(&U::ONE) {
+        return n;
+    }
+
+    if k == 2 {
+        return isqrt(n);
+    }
+
+    let bits = n.bits();
+    if k >= bits {
+        return U::ONE;
+    }
+
+    // x_{j+1} = ⌊((k - 1)·x_j + ⌊n / x_j^(k-1)⌋) / k⌋
+    let k_1 = U::from_digit((k - 1) as u64);
+    let mut x = U::ONE.shl(bits.div_ceil(k));
+
+    loop {
+        let q = match x.checked_pow(k - 1) {
+            Some(p) => div_rem(n, p).0,
+            None => U::ZERO,
+        };
+        let y = div_rem(k_1.mul(x).add(q), U::from_digit(k as u64)).0;
+        if y.ge(&x) {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Returns `true` if `n` is a perfect square.
+#[inline]
+pub const fn is_perfect_square<const N: usize>(n: U<N>) -> bool {
+    if (SQUARES_MOD_64 >> (n.digits()[0] & 63)) & 1 == 0 {
+        return false;
+    }
+
+    let r = isqrt(n);
+    r.mul(r).eq(&n)
+}
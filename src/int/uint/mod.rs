@@ -1,3 +1,4 @@
 pub mod math;
 pub mod convert;
 pub mod intrinsics;
+pub mod radix;
@@ -0,0 +1,218 @@
+//! Arithmetic on little-endian limb slices of arbitrary length.
+//!
+//! Unlike [`UInt`](crate::int::UInt) operations these work on the significant
+//! limbs only, so the cost of every step of the divide-and-conquer radix
+//! conversion is proportional to the size of its operands rather than to the
+//! full width of the integer type.
+
+use core::cmp::Ordering;
+
+use crate::int::intrinsics::{Digit, DoubleDigit, BITS};
+
+/// Below this number of limbs schoolbook multiplication is faster than
+/// Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Number of significant limbs of `a`.
+#[inline]
+pub(super) fn len(a: &[Digit]) -> usize {
+    let mut n = a.len();
+    while n > 0 && a[n - 1] == 0 {
+        n -= 1;
+    }
+    n
+}
+
+#[inline]
+pub(super) fn cmp(a: &[Digit], b: &[Digit]) -> Ordering {
+    let (la, lb) = (len(a), len(b));
+    if la != lb {
+        return la.cmp(&lb);
+    }
+
+    let mut i = la;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i].cmp(&b[i]);
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// _a += b_, returns the carry out of `a`.
+#[inline]
+pub(super) fn add_assign(a: &mut [Digit], b: &[Digit]) -> bool {
+    let b = &b[..len(b)];
+    debug_assert!(a.len() >= b.len());
+
+    let mut carry = false;
+    for (i, ai) in a.iter_mut().enumerate() {
+        let bi = match b.get(i) {
+            Some(&bi) => bi,
+            None if !carry => break,
+            None => 0,
+        };
+        let (s, c1) = ai.overflowing_add(bi);
+        let (s, c2) = s.overflowing_add(carry as Digit);
+        *ai = s;
+        carry = c1 | c2;
+    }
+
+    carry
+}
+
+/// _a -= b_, returns the borrow out of `a`.
+#[inline]
+pub(super) fn sub_assign(a: &mut [Digit], b: &[Digit]) -> bool {
+    let b = &b[..len(b)];
+    debug_assert!(a.len() >= b.len());
+
+    let mut borrow = false;
+    for (i, ai) in a.iter_mut().enumerate() {
+        let bi = match b.get(i) {
+            Some(&bi) => bi,
+            None if !borrow => break,
+            None => 0,
+        };
+        let (d, b1) = ai.overflowing_sub(bi);
+        let (d, b2) = d.overflowing_sub(borrow as Digit);
+        *ai = d;
+        borrow = b1 | b2;
+    }
+
+    borrow
+}
+
+/// _a = a·m + c_, returns the carry limb.
+#[inline]
+pub(super) fn mul_digit_add_assign(a: &mut [Digit], m: Digit, c: Digit) -> Digit {
+    let mut carry = c;
+    for ai in a.iter_mut() {
+        let t = (*ai as DoubleDigit) * (m as DoubleDigit) + carry as DoubleDigit;
+        *ai = t as Digit;
+        carry = (t >> BITS) as Digit;
+    }
+    carry
+}
+
+/// _out = a·b_.
+///
+/// `out` must hold at least as many limbs as the significant limbs of `a`
+/// and `b` together. `scratch` must hold at least four times as many limbs
+/// as the longer operand plus a small logarithmic overhead.
+pub(super) fn mul(out: &mut [Digit], a: &[Digit], b: &[Digit], scratch: &mut [Digit]) {
+    let a = &a[..len(a)];
+    let b = &b[..len(b)];
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    debug_assert!(out.len() >= a.len() + b.len());
+
+    out.fill(0);
+
+    if b.len() < KARATSUBA_THRESHOLD {
+        schoolbook_mul(out, a, b);
+        return;
+    }
+
+    let h = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(h);
+
+    if b.len() <= h {
+        // Unbalanced operands: a·b = a0·b + a1·b·β^h
+        mul(&mut out[..h + b.len()], a0, b, scratch);
+        let (t, scratch) = scratch.split_at_mut(a1.len() + b.len());
+        mul(t, a1, b, scratch);
+        add_assign(&mut out[h..], t);
+        return;
+    }
+
+    // Karatsuba: a·b = z2·β^2h + (z1 - z2 - z0)·β^h + z0
+    let (b0, b1) = b.split_at(h);
+    {
+        let (z0, z2) = out.split_at_mut(2 * h);
+        mul(z0, a0, b0, scratch);
+        mul(z2, a1, b1, scratch);
+    }
+
+    let n = h + 1;
+    let (sa, scratch) = scratch.split_at_mut(n);
+    sa.fill(0);
+    sa[..h].copy_from_slice(a0);
+    add_assign(sa, a1);
+
+    let (sb, scratch) = scratch.split_at_mut(n);
+    sb.fill(0);
+    sb[..h].copy_from_slice(b0);
+    add_assign(sb, b1);
+
+    let (z1, scratch) = scratch.split_at_mut(2 * n);
+    mul(z1, sa, sb, scratch);
+    sub_assign(z1, &out[..2 * h]);
+    sub_assign(z1, &out[2 * h..]);
+    add_assign(&mut out[h..], z1);
+}
+
+#[inline]
+fn schoolbook_mul(out: &mut [Digit], a: &[Digit], b: &[Digit]) {
+    for (i, &bi) in b.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &aj) in a.iter().enumerate() {
+            let t = (aj as DoubleDigit) * (bi as DoubleDigit)
+                + out[i + j] as DoubleDigit
+                + carry as DoubleDigit;
+            out[i + j] = t as Digit;
+            carry = (t >> BITS) as Digit;
+        }
+        out[i + a.len()] = carry;
+    }
+}
+
+/// Barrett division _x = q·p + r_ for _x < p<sup>2</sup>_.
+///
+/// `mu` is _⌊β<sup>2s</sup> / p⌋_ where `s` is the number of limbs of `p`
+/// and _β = 2<sup>64</sup>_. The quotient is written into `q` (at least
+/// `s + 1` limbs) and the remainder into `r` (at least `s + 1` limbs).
+pub(super) fn div_rem_barrett(
+    x: &[Digit],
+    p: &[Digit],
+    mu: &[Digit],
+    q: &mut [Digit],
+    r: &mut [Digit],
+    scratch: &mut [Digit],
+) {
+    let s = p.len();
+    let x = &x[..len(x)];
+    debug_assert!(x.len() <= 2 * s);
+
+    q.fill(0);
+    r.fill(0);
+
+    if x.len() < s {
+        r[..x.len()].copy_from_slice(x);
+        return;
+    }
+
+    // q ≈ ⌊⌊x / β^(s-1)⌋·μ / β^(s+1)⌋, underestimates the quotient by at most 2.
+    let q1 = &x[s - 1..];
+    let (q2, scratch) = scratch.split_at_mut(q1.len() + mu.len());
+    mul(q2, q1, mu, scratch);
+    let q3 = &q2[s + 1..];
+    let q3 = &q3[..len(q3)];
+    q[..q3.len()].copy_from_slice(q3);
+
+    let (t, scratch) = scratch.split_at_mut(q3.len() + s);
+    mul(t, q3, p, scratch);
+
+    let (rem, _) = scratch.split_at_mut(x.len());
+    rem.copy_from_slice(x);
+    sub_assign(rem, t);
+
+    while cmp(rem, p).is_ge() {
+        sub_assign(rem, p);
+        add_assign(q, &[1]);
+    }
+
+    let l = len(rem);
+    r[..l].copy_from_slice(&rem[..l]);
+}
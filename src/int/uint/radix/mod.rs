@@ -0,0 +1,414 @@
+//! Subquadratic conversion of [`UInt`] to and from strings.
+//!
+//! Radix `10` uses divide-and-conquer: the value is split by a cached power
+//! _10<sup>19·2<sup>k</sup></sup>_ (Barrett division when formatting,
+//! Karatsuba multiplication when parsing) and both halves are converted
+//! recursively, which is _O(M(n)·log n)_ instead of the _O(n<sup>2</sup>)_
+//! digit-by-digit method. Power-of-two radixes extract bits directly. Other
+//! radixes use a chunked schoolbook method.
+
+mod limbs;
+mod pow10;
+
+use crate::{
+    int::{
+        intrinsics::{Digit, DoubleDigit, BITS, POWER},
+        ParseError, UInt,
+    },
+    utils::err_msg,
+};
+
+use pow10::{Level, LEVELS};
+
+type U<const N: usize> = UInt<N>;
+
+/// Values of up to this many limbs are formatted directly via `u128`.
+const FORMAT_BASECASE_LIMBS: usize = 2;
+
+/// Strings of up to this many digits are parsed by schoolbook accumulation.
+const PARSE_BASECASE_DIGITS: usize = 8 * POWER as usize;
+
+/// Widest type whose decimal digits can be split by the cached powers of
+/// ten, larger types fall back to the schoolbook method.
+const MAX_LIMBS: usize = 128;
+
+/// Scratch space, in multiples of `N` limbs, sufficient for any conversion of
+/// a `UInt<N>`.
+const SCRATCH: usize = 16;
+
+const DIGIT_CHARS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Writes the digits of `n` in the given `radix` into the beginning of `buf`
+/// and returns them as a string slice, or `None` if `buf` is too small.
+///
+/// A buffer of [`UInt::<N>::BITS`](UInt::BITS) bytes is always large enough.
+///
+/// # Panics
+///
+/// This function will panic if `radix` is not in the range `2..=36`.
+#[track_caller]
+pub fn to_str_radix_into<'a, const N: usize>(
+    n: &U<N>,
+    buf: &'a mut [u8],
+    radix: u32,
+) -> Option<&'a str> {
+    assert_radix(radix);
+
+    let len = if n.is_zero() {
+        if buf.is_empty() {
+            return None;
+        }
+        buf[0] = b'0';
+        1
+    } else if radix.is_power_of_two() {
+        write_pow2(n.digits(), buf, radix.trailing_zeros())?
+    } else if radix == 10 && N <= MAX_LIMBS {
+        // Upper bound of the number of digits, _log10(2) < 0.30103_. The
+        // digits are written zero-padded into a stack buffer first.
+        let width = n.bits() as usize * 30103 / 100000 + 1;
+        let mut tmp = [[0; 20]; N];
+        let tmp = &mut tmp.as_flattened_mut()[..width];
+
+        let mut scratch = [[0; N]; SCRATCH];
+        write_dec(n.digits(), tmp, scratch.as_flattened_mut());
+
+        let zeros = tmp.iter().take_while(|&&c| c == b'0').count();
+        let len = width - zeros;
+        if buf.len() < len {
+            return None;
+        }
+        buf[..len].copy_from_slice(&tmp[zeros..]);
+        len
+    } else {
+        write_chunked(&mut n.digits().clone(), buf, radix)?
+    };
+
+    // Only ASCII digits have been written.
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+/// Writes the digits of _x · 10<sup>k</sup>_ in the given `radix` into the
+/// beginning of `buf` and returns their number, or `None` if `buf` is too
+/// small.
+///
+/// `x` holds the limbs of the value, least significant first, and must have
+/// room for the product in its upper limbs. It is used as scratch space.
+/// Unlike [`to_str_radix_into`] this isn't limited by the width of a `UInt`.
+///
+/// # Panics
+///
+/// This function will panic if `radix` is not in the range `2..=36`.
+#[track_caller]
+pub(crate) fn write_mul_pow10(x: &mut [Digit], k: u32, buf: &mut [u8], radix: u32) -> Option<usize> {
+    assert_radix(radix);
+
+    let mut k = k;
+    while k > 0 {
+        let p = k.min(POWER);
+        let carry = limbs::mul_digit_add_assign(x, (10 as Digit).pow(p), 0);
+        debug_assert_eq!(carry, 0);
+        k -= p;
+    }
+
+    if limbs::len(x) == 0 {
+        if buf.is_empty() {
+            return None;
+        }
+        buf[0] = b'0';
+        Some(1)
+    } else if radix.is_power_of_two() {
+        write_pow2(x, buf, radix.trailing_zeros())
+    } else {
+        write_chunked(x, buf, radix)
+    }
+}
+
+/// Parses an integer in the given `radix`.
+///
+/// Accepts the same input as [`UInt::from_str_radix`]: an optional leading
+/// `+` followed by at least one digit of the radix, case-insensitive.
+///
+/// # Panics
+///
+/// This function will panic if `radix` is not in the range `2..=36`.
+#[track_caller]
+pub fn from_str_radix<const N: usize>(s: &str, radix: u32) -> Result<U<N>, ParseError> {
+    assert_radix(radix);
+
+    let src = s.as_bytes();
+    let src = match src {
+        [] => return Err(ParseError::Empty),
+        [b'+', rest @ ..] => rest,
+        _ => src,
+    };
+
+    if src.is_empty() {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    let leading_zeros = src.iter().take_while(|&&c| c == b'0').count();
+    let src = &src[leading_zeros..];
+
+    let mut digits = [[0u8; 64]; N];
+    let digits = digits.as_flattened_mut();
+
+    // Any string longer than the type has bits overflows, digits are
+    // validated first so that invalid input is reported as such.
+    let mut count = 0;
+    for &c in src {
+        let d = match (c as char).to_digit(radix) {
+            Some(d) => d as u8,
+            None => return Err(ParseError::InvalidDigit),
+        };
+        if count < digits.len() {
+            digits[count] = d;
+        }
+        count += 1;
+    }
+
+    // The value is at least radix^(count - 1).
+    if count > digits.len()
+        || (count > 0 && (count as u64 - 1) * radix.ilog2() as u64 >= (N as u64) * BITS as u64)
+    {
+        return Err(ParseError::PosOverflow);
+    }
+
+    let digits = &digits[..count];
+
+    if radix.is_power_of_two() {
+        parse_pow2(digits, radix.trailing_zeros())
+    } else {
+        let mut scratch = [[0; N]; SCRATCH];
+        let scratch = scratch.as_flattened_mut();
+        let (out, scratch) = scratch.split_at_mut(count / chunk_len(radix) + 2);
+
+        if radix == 10 && N <= MAX_LIMBS {
+            parse_dec(digits, out, scratch);
+        } else {
+            parse_chunked(digits, radix, out);
+        }
+
+        let len = limbs::len(out);
+        if len > N {
+            return Err(ParseError::PosOverflow);
+        }
+
+        let mut res = [0; N];
+        res[..len].copy_from_slice(&out[..len]);
+        Ok(U::from_digits(res))
+    }
+}
+
+#[track_caller]
+#[inline]
+fn assert_radix(radix: u32) {
+    if !(2..=36).contains(&radix) {
+        panic!(err_msg!("radix must be in the range 2..=36"));
+    }
+}
+
+/// Writes exactly `out.len()` decimal digits of `x`, zero-padded on the left.
+/// `x` must be less than _10<sup>out.len()</sup>_.
+fn write_dec(x: &[Digit], out: &mut [u8], scratch: &mut [Digit]) {
+    let x = &x[..limbs::len(x)];
+
+    if x.len() <= FORMAT_BASECASE_LIMBS {
+        let mut v = 0;
+        for &d in x.iter().rev() {
+            v = (v << BITS) | d as DoubleDigit;
+        }
+        write_u128(v, out);
+        return;
+    }
+
+    let width = out.len();
+    let level = split_level(width);
+    let s = level.pow.len();
+
+    let (q, scratch) = scratch.split_at_mut(s + 1);
+    let (r, scratch) = scratch.split_at_mut(s + 1);
+    limbs::div_rem_barrett(x, level.pow, level.mu, q, r, scratch);
+
+    let (hi, lo) = out.split_at_mut(width - level.digits);
+    write_dec(q, hi, scratch);
+    write_dec(r, lo, scratch);
+}
+
+/// The largest cached power of ten with fewer than `width` digits.
+#[inline]
+fn split_level(width: usize) -> &'static Level {
+    let mut k = 0;
+    while k + 1 < LEVELS.len() && LEVELS[k + 1].digits < width {
+        k += 1;
+    }
+    &LEVELS[k]
+}
+
+#[inline]
+fn write_u128(mut v: DoubleDigit, out: &mut [u8]) {
+    const BASE: DoubleDigit = 10u128.pow(POWER);
+
+    let mut end = out.len();
+    while end > 0 && v != 0 {
+        let start = end.saturating_sub(POWER as usize);
+        write_u64((v % BASE) as Digit, &mut out[start..end]);
+        v /= BASE;
+        end = start;
+    }
+    out[..end].fill(b'0');
+}
+
+#[inline]
+fn write_u64(mut v: Digit, out: &mut [u8]) {
+    for c in out.iter_mut().rev() {
+        *c = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+}
+
+fn write_pow2(x: &[Digit], buf: &mut [u8], bits_per_digit: u32) -> Option<usize> {
+    let bits = (limbs::len(x) as u32) * BITS - x[limbs::len(x) - 1].leading_zeros();
+    let len = bits.div_ceil(bits_per_digit) as usize;
+    if buf.len() < len {
+        return None;
+    }
+
+    let mask = (1 << bits_per_digit) - 1;
+    for (i, c) in buf[..len].iter_mut().rev().enumerate() {
+        let bit = i as u32 * bits_per_digit;
+        let (j, offset) = ((bit / BITS) as usize, bit % BITS);
+
+        let mut d = x[j] >> offset;
+        if offset + bits_per_digit > BITS && j + 1 < x.len() {
+            d |= x[j + 1] << (BITS - offset);
+        }
+
+        *c = DIGIT_CHARS[(d & mask) as usize];
+    }
+
+    Some(len)
+}
+
+/// Number of digits of the given `radix` that fit into a single limb.
+#[inline]
+fn chunk_len(radix: u32) -> usize {
+    (Digit::MAX.ilog(radix as Digit)) as usize
+}
+
+/// Repeated division by the largest power of `radix` fitting into a limb,
+/// which consumes `x`. Digits are produced from the right at the end of `buf`
+/// and then moved to its beginning.
+fn write_chunked(x: &mut [Digit], buf: &mut [u8], radix: u32) -> Option<usize> {
+    let chunk = chunk_len(radix);
+    let base = (radix as Digit).pow(chunk as u32) as DoubleDigit;
+
+    let mut end = buf.len();
+    loop {
+        let mut rem = 0;
+        let len = limbs::len(x);
+        for d in x[..len].iter_mut().rev() {
+            let t = ((rem as DoubleDigit) << BITS) | *d as DoubleDigit;
+            *d = (t / base) as Digit;
+            rem = (t % base) as Digit;
+        }
+
+        let last = limbs::len(x) == 0;
+        for _ in 0..chunk {
+            if last && rem == 0 {
+                break;
+            }
+            if end == 0 {
+                return None;
+            }
+            end -= 1;
+            buf[end] = DIGIT_CHARS[(rem % radix as Digit) as usize];
+            rem /= radix as Digit;
+        }
+
+        if last {
+            break;
+        }
+    }
+
+    let len = buf.len() - end;
+    buf.copy_within(end.., 0);
+    Some(len)
+}
+
+/// Parses decimal digit values (not characters) into `out`, which must hold
+/// at least `digits.len() / 19 + 2` limbs.
+fn parse_dec(digits: &[u8], out: &mut [Digit], scratch: &mut [Digit]) {
+    if digits.len() <= PARSE_BASECASE_DIGITS {
+        parse_chunked(digits, 10, out);
+        return;
+    }
+
+    let level = split_level(digits.len());
+    let (high, low) = digits.split_at(digits.len() - level.digits);
+
+    let (hi, scratch) = scratch.split_at_mut(high.len() / POWER as usize + 2);
+    parse_dec(high, hi, scratch);
+
+    let (lo, scratch) = scratch.split_at_mut(low.len() / POWER as usize + 2);
+    parse_dec(low, lo, scratch);
+
+    limbs::mul(out, hi, level.pow, scratch);
+    limbs::add_assign(out, lo);
+}
+
+/// Schoolbook accumulation of digit values in chunks fitting into a limb.
+fn parse_chunked(digits: &[u8], radix: u32, out: &mut [Digit]) {
+    out.fill(0);
+
+    let chunk = chunk_len(radix);
+    let first = match digits.len() % chunk {
+        0 => chunk.min(digits.len()),
+        r => r,
+    };
+
+    let (head, tail) = digits.split_at(first);
+    let mut len = 0;
+    for part in core::iter::once(head).chain(tail.chunks(chunk)) {
+        let mut v: Digit = 0;
+        for &d in part {
+            v = v * radix as Digit + d as Digit;
+        }
+
+        let m = (radix as Digit).pow(part.len() as u32);
+        let carry = limbs::mul_digit_add_assign(&mut out[..len], m, v);
+        if carry != 0 {
+            out[len] = carry;
+            len += 1;
+        }
+    }
+}
+
+fn parse_pow2<const N: usize>(digits: &[u8], bits_per_digit: u32) -> Result<U<N>, ParseError> {
+    if digits.len() as u64 * bits_per_digit as u64 > (N as u64) * BITS as u64 + BITS as u64 {
+        return Err(ParseError::PosOverflow);
+    }
+
+    let mut res = [0; N];
+    for (i, &d) in digits.iter().rev().enumerate() {
+        let bit = i as u32 * bits_per_digit;
+        let (j, offset) = ((bit / BITS) as usize, bit % BITS);
+        let d = d as Digit;
+
+        if j < N {
+            res[j] |= d << offset;
+        } else if d != 0 {
+            return Err(ParseError::PosOverflow);
+        }
+
+        if offset + bits_per_digit > BITS {
+            let spill = d >> (BITS - offset);
+            if j + 1 < N {
+                res[j + 1] |= spill;
+            } else if spill != 0 {
+                return Err(ParseError::PosOverflow);
+            }
+        }
+    }
+
+    Ok(U::from_digits(res))
+}
@@ -0,0 +1,73 @@
+//! Cached powers _10<sup>19·2<sup>k</sup></sup>_ used to split integers in
+//! the divide-and-conquer radix `10` conversion.
+
+use crate::int::{
+    intrinsics::{Digit, POWER},
+    resize, UInt,
+};
+
+/// A cached power of ten together with its Barrett reciprocal.
+pub(super) struct Level {
+    /// Number of decimal digits `d`, the power is _10<sup>d</sup>_.
+    pub digits: usize,
+    /// _10<sup>d</sup>_, the most significant limb is non-zero.
+    pub pow: &'static [Digit],
+    /// _⌊β<sup>2s</sup> / 10<sup>d</sup>⌋_ where `s = pow.len()`.
+    pub mu: &'static [Digit],
+}
+
+/// Largest power of ten that fits into a single limb.
+const BASE: Digit = 10_000_000_000_000_000_000;
+
+const fn pow<const S: usize>(k: u32) -> [Digit; S] {
+    let mut p = UInt::<S>::from_digit(BASE);
+    let mut i = 0;
+    while i < k {
+        p = p.strict_mul(p);
+        i += 1;
+    }
+    *p.digits()
+}
+
+const fn mu<const S: usize, const W: usize, const M: usize>(pow: [Digit; S]) -> [Digit; M] {
+    let mut num = [0; W];
+    num[2 * S] = 1;
+
+    let den = resize::widen::<S, W>(UInt::from_digits(pow));
+    let q = UInt::<W>::from_digits(num).div(den);
+
+    *resize::try_narrow::<W, M>(q)
+        .expect("reciprocal is wider than M digits")
+        .digits()
+}
+
+macro_rules! levels {
+    ($($k: literal: $pow: ident[$s: literal], $mu: ident[$w: literal, $m: literal];)*) => {
+        $(
+            const $pow: [Digit; $s] = pow::<$s>($k);
+            const $mu: [Digit; $m] = mu::<$s, $w, $m>($pow);
+        )*
+
+        /// _10<sup>19·2<sup>k</sup></sup>_ for `k` in `0..LEVELS.len()`.
+        pub(super) static LEVELS: [Level; [$($k),*].len()] = [
+            $(
+                Level {
+                    digits: (POWER as usize) << $k,
+                    pow: &$pow,
+                    mu: &$mu,
+                },
+            )*
+        ];
+    };
+}
+
+levels!(
+    0: P0[1],   M0[3, 2];
+    1: P1[2],   M1[5, 3];
+    2: P2[4],   M2[9, 5];
+    3: P3[8],   M3[17, 9];
+    4: P4[16],  M4[33, 17];
+    5: P5[32],  M5[65, 33];
+    6: P6[64],  M6[129, 65];
+    7: P7[127], M7[255, 128];
+);
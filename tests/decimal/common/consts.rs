@@ -13,9 +13,40 @@ macro_rules! test_impl {
             super::test_impl!(@ $bits, $dec, $D);
         }
     };
+    (@ 4096, $dec: ident, $D: ident) => {
+        // The last digits of the constants, well past the 1000th.
+        #[rstest(::trace)]
+        #[case($D::PI, "4882401285836160356370766010471018194296")]
+        #[case($D::E, "9709640975459185695638023637016211204774")]
+        #[case($D::LN_2, "9686022194122924207574321757489097706753")]
+        #[case($D::LN_10, "6583698371632898217440736600916217785054")]
+        #[case($D::SQRT_2, "3006558695686859645951555016447245098369")]
+        #[case($D::LOG2_E, "3129695250045937262738902680566935512873")]
+        #[case($D::FRAC_2_SQRT_PI, "1750820631694663415243390628978099463480")]
+        #[case($D::EULER_GAMMA, "7188714958752549236649352047324364109727")]
+        #[case($D::CATALAN, "6393272936775866739258395406180333878307")]
+        #[case($D::PHI, "3126453220416397232134044449487302315418")]
+        #[case($D::SQRT_3, "7029750479872807946165810041705268194002")]
+        #[case($D::SQRT_5, "6252906440832794464268088898974604630835")]
+        #[case($D::LN_3, "6818724240687908909929874208152183237529")]
+        #[case($D::APERY, "9374168266546657729261117182460363056605")]
+        #[case($D::FRAC_1_SQRT_PI, "8754103158473317076216953144890497317398")]
+        fn test_math_consts(#[case] d: $D, #[case] tail: &str) {
+            assert_eq!(d.digits_count(), 1233);
+            assert!(d.to_string().ends_with(tail));
+        }
+    };
     (@ 512, $dec: ident, $D: ident) => {
         #[rstest(::trace)]
         #[case($D::PI, $dec!(3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117067982148086513282306647093844609550582231725359408128481))]
+        #[case($D::EULER_GAMMA, $dec!(0.5772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495146314472498070824809605040144865428362241739976449235))]
+        #[case($D::CATALAN, $dec!(0.9159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062485744226191961995790358988033258590594315947374811584))]
+        #[case($D::PHI, $dec!(1.618033988749894848204586834365638117720309179805762862135448622705260462818902449707207204189391137484754088075386891752126633862223536931793180060766726))]
+        #[case($D::SQRT_3, $dec!(1.732050807568877293527446341505872366942805253810380628055806979451933016908800037081146186757248575675626141415406703029969945094998952478811655512094374))]
+        #[case($D::SQRT_5, $dec!(2.236067977499789696409173668731276235440618359611525724270897245410520925637804899414414408378782274969508176150773783504253267724447073863586360121533453))]
+        #[case($D::LN_3, $dec!(1.0986122886681096913952452369225257046474905578227494517346943336374942932186089668736157548137320887879700290659578657423680042259305198210528018707672774))]
+        #[case($D::APERY, $dec!(1.2020569031595942853997381615114499907649862923404988817922715553418382057863130901864558736093352581461991577952607194184919959986732832137763968372079002))]
+        #[case($D::FRAC_1_SQRT_PI, $dec!(0.5641895835477562869480794515607725858440506293289988568440857217106424684414934144867436602021073634430283479063617073516899314948261628663654895200177690))]
         fn test_math_consts(#[case] d: $D, #[case] expected: $D) {
             assert_eq!(d, expected);
         }
@@ -29,6 +60,14 @@ macro_rules! test_impl {
     (@ 256, $dec: ident, $D: ident) => {
         #[rstest(::trace)]
         #[case($D::PI, $dec!(3.1415926535897932384626433832795028841971693993751058209749445923078164062862))]
+        #[case($D::EULER_GAMMA, $dec!(0.57721566490153286060651209008240243104215933593992359880576723488486772677766))]
+        #[case($D::CATALAN, $dec!(0.91596559417721901505460351493238411077414937428167213426649811962176301977625))]
+        #[case($D::PHI, $dec!(1.6180339887498948482045868343656381177203091798057628621354486227052604628189))]
+        #[case($D::SQRT_3, $dec!(1.7320508075688772935274463415058723669428052538103806280558069794519330169088))]
+        #[case($D::SQRT_5, $dec!(2.2360679774997896964091736687312762354406183596115257242708972454105209256378))]
+        #[case($D::LN_3, $dec!(1.09861228866810969139524523692252570464749055782274945173469433363749429321861))]
+        #[case($D::APERY, $dec!(1.2020569031595942853997381615114499907649862923404988817922715553418382057863))]
+        #[case($D::FRAC_1_SQRT_PI, $dec!(0.56418958354775628694807945156077258584405062932899885684408572171064246844149))]
         fn test_math_consts(#[case] d: $D, #[case] expected: $D) {
             assert_eq!(d, expected);
         }
@@ -43,6 +82,14 @@ macro_rules! test_impl {
     (@ 128, $dec: ident, $D: ident) => {
         #[rstest(::trace)]
         #[case($D::PI, $dec!(3.1415926535897932384626433832795028842))]
+        #[case($D::EULER_GAMMA, $dec!(0.57721566490153286060651209008240243104))]
+        #[case($D::CATALAN, $dec!(0.91596559417721901505460351493238411077))]
+        #[case($D::PHI, $dec!(1.61803398874989484820458683436563811772))]
+        #[case($D::SQRT_3, $dec!(1.73205080756887729352744634150587236694))]
+        #[case($D::SQRT_5, $dec!(2.23606797749978969640917366873127623544))]
+        #[case($D::LN_3, $dec!(1.09861228866810969139524523692252570465))]
+        #[case($D::APERY, $dec!(1.20205690315959428539973816151144999076))]
+        #[case($D::FRAC_1_SQRT_PI, $dec!(0.56418958354775628694807945156077258584))]
         fn test_math_consts(#[case] d: $D, #[case] expected: $D) {
             assert_eq!(d, expected);
         }
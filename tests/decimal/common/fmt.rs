@@ -57,7 +57,7 @@ macro_rules! test_impl {
         super::test_impl!(COMMON:: 128, $dec, $D);
 
         #[rstest(::trace)]
-        #[case($D::INFINITY, concat!(stringify!($D), r#"(digits=[340282366920938463463374607431768211455], exp=[32768], flags=[INF], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
+        #[case($D::INFINITY, concat!(stringify!($D), r#"(digits=[340282366920938463463374607431768211455], exp=[32768], flags=[INF], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
         fn test_fmt_debug_128(#[case] d: $D, #[case] expected: &str) {
             let formated = format!("{d:?}");
             assert_eq!(formated.as_str(), expected);
@@ -84,18 +84,18 @@ macro_rules! test_impl {
         }
 
         #[rstest(::trace)]
-        #[case($D::NAN, concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[NAN], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(0), concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(0.00), concat!(stringify!($D), r#"(digits=[0], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(1), concat!(stringify!($D), r#"(digits=[1], exp=[0], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(123.400), concat!(stringify!($D), r#"(digits=[123400], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(123.4e-2), concat!(stringify!($D), r#"(digits=[1234], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(123.456), concat!(stringify!($D), r#"(digits=[123456], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(01.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(1.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(01.2E3), concat!(stringify!($D), r#"(digits=[12], exp=[2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(6.02214076e1023), concat!(stringify!($D), r#"(digits=[602214076], exp=[1015], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(1e9999), concat!(stringify!($D), r#"(digits=[1], exp=[9999], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
+        #[case($D::NAN, concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[NAN], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(0), concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(0.00), concat!(stringify!($D), r#"(digits=[0], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(1), concat!(stringify!($D), r#"(digits=[1], exp=[0], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(123.400), concat!(stringify!($D), r#"(digits=[123400], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(123.4e-2), concat!(stringify!($D), r#"(digits=[1234], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(123.456), concat!(stringify!($D), r#"(digits=[123456], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(01.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(1.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(01.2E3), concat!(stringify!($D), r#"(digits=[12], exp=[2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(6.02214076e1023), concat!(stringify!($D), r#"(digits=[602214076], exp=[1015], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(1e9999), concat!(stringify!($D), r#"(digits=[1], exp=[9999], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
         fn test_fmt_debug(#[case] d: $D, #[case] expected: &str) {
             let formated = format!("{d:?}");
             assert_eq!(formated.as_str(), expected);
@@ -547,6 +547,410 @@ macro_rules! test_impl {
         fn test_fmt_engineering_notation(#[case] d: $D, #[case] expected: &str) {
             assert_eq!(d.to_engineering_notation(), expected);
         }
+
+        #[rstest(::trace)]
+        #[case($D::NAN, Notation::Unspecified, "NaN")]
+        #[case($D::INFINITY, Notation::Plain, "Inf")]
+        #[case($dec!(0), Notation::Unspecified, "0")]
+        #[case($dec!(0.00), Notation::Scientific, "0e0")]
+        #[case($dec!(19073.97235939614856), Notation::Unspecified, "19073.97235939614856")]
+        #[case($dec!(491326e-12), Notation::Unspecified, "4.91326E-7")]
+        #[case($dec!(491326e-12), Notation::FullScale, "0.000000491326")]
+        #[case($dec!(491326e-12), Notation::Scientific, "4.91326e-7")]
+        #[case($dec!(1234506789e16), Notation::Unspecified, "1234506789e+16")]
+        #[case($dec!(1234506789e16), Notation::FullScale, "12345067890000000000000000")]
+        #[case($dec!(1e1001), Notation::FullScale, "1e+1001")]
+        #[case($dec!(1e25), Notation::Plain, "10000000000000000000000000")]
+        fn test_format_into(#[case] d: $D, #[case] notation: Notation, #[case] expected: &str) {
+            let mut style = FormatStyle::default();
+            style.notation = notation;
+
+            let mut buf = [0; $D::MAX_STR_LEN];
+            assert_eq!(d.format_into(&mut buf, style), Ok(expected));
+
+            let len = expected.len();
+            assert_eq!(d.format_into(&mut buf[..len], style), Ok(expected));
+            assert_eq!(d.format_into(&mut buf[..len - 1], style), Err(core::fmt::Error));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0.00003102564500))]
+        #[case($dec!(1e16))]
+        #[case($dec!(10950633712399.557))]
+        fn test_format_into_display(#[case] d: $D) {
+            let mut buf = [0; 64];
+            assert_eq!(d.format_into(&mut buf, FormatStyle::default()), Ok(d.to_string().as_str()));
+        }
+
+        #[rstest(::trace)]
+        fn test_format_into_max() {
+            let mut style = FormatStyle::default();
+            style.notation = Notation::Plain;
+
+            let mut buf = [0; $D::MAX_STR_LEN];
+            let s = $D::MAX.format_into(&mut buf, style).unwrap();
+
+            assert_eq!(s.len(), $D::MAX_STR_LEN - 1);
+            assert!(s.bytes().all(|c| c.is_ascii_digit()));
+        }
+
+        #[rstest(::trace)]
+        fn test_fmt_special_padding() {
+            assert_eq!(format!("{:06}", $D::NAN), "000NaN");
+            assert_eq!(format!("{:+}", $D::NAN), "NaN");
+            assert_eq!(format!("{:*^9}", $D::NAN), "***NaN***");
+            assert_eq!(format!("{:<5.2e}", $D::NAN), "NaN  ");
+            assert_eq!(format!("{:+}", $D::INFINITY), "+Inf");
+            assert_eq!(format!("{:>6}", $D::INFINITY), "   Inf");
+            assert_eq!(format!("{:+07.3E}", $D::INFINITY), "+000Inf");
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(123.456),    "[1.23456e2] [1e2] [1.235e2] [    1.23e2] [1.2e2     ] [+0001.23e2]")]
+        #[case($dec!(0),          "[0e0] [0e0] [0.000e0] [    0.00e0] [0.0e0     ] [+0000.00e0]")]
+        #[case($dec!(9.99),       "[9.99e0] [1e1] [9.990e0] [    9.99e0] [1.0e1     ] [+0009.99e0]")]
+        #[case($dec!(0.00001234), "[1.234e-5] [1e-5] [1.234e-5] [   1.23e-5] [1.2e-5    ] [+001.23e-5]")]
+        fn test_fmt_scientific_options(#[case] d: $D, #[case] expected: &str) {
+            let mut ctx = Context::default();
+            ctx.set_notation(Notation::Scientific);
+            let d = d.with_ctx(ctx);
+
+            assert_eq!(format!("[{}] [{:.0}] [{:.3}] [{:10.2}] [{:<10.1}] [{:+010.2}]", d, d, d, d, d, d), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::Up,       "[1.3] [2] [1.3e+0] [0.2]")]
+        #[case(RoundingMode::Down,     "[1.2] [1] [1.2e+0] [0.1]")]
+        #[case(RoundingMode::Ceiling,  "[1.3] [2] [1.3e+0] [0.2]")]
+        #[case(RoundingMode::Floor,    "[1.2] [1] [1.2e+0] [0.1]")]
+        #[case(RoundingMode::HalfUp,   "[1.3] [1] [1.3e+0] [0.2]")]
+        #[case(RoundingMode::HalfDown, "[1.2] [1] [1.2e+0] [0.1]")]
+        #[case(RoundingMode::HalfEven, "[1.2] [1] [1.2e+0] [0.2]")]
+        fn test_fmt_rounding_mode(#[case] rm: RoundingMode, #[case] expected: &str) {
+            let d = $dec!(1.25).with_rounding_mode(rm);
+            let x = $dec!(0.15).with_rounding_mode(rm);
+
+            assert_eq!(format!("[{:.1}] [{:.0}] [{:.1e}] [{:.1}]", d, d, d, x), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1234567.891), "1,234,567.891", "1.234.567,89",  "1 234 567,9",  "12,34,567.89",   "123,4567.89")]
+        #[case($dec!(0),           "0",             "0,00",          "0,0",          "0.00",           "0.00")]
+        #[case($dec!(1e7),         "10,000,000",    "10.000.000,00", "10 000 000,0", "1,00,00,000.00", "1000,0000.00")]
+        #[case($dec!(999.999),     "999.999",       "1.000,00",      "1 000,0",      "1,000.00",       "1000.00")]
+        #[case($dec!(0.001),       "0.001",         "0,00",          "0,0",          "0.00",           "0.00")]
+        #[case($dec!(12.50),       "12.50",         "12,50",         "12,5",         "12.50",          "12.50")]
+        fn test_format_with(
+            #[case] d: $D,
+            #[case] expected_en: &str,
+            #[case] expected_de: &str,
+            #[case] expected_fr: &str,
+            #[case] expected_in: &str,
+            #[case] expected_cn: &str,
+        ) {
+            let en = NumberFormat::new().with_grouping(',');
+            let de = NumberFormat::new().with_grouping('.').with_decimal_separator(',').with_fraction_digits(2);
+            let fr = NumberFormat::new().with_grouping(' ').with_decimal_separator(',').with_fraction_digits(1);
+            let lakh = en.with_group_sizes(3, 2).with_fraction_digits(2);
+            let cn = en.with_group_sizes(4, 4).with_fraction_digits(2);
+
+            assert_eq!(d.format_with(&en).to_string(), expected_en);
+            assert_eq!(d.format_with(&de).to_string(), expected_de);
+            assert_eq!(d.format_with(&fr).to_string(), expected_fr);
+            assert_eq!(d.format_with(&lakh).to_string(), expected_in);
+            assert_eq!(d.format_with(&cn).to_string(), expected_cn);
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::Up,       "2.35")]
+        #[case(RoundingMode::Down,     "2.34")]
+        #[case(RoundingMode::Ceiling,  "2.35")]
+        #[case(RoundingMode::Floor,    "2.34")]
+        #[case(RoundingMode::HalfUp,   "2.35")]
+        #[case(RoundingMode::HalfDown, "2.34")]
+        #[case(RoundingMode::HalfEven, "2.34")]
+        fn test_format_with_rounding_mode(#[case] rm: RoundingMode, #[case] expected: &str) {
+            let nf = NumberFormat::new().with_max_fraction_digits(2);
+            assert_eq!($dec!(2.345).with_rounding_mode(rm).format_with(&nf).to_string(), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_format_with_fraction_digits() {
+            let nf = NumberFormat::new().with_min_fraction_digits(2).with_max_fraction_digits(4);
+
+            assert_eq!($dec!(1).format_with(&nf).to_string(), "1.00");
+            assert_eq!($dec!(1.5).format_with(&nf).to_string(), "1.50");
+            assert_eq!($dec!(1.500).format_with(&nf).to_string(), "1.500");
+            assert_eq!($dec!(1.23456).format_with(&nf).to_string(), "1.2346");
+            assert_eq!($dec!(1.2e-10).format_with(&nf).to_string(), "0.0000");
+            assert_eq!($dec!(1.2e-10).format_with(&nf.with_max_fraction_digits(1)).to_string(), "0.0");
+            assert_eq!($dec!(9.99999).format_with(&nf.with_max_fraction_digits(0)).to_string(), "10");
+        }
+
+        #[rstest(::trace)]
+        fn test_format_with_padding() {
+            let d = $dec!(1234567).format_with(&NumberFormat::new().with_grouping('\u{202F}'));
+
+            assert_eq!(format!("[{}]", d), "[1\u{202F}234\u{202F}567]");
+            assert_eq!(format!("[{:>11}]", d), "[  1\u{202F}234\u{202F}567]");
+            assert_eq!(format!("[{:<11}]", d), "[1\u{202F}234\u{202F}567  ]");
+            assert_eq!(format!("[{:*^12}]", d), "[*1\u{202F}234\u{202F}567**]");
+            assert_eq!(format!("[{:5}]", d), "[1\u{202F}234\u{202F}567]");
+        }
+
+        #[rstest(::trace)]
+        fn test_format_with_special() {
+            let nf = NumberFormat::new().with_grouping(',').with_sign_placement(SignPlacement::LeadingAlways);
+
+            assert_eq!($D::NAN.format_with(&nf).to_string(), "NaN");
+            assert_eq!($D::INFINITY.format_with(&nf).to_string(), "+Inf");
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), 16, "0")]
+        #[case($dec!(0.000), 16, "0")]
+        #[case($dec!(1), 2, "1")]
+        #[case($dec!(255.75), 16, "ff.c")]
+        #[case($dec!(255.75), 2, "11111111.11")]
+        #[case($dec!(255.75), 8, "377.6")]
+        #[case($dec!(1.50), 16, "1.8")]
+        #[case($dec!(0.015625), 2, "0.000001")]
+        #[case($dec!(0.015625), 16, "0.04")]
+        #[case($dec!(1e3), 8, "1750")]
+        #[case($dec!(1e3), 36, "rs")]
+        #[case($dec!(12345678901234567890), 16, "ab54a98ceb1f0ad2")]
+        #[case($dec!(123456789e40), 16, "d83ff0570104b6045b210ce03acdf50000000000")]
+        #[case($dec!(0.1), 16, "0.1…")]
+        #[case($dec!(0.1), 2, "0.0001…")]
+        #[case($dec!(0.10), 16, "0.19…")]
+        #[case($dec!(0.5), 3, "0.111…")]
+        #[case($dec!(1e-20), 16, "0.00000000000000002…")]
+        #[case($dec!(1.25), 10, "1.25")]
+        #[case($dec!(1.25e-10), 10, "0.000000000125")]
+        #[case($D::NAN, 16, "NaN")]
+        #[case($D::INFINITY, 16, "Inf")]
+        fn test_to_str_radix(#[case] d: $D, #[case] radix: u32, #[case] expected: &str) {
+            assert_eq!(d.to_str_radix(radix), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0.1), 16)]
+        #[case($dec!(0.2), 2)]
+        #[case($dec!(0.333), 3)]
+        #[case($dec!(1.7), 36)]
+        fn test_to_str_radix_truncated(#[case] d: $D, #[case] radix: u32) {
+            let s = d.to_str_radix(radix);
+            assert!(s.ends_with('…'));
+            assert_eq!($D::from_str_radix(&s, radix, Context::default()), Err(ParseError::InvalidLiteral));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(255.75), 16)]
+        #[case($dec!(0.015625), 2)]
+        #[case($dec!(1e3), 8)]
+        #[case($dec!(12345678901234567890.0625), 4)]
+        fn test_to_str_radix_round_trip(#[case] d: $D, #[case] radix: u32) {
+            let s = d.to_str_radix(radix);
+            assert_eq!($D::from_str_radix(&s, radix, Context::default()), Ok(d));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), 0, "0")]
+        #[case($dec!(0), 2, "0.00")]
+        #[case($dec!(0.000), 1, "0.0")]
+        #[case($dec!(5), 2, "5.00")]
+        #[case($dec!(5), 0, "5")]
+        #[case($dec!(1.005), 2, "1.01")]
+        #[case($dec!(1.004), 2, "1.00")]
+        #[case($dec!(0.995), 2, "1.00")]
+        #[case($dec!(9.9999), 3, "10.000")]
+        #[case($dec!(2.5), 0, "3")]
+        #[case($dec!(0.4), 0, "0")]
+        #[case($dec!(123.456), 1, "123.5")]
+        #[case($dec!(1.50), 4, "1.5000")]
+        #[case($dec!(1e-10), 12, "0.000000000100")]
+        #[case($dec!(1e-10), 2, "0.00")]
+        #[case($dec!(1.2345e-7), 10, "0.0000001235")]
+        #[case($dec!(1.5e20), 1, "150000000000000000000.0")]
+        #[case($dec!(1e25), 2, "10000000000000000000000000.00")]
+        #[case($D::NAN, 2, "NaN")]
+        #[case($D::INFINITY, 2, "Inf")]
+        fn test_to_fixed(#[case] d: $D, #[case] frac_digits: u16, #[case] expected: &str) {
+            assert_eq!(d.to_fixed(frac_digits), expected);
+
+            let mut s = String::new();
+            d.write_fixed(frac_digits, &mut s).unwrap();
+            assert_eq!(s, expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::HalfUp, "1.01")]
+        #[case(RoundingMode::HalfDown, "1.00")]
+        #[case(RoundingMode::HalfEven, "1.00")]
+        #[case(RoundingMode::Up, "1.01")]
+        #[case(RoundingMode::Down, "1.00")]
+        fn test_to_fixed_rounding(#[case] rm: RoundingMode, #[case] expected: &str) {
+            assert_eq!($dec!(1.005).with_rounding_mode(rm).to_fixed(2), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_to_fixed_large_scale() {
+            let d = $dec!(1.5e-30);
+            assert_eq!(d.to_string(), "1.5E-30");
+            assert_eq!(d.to_fixed(31), "0.0000000000000000000000000000015");
+
+            let d = $dec!(1e100);
+            assert_eq!(d.to_fixed(1).len(), 103);
+            assert!(!d.to_fixed(1).contains('e'));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1.5e3), ExponentStyle::new(), "1.5e3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_uppercase(true), "1.5E3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_always_signed(true), "1.5e+3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_min_digits(2), "1.5e03")]
+        #[case($dec!(1.5e-3), ExponentStyle::new().with_min_digits(3), "1.5e-003")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_uppercase(true).with_always_signed(true).with_min_digits(2), "1.5E+03")]
+        #[case($dec!(1.5e123), ExponentStyle::new().with_min_digits(2), "1.5e123")]
+        #[case($dec!(0), ExponentStyle::new().with_always_signed(true).with_min_digits(2), "0e+00")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_si_prefix(true), "1.5e3")]
+        fn test_exponent_style_scientific(#[case] d: $D, #[case] style: ExponentStyle, #[case] expected: &str) {
+            let ctx = Context::default().with_exponent_style(style);
+            assert_eq!(d.with_ctx(ctx).to_scientific_notation(), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1.5e3), ExponentStyle::new(), "1.5e3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_uppercase(true).with_always_signed(true).with_min_digits(2), "1.5E+03")]
+        #[case($dec!(12.5e-9), ExponentStyle::new().with_min_digits(2), "12.5e-09")]
+        #[case($dec!(0), ExponentStyle::new().with_uppercase(true), "0E0")]
+        #[case($dec!(0), ExponentStyle::new().with_si_prefix(true), "0")]
+        #[case($dec!(1), ExponentStyle::new().with_si_prefix(true), "1")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_si_prefix(true), "1.5k")]
+        #[case($dec!(2.3e-6), ExponentStyle::new().with_si_prefix(true), "2.3µ")]
+        #[case($dec!(470e-9), ExponentStyle::new().with_si_prefix(true), "470n")]
+        #[case($dec!(1e-30), ExponentStyle::new().with_si_prefix(true), "1q")]
+        #[case($dec!(1e-27), ExponentStyle::new().with_si_prefix(true), "1r")]
+        #[case($dec!(1e-3), ExponentStyle::new().with_si_prefix(true), "1m")]
+        #[case($dec!(1e6), ExponentStyle::new().with_si_prefix(true), "1M")]
+        #[case($dec!(1e9), ExponentStyle::new().with_si_prefix(true), "1G")]
+        #[case($dec!(1e30), ExponentStyle::new().with_si_prefix(true), "1Q")]
+        #[case($dec!(1e33), ExponentStyle::new().with_si_prefix(true), "1e33")]
+        #[case($dec!(1e-33), ExponentStyle::new().with_si_prefix(true).with_uppercase(true), "1E-33")]
+        fn test_exponent_style_engineering(#[case] d: $D, #[case] style: ExponentStyle, #[case] expected: &str) {
+            let ctx = Context::default().with_exponent_style(style);
+            assert_eq!(d.with_ctx(ctx).to_engineering_notation(), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1.2345e-7), ExponentStyle::new(), "1.2345E-7")]
+        #[case($dec!(1.2345e-7), ExponentStyle::new().with_uppercase(false), "1.2345e-7")]
+        #[case($dec!(1.2345e-7), ExponentStyle::new().with_min_digits(2), "1.2345E-07")]
+        #[case($dec!(1e25), ExponentStyle::new(), "1e+25")]
+        #[case($dec!(1e25), ExponentStyle::new().with_uppercase(true).with_min_digits(3), "1E+025")]
+        #[case($dec!(123.45), ExponentStyle::new().with_uppercase(true), "123.45")]
+        fn test_exponent_style_display(#[case] d: $D, #[case] style: ExponentStyle, #[case] expected: &str) {
+            let ctx = Context::default().with_exponent_style(style);
+            assert_eq!(d.with_ctx(ctx).to_string(), expected);
+            assert_eq!(format!("{:>12}", d.with_ctx(ctx)), format!("{:>12}", expected));
+
+            let mut fs = FormatStyle::default();
+            fs.set_exponent_style(style);
+            let mut buf = [0; 64];
+            assert_eq!(d.format_into(&mut buf, fs), Ok(expected));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1.5e3), ExponentStyle::new(), "1.5e+3", "1.5E+3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_uppercase(true), "1.5e+3", "1.5E+3")]
+        #[case($dec!(1.5e3), ExponentStyle::new().with_min_digits(2), "1.5e+03", "1.5E+03")]
+        fn test_exponent_style_exp(#[case] d: $D, #[case] style: ExponentStyle, #[case] lower: &str, #[case] upper: &str) {
+            let d = d.with_ctx(Context::default().with_exponent_style(style));
+            assert_eq!(format!("{d:e}"), lower);
+            assert_eq!(format!("{d:E}"), upper);
+        }
+
+        #[rstest(::trace)]
+        #[should_panic(expected = "(fastnum) min_digits should be greater than zero!")]
+        fn test_exponent_style_min_digits_zero() {
+            let _ = ExponentStyle::new().with_min_digits(0);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), "zero")]
+        #[case($dec!(0.00), "zero point zero zero")]
+        #[case($dec!(7), "seven")]
+        #[case($dec!(13), "thirteen")]
+        #[case($dec!(20), "twenty")]
+        #[case($dec!(21), "twenty-one")]
+        #[case($dec!(99), "ninety-nine")]
+        #[case($dec!(100), "one hundred")]
+        #[case($dec!(101), "one hundred one")]
+        #[case($dec!(110), "one hundred ten")]
+        #[case($dec!(1000), "one thousand")]
+        #[case($dec!(1001), "one thousand one")]
+        #[case($dec!(1234), "one thousand two hundred thirty-four")]
+        #[case($dec!(100000), "one hundred thousand")]
+        #[case($dec!(1000001), "one million one")]
+        #[case($dec!(2000000000), "two billion")]
+        #[case($dec!(1e6), "one million")]
+        #[case($dec!(1.5e4), "fifteen thousand")]
+        #[case($dec!(999999999999), "nine hundred ninety-nine billion nine hundred ninety-nine million nine hundred ninety-nine thousand nine hundred ninety-nine")]
+        #[case($dec!(1e63), "one vigintillion")]
+        #[case($dec!(1e66), "one thousand vigintillion")]
+        #[case($dec!(123e70), "one billion two hundred thirty million vigintillion")]
+        #[case($dec!(1e129), "one thousand vigintillion vigintillion")]
+        #[case($dec!(1234.56), "one thousand two hundred thirty-four point five six")]
+        #[case($dec!(1.50), "one point five zero")]
+        #[case($dec!(0.05), "zero point zero five")]
+        #[case($dec!(12.5e-3), "zero point zero one two five")]
+        #[case($D::NAN, "not a number")]
+        #[case($D::INFINITY, "infinity")]
+        fn test_to_words(#[case] d: $D, #[case] expected: &str) {
+            assert_eq!(d.to_words(&WordsStyle::new()), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), 2, "zero and 00/100")]
+        #[case($dec!(17), 2, "seventeen and 00/100")]
+        #[case($dec!(1234.56), 2, "one thousand two hundred thirty-four and 56/100")]
+        #[case($dec!(1234.5), 2, "one thousand two hundred thirty-four and 50/100")]
+        #[case($dec!(1234.555), 2, "one thousand two hundred thirty-four and 56/100")]
+        #[case($dec!(0.05), 2, "zero and 05/100")]
+        #[case($dec!(0.999), 2, "one and 00/100")]
+        #[case($dec!(1.5), 0, "two")]
+        #[case($dec!(1.25), 1, "one and 3/10")]
+        #[case($dec!(1.0125), 3, "one and 013/1000")]
+        fn test_to_words_ratio(#[case] d: $D, #[case] digits: u16, #[case] expected: &str) {
+            let style = WordsStyle::new()
+                .with_fraction_style(FractionStyle::Ratio)
+                .with_fraction_digits(digits);
+            assert_eq!(d.to_words(&style), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_to_words_ratio_all_digits() {
+            let style = WordsStyle::new().with_fraction_style(FractionStyle::Ratio);
+            assert_eq!($dec!(3.125).to_words(&style), "three and 125/1000");
+            assert_eq!($dec!(0.010).to_words(&style), "zero and 010/1000");
+            assert_eq!($dec!(42).to_words(&style), "forty-two");
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::HalfUp, "one point zero one")]
+        #[case(RoundingMode::Down, "one point zero zero")]
+        fn test_to_words_rounding(#[case] rm: RoundingMode, #[case] expected: &str) {
+            let style = WordsStyle::new().with_fraction_digits(2);
+            assert_eq!($dec!(1.005).with_rounding_mode(rm).to_words(&style), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(1)]
+        #[case(37)]
+        #[should_panic(expected = "(fastnum) radix must be in the range 2..=36")]
+        fn test_to_str_radix_invalid(#[case] radix: u32) {
+            let _ = $dec!(1).to_str_radix(radix);
+        }
     };
     (UNSIGNED:: 128, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(UNSIGNED:: 128, $dec, $D);
@@ -557,13 +961,65 @@ macro_rules! test_impl {
         super::test_impl!(SIGNED:: 128, $dec, $D);
 
         #[rstest(::trace)]
-        #[case($D::NEG_INFINITY, concat!(stringify!($D), r#"(digits=[340282366920938463463374607431768211455], exp=[32768], flags=[S, INF], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
+        #[case($D::NEG_INFINITY, concat!(stringify!($D), r#"(digits=[340282366920938463463374607431768211455], exp=[32768], flags=[S, INF], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
         fn test_fmt_debug_signed_128(#[case] d: $D, #[case] expected: &str) {
             let formated = format!("{d:?}");
             assert_eq!(formated.as_str(), expected);
         }
     };
     (SIGNED:: 128, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        #[case($dec!(-5), 2, "-5.00")]
+        #[case($dec!(-1.005), 2, "-1.01")]
+        #[case($dec!(-0.001), 2, "0.00")]
+        #[case($dec!(-2.5e-10), 12, "-0.000000000250")]
+        #[case($D::NEG_INFINITY, 2, "-Inf")]
+        fn test_to_fixed_signed(#[case] d: $D, #[case] frac_digits: u16, #[case] expected: &str) {
+            assert_eq!(d.to_fixed(frac_digits), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::Floor, "-1.01")]
+        #[case(RoundingMode::Ceiling, "-1.00")]
+        fn test_to_fixed_rounding_signed(#[case] rm: RoundingMode, #[case] expected: &str) {
+            assert_eq!($dec!(-1.001).with_rounding_mode(rm).to_fixed(2), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_exponent_style_signed() {
+            let ctx = Context::default().with_exponent_style(ExponentStyle::new().with_si_prefix(true));
+            assert_eq!($dec!(-4.7e-9).with_ctx(ctx).to_engineering_notation(), "-4.7n");
+
+            let ctx = Context::default().with_exponent_style(ExponentStyle::new().with_always_signed(true).with_min_digits(2));
+            assert_eq!($dec!(-1.5e3).with_ctx(ctx).to_scientific_notation(), "-1.5e+03");
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(-1), "minus one")]
+        #[case($dec!(-0), "minus zero")]
+        #[case($dec!(-0.05), "minus zero point zero five")]
+        #[case($D::NEG_INFINITY, "minus infinity")]
+        fn test_to_words_signed(#[case] d: $D, #[case] expected: &str) {
+            assert_eq!(d.to_words(&WordsStyle::new()), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_to_words_ratio_signed() {
+            let style = WordsStyle::new()
+                .with_fraction_style(FractionStyle::Ratio)
+                .with_fraction_digits(2);
+            assert_eq!($dec!(-1234.56).to_words(&style), "minus one thousand two hundred thirty-four and 56/100");
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(-255.75), 16, "-ff.c")]
+        #[case($dec!(-0), 16, "-0")]
+        #[case($dec!(-0.1), 2, "-0.0001…")]
+        #[case($D::NEG_INFINITY, 16, "-Inf")]
+        fn test_to_str_radix_signed(#[case] d: $D, #[case] radix: u32, #[case] expected: &str) {
+            assert_eq!(d.to_str_radix(radix), expected);
+        }
+
         #[rstest(::trace)]
         #[case($D::NEG_INFINITY, "-Inf")]
         #[case($dec!(-0), "-0")]
@@ -576,19 +1032,19 @@ macro_rules! test_impl {
         }
 
         #[rstest(::trace)]
-        #[case($dec!(-0), concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-1), concat!(stringify!($D), r#"(digits=[1], exp=[0], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-123.400), concat!(stringify!($D), r#"(digits=[123400], exp=[-3], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(+123.4e-2), concat!(stringify!($D), r#"(digits=[1234], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-123.456), concat!(stringify!($D), r#"(digits=[123456], exp=[-3], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(+01.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-1.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-01.2E3), concat!(stringify!($D), r#"(digits=[12], exp=[2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-6.02214076e1023), concat!(stringify!($D), r#"(digits=[602214076], exp=[1015], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(+1e9999), concat!(stringify!($D), r#"(digits=[1], exp=[9999], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-144.3308279), concat!(stringify!($D), r#"(digits=[1443308279], exp=[-7], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-349983058835858339619e2), concat!(stringify!($D), r#"(digits=[349983058835858339619], exp=[2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
-        #[case($dec!(-90037659.6905), concat!(stringify!($D), r#"(digits=[900376596905], exp=[-4], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW], extra=[0.0000000])"#))]
+        #[case($dec!(-0), concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-1), concat!(stringify!($D), r#"(digits=[1], exp=[0], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-123.400), concat!(stringify!($D), r#"(digits=[123400], exp=[-3], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(+123.4e-2), concat!(stringify!($D), r#"(digits=[1234], exp=[-3], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-123.456), concat!(stringify!($D), r#"(digits=[123456], exp=[-3], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(+01.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-1.20), concat!(stringify!($D), r#"(digits=[120], exp=[-2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-01.2E3), concat!(stringify!($D), r#"(digits=[12], exp=[2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-6.02214076e1023), concat!(stringify!($D), r#"(digits=[602214076], exp=[1015], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(+1e9999), concat!(stringify!($D), r#"(digits=[1], exp=[9999], flags=[], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-144.3308279), concat!(stringify!($D), r#"(digits=[1443308279], exp=[-7], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-349983058835858339619e2), concat!(stringify!($D), r#"(digits=[349983058835858339619], exp=[2], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
+        #[case($dec!(-90037659.6905), concat!(stringify!($D), r#"(digits=[900376596905], exp=[-4], flags=[S], signals=[], ctx=[R=HalfUp, S=!DBZ, !INV, !OFW, FS=Unspecified Notation(LeadingZeros:5,TrailingZeros:15)], extra=[0.0000000])"#))]
         fn test_fmt_debug_signed(#[case] d: $D, #[case] expected: &str) {
             let formated = format!("{d:?}");
             assert_eq!(formated.as_str(), expected);
@@ -668,7 +1124,142 @@ macro_rules! test_impl {
         fn test_fmt_engineering_notation_signed(#[case] d: $D, #[case] expected: &str) {
             assert_eq!(d.to_engineering_notation(), expected);
         }
+
+        #[rstest(::trace)]
+        #[case($D::NEG_INFINITY, Notation::Unspecified, "-Inf")]
+        #[case($dec!(-0), Notation::Plain, "-0")]
+        #[case($dec!(-0.00001234), Notation::Scientific, "-1.234e-5")]
+        #[case($dec!(-0.0999), Notation::Plain, "-0.0999")]
+        #[case($dec!(-90037659.6905), Notation::Unspecified, "-90037659.6905")]
+        fn test_format_into_signed(#[case] d: $D, #[case] notation: Notation, #[case] expected: &str) {
+            let mut style = FormatStyle::default();
+            style.notation = notation;
+
+            let mut buf = [0; 32];
+            assert_eq!(d.format_into(&mut buf, style), Ok(expected));
+            assert_eq!(d.format_into(&mut buf[..expected.len() - 1], style), Err(core::fmt::Error));
+        }
+
+        #[rstest(::trace)]
+        fn test_format_into_min() {
+            let mut style = FormatStyle::default();
+            style.notation = Notation::Plain;
+
+            let mut buf = [0; $D::MAX_STR_LEN];
+            let s = $D::MIN.format_into(&mut buf, style).unwrap();
+
+            assert_eq!(s.len(), $D::MAX_STR_LEN);
+        }
+
+        #[rstest(::trace)]
+        fn test_fmt_special_padding_signed() {
+            assert_eq!(format!("{:06}", $D::NEG_INFINITY), "-00Inf");
+            assert_eq!(format!("{:^8}", $D::NEG_INFINITY), "  -Inf  ");
+            assert_eq!(format!("{:+e}", $D::NEG_INFINITY), "-Inf");
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::Up,      "[-1.3] [-2]")]
+        #[case(RoundingMode::Down,    "[-1.2] [-1]")]
+        #[case(RoundingMode::Ceiling, "[-1.2] [-1]")]
+        #[case(RoundingMode::Floor,   "[-1.3] [-2]")]
+        fn test_fmt_rounding_mode_signed(#[case] rm: RoundingMode, #[case] expected: &str) {
+            let d = $dec!(-1.25).with_rounding_mode(rm);
+            assert_eq!(format!("[{:.1}] [{:.0}]", d, d), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(SignPlacement::Leading,       "-1,234.50",  "1,234.50",  "-Inf")]
+        #[case(SignPlacement::LeadingAlways, "-1,234.50",  "+1,234.50", "-Inf")]
+        #[case(SignPlacement::Trailing,      "1,234.50-",  "1,234.50",  "Inf-")]
+        #[case(SignPlacement::Parentheses,   "(1,234.50)", "1,234.50",  "(Inf)")]
+        fn test_format_with_sign_placement(
+            #[case] sign_placement: SignPlacement,
+            #[case] expected_negative: &str,
+            #[case] expected_positive: &str,
+            #[case] expected_neg_infinity: &str,
+        ) {
+            let nf = NumberFormat::new()
+                .with_grouping(',')
+                .with_fraction_digits(2)
+                .with_sign_placement(sign_placement);
+
+            assert_eq!($dec!(-1234.5).format_with(&nf).to_string(), expected_negative);
+            assert_eq!($dec!(1234.5).format_with(&nf).to_string(), expected_positive);
+            assert_eq!($D::NEG_INFINITY.format_with(&nf).to_string(), expected_neg_infinity);
+        }
+
+        #[rstest(::trace)]
+        #[case(SignPlacement::Leading,       "-0,01")]
+        #[case(SignPlacement::LeadingAlways, "-0,01")]
+        #[case(SignPlacement::Trailing,      "0,01-")]
+        #[case(SignPlacement::Parentheses,   "(0,01)")]
+        fn test_format_with_rounded_to_zero(
+            #[case] sign_placement: SignPlacement,
+            #[case] expected_cent: &str,
+        ) {
+            let nf = NumberFormat::new()
+                .with_decimal_separator(',')
+                .with_fraction_digits(2)
+                .with_sign_placement(sign_placement);
+
+            let zero = match sign_placement {
+                SignPlacement::LeadingAlways => "+0,00",
+                _ => "0,00",
+            };
+
+            assert_eq!($dec!(-0.001).format_with(&nf).to_string(), zero);
+            assert_eq!($dec!(-0.004).format_with(&nf).to_string(), zero);
+            assert_eq!($dec!(-0).format_with(&nf).to_string(), zero);
+            assert_eq!($dec!(-0.005).format_with(&nf).to_string(), expected_cent);
+        }
+
+        #[rstest(::trace)]
+        #[case(RoundingMode::Ceiling, "-2.34")]
+        #[case(RoundingMode::Floor,   "-2.35")]
+        fn test_format_with_rounding_mode_signed(#[case] rm: RoundingMode, #[case] expected: &str) {
+            let nf = NumberFormat::new().with_max_fraction_digits(2);
+            assert_eq!($dec!(-2.345).with_rounding_mode(rm).format_with(&nf).to_string(), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case("0")]
+        #[case("-0")]
+        #[case("1")]
+        #[case("-1")]
+        #[case("1.5")]
+        #[case("-2.5")]
+        #[case("0.125")]
+        #[case("0.375")]
+        #[case("3.75")]
+        #[case("-0.001")]
+        #[case("1000")]
+        #[case("1234.5678")]
+        #[case("12345678.9")]
+        fn test_fmt_as_f64(#[case] s: &str) {
+            let d = $D::from_str(s, Context::default()).unwrap().with_rounding_mode(RoundingMode::HalfEven);
+            let f: f64 = s.parse().unwrap();
+
+            crate::decimal::common::fmt::assert_fmt_as_f64!(
+                d, f,
+                "{}", "{:.0}", "{:.1}", "{:.2}", "{:.5}",
+                "{:10}", "{:<10}", "{:^10}", "{:>10}", "{:*^12.3}",
+                "{:+}", "{:+.1}", "{:010.2}", "{:+010.2}", "{:08}",
+                "{:#}", "{:#.3}", "{:#<9.1}",
+            );
+        }
+    };
+}
+
+/// Asserts that the decimal is formatted exactly like the `f64` of the same
+/// value for each of the format specs.
+macro_rules! assert_fmt_as_f64 {
+    ($d: expr, $f: expr, $($spec: literal),+ $(,)?) => {
+        $(
+            assert_eq!(format!($spec, $d), format!($spec, $f), "format spec {}", $spec);
+        )+
     };
 }
 
+pub(crate) use assert_fmt_as_f64;
 pub(crate) use test_impl;
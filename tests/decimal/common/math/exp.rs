@@ -66,6 +66,9 @@ macro_rules! test_impl {
         #[case($dec!(10),  $dec!(22026.465794806716516957900645284244366353512618556781074235426355225202818571))]
         #[case($dec!(15),  $dec!(3269017.3724721106393018550460917213155057385438200342066295627732420213327489))]
         #[case($dec!(100), $dec!(2.6881171418161354484126255515800135873611118773741922415191608615280287034910e+43))]
+        #[case($dec!(150.3), $dec!(188131115266557060774687553433071525180499406624835726284601932049.19649740833))]
+        #[case($dec!(700.5), $dec!(16721859620674985572410360793021203111449422613713041352496415947763492920228e+228))]
+        #[case($dec!(20000.123), $dec!(87711449134136419561148170743036136866613347912410485228967801748574792804523e+8609))]
         fn test_exp_256(#[case] d: $D, #[case] expected: $D) {
             let res = d.exp();
             assert_eq!(res, expected);
@@ -85,8 +88,30 @@ macro_rules! test_impl {
     (SIGNED:: 256, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 256, $dec, $D);
 
+        #[rstest(::trace)]
+        #[case($dec!(1e-9), $dec!(1.00000000050000000016666666670833333334166666666805555555575396825399305555556E-9))]
+        #[case($dec!(-1e-9), $dec!(-9.9999999950000000016666666662500000000833333333194444444464285714283234126984E-10))]
+        #[case($dec!(1e-20), $dec!(1.00000000000000000000500000000000000000001666666666666666666670833333333333333E-20))]
+        #[case($dec!(-1e-30), $dec!(-9.9999999999999999999999999999950000000000000000000000000000016666666666666667E-31))]
+        #[case($dec!(0.001), $dec!(0.00100050016670834166805575399305831156307620058070146022851467446035974825144830))]
+        #[case($dec!(-0.001), $dec!(-0.00099950016662500833194464283234402529764409763991794094797148880391319741048764))]
+        #[case($dec!(0.25), $dec!(0.28402541668774148407342056806243645833628086528146308921750729687220776586724))]
+        #[case($dec!(-0.25), $dec!(-0.22119921692859513175482973302167935270322770957385852575868263373175438794648))]
+        #[case($dec!(0.49), $dec!(0.63231621995537897012241813133450149597899126758240177584654189581916780658375))]
+        #[case($dec!(-0.49), $dec!(-0.38737360581558393101142003198095307379379864125776901013711696222280147287678))]
+        #[case($dec!(3.7e-5), $dec!(0.000037000684508442244757286203205194403832578320787890363568341317424131651590916))]
+        #[case($dec!(0.00027397260273972603), $dec!(0.00027401013666092912019610536791468134731148927159209286365665067200498607908846))]
+        fn test_exp_m1_256(#[case] d: $D, #[case] expected: $D) {
+            let res = d.exp_m1();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
+
         #[rstest(::trace)]
         #[case($dec!(-1),   $dec!(0.36787944117144232159552377016146086744581113103176783450783680169746149574490))]
+        #[case($dec!(-2.75), $dec!(0.063927861206707572702430025557951749308634095078768448218190163693515595564781))]
+        #[case($dec!(-20000.123), $dec!(1.14010201618115780546520529903108365400559874176871057623905450926191624968872E-8686))]
         // #[case($dec!(-1.5), $dec!(4.4816890703380648226020554601192758187))]
         // #[case($dec!(-2),   $dec!(7.3890560989306502272304274605750078133))]
         // #[case($dec!(-2.5), $dec!(12.1824939607034734380701759511679661832))]
@@ -121,6 +146,9 @@ macro_rules! test_impl {
         #[case($dec!(10),  $dec!(22026.4657948067165169579006452842443664))]
         #[case($dec!(15),  $dec!(3269017.37247211063930185504609172131551))]
         #[case($dec!(100), $dec!(2.68811714181613544841262555158001358736e+43))]
+        #[case($dec!(150.3), $dec!(188131115266557060774687553433071525180e+27))]
+        #[case($dec!(700.5), $dec!(167218596206749855724103607930212031114e+266))]
+        #[case($dec!(20000.123), $dec!(87711449134136419561148170743036136867e+8648))]
         fn test_exp_128(#[case] d: $D, #[case] expected: $D) {
             let res = d.exp();
             assert_eq!(res, expected);
@@ -166,8 +194,30 @@ macro_rules! test_impl {
     (SIGNED:: 128, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 128, $dec, $D);
 
+        #[rstest(::trace)]
+        #[case($dec!(1e-9), $dec!(1.00000000050000000016666666670833333334E-9))]
+        #[case($dec!(-1e-9), $dec!(-9.9999999950000000016666666662500000001E-10))]
+        #[case($dec!(1e-20), $dec!(1.00000000000000000000500000000000000000E-20))]
+        #[case($dec!(-1e-30), $dec!(-9.999999999999999999999999999995E-31))]
+        #[case($dec!(0.001), $dec!(0.00100050016670834166805575399305831156308))]
+        #[case($dec!(-0.001), $dec!(-0.00099950016662500833194464283234402529764))]
+        #[case($dec!(0.25), $dec!(0.284025416687741484073420568062436458336))]
+        #[case($dec!(-0.25), $dec!(-0.221199216928595131754829733021679352703))]
+        #[case($dec!(0.49), $dec!(0.63231621995537897012241813133450149598))]
+        #[case($dec!(-0.49), $dec!(-0.38737360581558393101142003198095307379))]
+        #[case($dec!(3.7e-5), $dec!(0.000037000684508442244757286203205194403833))]
+        #[case($dec!(0.00027397260273972603), $dec!(0.000274010136660929120196105367914681347311))]
+        fn test_exp_m1_128(#[case] d: $D, #[case] expected: $D) {
+            let res = d.exp_m1();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
+
         #[rstest(::trace)]
         #[case($dec!(-1),   $dec!(0.36787944117144232159552377016146086745))]
+        #[case($dec!(-2.75), $dec!(0.063927861206707572702430025557951749309))]
+        #[case($dec!(-20000.123), $dec!(1.14010201618115780546520529903108365401E-8686))]
         // #[case($dec!(-1.5), $dec!(4.4816890703380648226020554601192758187))]
         // #[case($dec!(-2),   $dec!(7.3890560989306502272304274605750078133))]
         // #[case($dec!(-2.5), $dec!(12.1824939607034734380701759511679661832))]
@@ -185,6 +235,38 @@ macro_rules! test_impl {
         }
     };
     (SIGNED:: 128, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        #[case($dec!(0), $dec!(0))]
+        #[case($dec!(-0), $dec!(-0))]
+        #[case($D::INFINITY, $D::INFINITY)]
+        #[case($D::NEG_INFINITY, $dec!(-1))]
+        fn test_exp_m1_special(#[case] d: $D, #[case] expected: $D) {
+            let res = d.exp_m1();
+            assert_eq!(res, expected);
+            assert_eq!(res.op_signals(), signals![]);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1e5))]
+        #[case($dec!(1e20))]
+        fn test_exp_m1_overflow(#[case] d: $D) {
+            let ctx = Context::default().without_traps();
+            let res = d.with_ctx(ctx).exp_m1();
+            assert_eq!(res, $D::INFINITY);
+            assert!(res.is_op_overflow());
+            assert!(!res.is_op_invalid());
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(-1e5))]
+        #[case($dec!(-1e20))]
+        fn test_exp_m1_underflow(#[case] d: $D) {
+            let res = d.exp_m1();
+            assert_eq!(res, $dec!(-1));
+            assert!(res.is_op_inexact());
+            assert!(!res.is_op_invalid());
+        }
+
         #[rstest(::trace)]
         #[case($dec!(-0), $D::ONE)]
         #[case($D::NEG_INFINITY, $D::ZERO)]
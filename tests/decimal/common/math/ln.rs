@@ -48,6 +48,41 @@ macro_rules! test_impl {
     };
     (SIGNED:: 256, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 256, $dec, $D);
+
+        #[rstest(::trace)]
+        #[case($dec!(1e-9), $dec!(9.9999999950000000033333333308333333353333333316666666680952380939880952392063E-10))]
+        #[case($dec!(-1e-9), $dec!(-1.00000000050000000033333333358333333353333333350000000014285714298214285725397E-9))]
+        #[case($dec!(1e-20), $dec!(9.9999999999999999999500000000000000000003333333333333333333308333333333333333E-21))]
+        #[case($dec!(-1e-30), $dec!(-1.00000000000000000000000000000050000000000000000000000000000033333333333333333E-30))]
+        #[case($dec!(0.001), $dec!(0.00099950033308353316680939892053501146075506239316655199701966682890032495765872))]
+        #[case($dec!(-0.001), $dec!(-0.00100050033358353350014298225406834496075520525043440925098802079724520238586947))]
+        #[case($dec!(0.25), $dec!(0.22314355131420975576629509030983450337460108554800721367128787248739174376827))]
+        #[case($dec!(-0.25), $dec!(-0.28768207245178092743921900599382743150350971089776105650666568534929295072078))]
+        #[case($dec!(0.49), $dec!(0.39877611995736777295644536573539207265388179393542119572288429457182661493309))]
+        #[case($dec!(-0.49), $dec!(-0.67334455326376559639120305457307617496650940684905171303472373041535835033516))]
+        #[case($dec!(3.7e-5), $dec!(0.000036999315516883864806951697125826424354460353295681602399243341898221117003634))]
+        #[case($dec!(0.00027397260273972603), $dec!(0.00027393507909967647311964791409341930727041028298948722803240653194744905533943))]
+        fn test_ln_1p_256(#[case] d: $D, #[case] expected: $D) {
+            let res = d.ln_1p();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1e-300), $dec!(-690.77552789821370520539743640530926228033044658863189280999837029027178290321))]
+        #[case($dec!(0.00049), $dec!(-7.6211051668596018098792517865354615787313379839801425032478592049627033228826))]
+        #[case($dec!(0.001), $dec!(-6.9077552789821370520539743640530926228033044658863189280999837029027178290321))]
+        #[case($dec!(123456789.123), $dec!(18.631401767164318041763956576763670273401007920466551121528117885493954535464))]
+        #[case($dec!(1e300), $dec!(690.77552789821370520539743640530926228033044658863189280999837029027178290321))]
+        #[case($dec!(2.5e4000), $dec!(9211.2566627080568911371493459492248414758560557350001666011035717522712240751))]
+        #[case($dec!(7e-25000), $dec!(-57562.681414702086787144681014365662010297900130989742539644738134039377662071))]
+        fn test_ln_256(#[case] d: $D, #[case] expected: $D) {
+            let res = d.ln();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
     };
     (SIGNED:: 256, $dec: ident, $D: ident) => {
         super::test_impl!(SIGNED:: 128, $dec, $D);
@@ -94,8 +129,53 @@ macro_rules! test_impl {
     };
     (SIGNED:: 128, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 128, $dec, $D);
+
+        #[rstest(::trace)]
+        #[case($dec!(1e-9), $dec!(9.9999999950000000033333333308333333353E-10))]
+        #[case($dec!(-1e-9), $dec!(-1.00000000050000000033333333358333333353E-9))]
+        #[case($dec!(1e-20), $dec!(9.9999999999999999999500000000000000000E-21))]
+        #[case($dec!(-1e-30), $dec!(-1.00000000000000000000000000000050000000E-30))]
+        #[case($dec!(0.001), $dec!(0.00099950033308353316680939892053501146076))]
+        #[case($dec!(-0.001), $dec!(-0.00100050033358353350014298225406834496076))]
+        #[case($dec!(0.25), $dec!(0.223143551314209755766295090309834503375))]
+        #[case($dec!(-0.25), $dec!(-0.287682072451780927439219005993827431504))]
+        #[case($dec!(0.49), $dec!(0.39877611995736777295644536573539207265))]
+        #[case($dec!(-0.49), $dec!(-0.67334455326376559639120305457307617497))]
+        #[case($dec!(3.7e-5), $dec!(0.000036999315516883864806951697125826424354))]
+        #[case($dec!(0.00027397260273972603), $dec!(0.000273935079099676473119647914093419307270))]
+        fn test_ln_1p_128(#[case] d: $D, #[case] expected: $D) {
+            let res = d.ln_1p();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1e-300), $dec!(-690.77552789821370520539743640530926228))]
+        #[case($dec!(0.00049), $dec!(-7.6211051668596018098792517865354615787))]
+        #[case($dec!(0.001), $dec!(-6.9077552789821370520539743640530926228))]
+        #[case($dec!(123456789.123), $dec!(18.6314017671643180417639565767636702734))]
+        #[case($dec!(1e300), $dec!(690.77552789821370520539743640530926228))]
+        #[case($dec!(2.5e4000), $dec!(9211.2566627080568911371493459492248415))]
+        #[case($dec!(7e-25000), $dec!(-57562.681414702086787144681014365662010))]
+        fn test_ln_128(#[case] d: $D, #[case] expected: $D) {
+            let res = d.ln();
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
     };
     (SIGNED:: 128, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        #[case($dec!(0), $dec!(0))]
+        #[case($dec!(-0), $dec!(-0))]
+        #[case($D::INFINITY, $D::INFINITY)]
+        fn test_ln_1p_special(#[case] d: $D, #[case] expected: $D) {
+            let res = d.ln_1p();
+            assert_eq!(res, expected);
+            assert_eq!(res.op_signals(), signals![]);
+        }
+
         #[rstest(::trace)]
         #[case($dec!(-1))]
         fn test_ln_neg(#[case] d: $D) {
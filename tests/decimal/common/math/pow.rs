@@ -55,6 +55,17 @@ macro_rules! test_impl {
             assert_eq!(d, expected);
             assert_eq!(d.op_signals(), signals);
         }
+
+        #[rstest(::trace)]
+        #[case($dec!(2.5), $sdec!(1000.3), $dec!(114654386452039989352201230090674131951977621467873475879387370245945256788901e+321))]
+        #[case($dec!(3e-300), $sdec!(0.7), $dec!(2.1576692799745930995549489159802732216718140468388317679677762411394132598811E-210))]
+        #[case($dec!(123.456), $sdec!(-77.7), $dec!(3.0867533881857354115582629377154129281189566344637726057095889711383892501147E-163))]
+        fn test_pow_256(#[case] d: $D, #[case] n: $SD, #[case] expected: $D) {
+            let res = d.pow(n);
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
     };
     (COMMON:: 256, $dec: ident, $D: ident, $sdec: ident, $SD: ident) => {
         super::test_impl!(COMMON:: 128, $dec, $D, $sdec, $SD);
@@ -90,6 +101,17 @@ macro_rules! test_impl {
             assert_eq!(d, expected);
             assert_eq!(d.op_signals(), signals);
         }
+
+        #[rstest(::trace)]
+        #[case($dec!(2.5), $sdec!(1000.3), $dec!(114654386452039989352201230090674131952e+360))]
+        #[case($dec!(3e-300), $sdec!(0.7), $dec!(2.15766927997459309955494891598027322167E-210))]
+        #[case($dec!(123.456), $sdec!(-77.7), $dec!(3.08675338818573541155826293771541292812E-163))]
+        fn test_pow_128(#[case] d: $D, #[case] n: $SD, #[case] expected: $D) {
+            let res = d.pow(n);
+            assert_eq!(res, expected);
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
     };
     (COMMON:: 128, $dec: ident, $D: ident, $sdec: ident, $SD: ident) => {
 
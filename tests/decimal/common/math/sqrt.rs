@@ -71,7 +71,9 @@ macro_rules! test_impl {
         #[case($dec!(0), $dec!(0), signals![])]
         #[case($dec!(1), $dec!(1), signals![])]
         #[case($dec!(2), $D::SQRT_2, signals![!ROUND, !INEXACT])]
-        #[case($dec!(4), $dec!(2), signals![!CP, !ROUND, !INEXACT])]
+        #[case($dec!(4), $dec!(2), signals![])]
+        #[case($dec!(2.25), $dec!(1.5), signals![])]
+        #[case($dec!(152.2756), $dec!(12.34), signals![])]
         fn test_sqrt(#[case] d: $D, #[case] expected: $D, #[case] signals: Signals) {
             let res = d.sqrt();
 
@@ -79,6 +81,22 @@ macro_rules! test_impl {
             assert_eq!(res.op_signals(), signals);
         }
 
+        #[rstest(::trace)]
+        #[case($dec!(2.25), 1)]
+        #[case($dec!(0.0400), 2)]
+        #[case($dec!(100), 0)]
+        #[case($dec!(1e2), -1)]
+        #[case($dec!(1.00), 1)]
+        #[case($dec!(0.00), 1)]
+        #[case($dec!(6.25e-8), 5)]
+        fn test_sqrt_ideal_exponent(#[case] d: $D, #[case] fractional_digits: i16) {
+            let res = d.sqrt();
+
+            assert_eq!(res * res, d);
+            assert_eq!(res.fractional_digits_count(), fractional_digits);
+            assert!(!res.is_op_inexact());
+        }
+
         #[rstest(::trace)]
         #[case($D::NAN)]
         fn test_sqrt_nan(#[case] d: $D) {
@@ -107,6 +125,14 @@ macro_rules! test_impl {
             assert!(res.is_nan());
             assert!(res.is_op_invalid());
         }
+
+        #[rstest(::trace)]
+        fn test_sqrt_neg_zero() {
+            let res = $dec!(-0).sqrt();
+
+            assert!(res.is_zero());
+            assert!(res.is_sign_negative());
+        }
     };
 }
 
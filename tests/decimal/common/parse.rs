@@ -112,6 +112,144 @@ macro_rules! test_impl {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
 
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211455.49", RoundingMode::HalfUp, $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("340282366920938463463374607431768211455.5", RoundingMode::HalfUp, $uint!(34028236692093846346337460743176821146), 1)]
+        #[case("340282366920938463463374607431768211455.5", RoundingMode::Down, $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("34028236692093846346337460743176821145.51", RoundingMode::HalfUp, $uint!(340282366920938463463374607431768211455), -1)]
+        #[case("34028236692093846346337460743176821145.45", RoundingMode::HalfEven, $uint!(340282366920938463463374607431768211454), -1)]
+        #[case("34028236692093846346337460743176821145.450001", RoundingMode::HalfEven, $uint!(340282366920938463463374607431768211455), -1)]
+        #[case("34028236692093846346337460743176821145.41", RoundingMode::Up, $uint!(340282366920938463463374607431768211455), -1)]
+        #[case("1157920892373161954235709850086879078532699846656405640394575840079131296399351", RoundingMode::HalfUp, $uint!(115792089237316195423570985008687907853), 40)]
+        fn test_parse_with_round(#[case] s: &str, #[case] rm: RoundingMode, #[case] _int: $U, #[case] exp: i16) {
+            let opts = ParseOptions::new().with_excess_digits(ExcessDigits::Round);
+            let dec = $D::from_str_with(s, Context::default().with_rounding_mode(rm), &opts).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_rounded());
+            assert!(dec.is_op_inexact());
+        }
+
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211455.99", $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("0.1234567890123456789012345678901234567890123", $uint!(123456789012345678901234567890123456789), -39)]
+        fn test_parse_with_truncate(#[case] s: &str, #[case] _int: $U, #[case] exp: i16) {
+            let opts = ParseOptions::new().with_excess_digits(ExcessDigits::Truncate);
+            let dec = $D::from_str_with(s, Context::default(), &opts).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_inexact());
+        }
+
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211455.0000", $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("3402823669209384634633746074317682114550", $uint!(340282366920938463463374607431768211455), 1)]
+        fn test_parse_with_excess_zeros(#[case] s: &str, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_with(s, Context::default(), &ParseOptions::new()).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(!dec.is_op_inexact());
+            assert_eq!(Ok(dec), $D::from_str_exact(s, Context::default()));
+        }
+
+        #[rstest(::trace)]
+        #[case("1331.107")]
+        #[case("-1_1.2_2e-3")]
+        #[case("0.1234567890123456789012345678901234567890")]
+        #[case("340282366920938463463374607431768211455.0000")]
+        #[case("340282366920938463463374607431768211456")]
+        #[case("340282366920938463463374607431768211455000000")]
+        #[case("1e-9223372036854775808")]
+        #[case("1e99999")]
+        #[case("-Infinity")]
+        #[case("1.")]
+        #[case(".")]
+        #[case("1e")]
+        #[case("1e+")]
+        #[case("+nan")]
+        fn test_parse_with_as_from_str_exact(#[case] s: &str) {
+            let ctx = Context::default();
+            let res = $D::from_str_with(s, ctx, &ParseOptions::new());
+
+            assert_eq!(res, $D::from_str_exact(s, ctx));
+            assert_eq!(res.map(|d| d.op_signals()), $D::from_str_exact(s, ctx).map(|d| d.op_signals()));
+        }
+
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211455.5")]
+        #[case("340282366920938463463374607431768211456")]
+        fn test_parse_with_excess_error(#[case] s: &str) {
+            assert_eq!($D::from_str_with(s, Context::default(), &ParseOptions::new()), Err(ParseError::Inexact));
+        }
+
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211455", $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("340282366920938463463374607431768211455.0000", $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("3402823669209384634633746074317682114550", $uint!(340282366920938463463374607431768211455), 1)]
+        #[case("34028236692093846346337460743176821145500", $uint!(340282366920938463463374607431768211455), 2)]
+        #[case("34028236692093846346337460743176821145_5_00e-2", $uint!(340282366920938463463374607431768211455), 0)]
+        #[case("100000000000000000000000000000000000000.00", $uint!(100000000000000000000000000000000000000), 0)]
+        #[case("1000000000000000000000000000000000000000000e-3", $uint!(100000000000000000000000000000000000000), 1)]
+        #[case("0.1234567890123456789012345678901234567890", $uint!(123456789012345678901234567890123456789), -39)]
+        #[case("0.12345678901234567890123456789012345678900000E5", $uint!(123456789012345678901234567890123456789), -34)]
+        fn test_parse_exact_ok_128(#[case] s: &str, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_exact(s, Context::default()).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("340282366920938463463374607431768211456")]
+        #[case("340282366920938463463374607431768211455.5")]
+        #[case("340282366920938463463374607431768211455.0001")]
+        #[case("0.1234567890123456789012345678901234567891")]
+        #[case("1234567890123456789012345678901234567890123e-10")]
+        #[should_panic(expected = "(fastnum) number cannot be represented exactly in target type")]
+        fn test_parse_exact_inexact_128(#[case] s: &str) {
+            let _ = $D::from_str_exact(s, Context::default()).unwrap();
+        }
+
+        #[rstest(::trace)]
+        #[case("1e2000000000")]
+        #[case("3402823669209384634633746074317682114550e2000000000")]
+        #[should_panic(expected = "(fastnum) number too large to fit in target type")]
+        fn test_parse_exact_overflow_128(#[case] s: &str) {
+            let _ = $D::from_str_exact(s, Context::default()).unwrap();
+        }
+
+        #[rstest(::trace)]
+        #[case("0x1p-200", 16, RoundingMode::HalfUp, $uint!(62230152778611417071440640537801242406), 98)]
+        #[case("0x1p-200", 16, RoundingMode::Down, $uint!(62230152778611417071440640537801242405), 98)]
+        #[case("0x1p-200", 16, RoundingMode::Up, $uint!(62230152778611417071440640537801242406), 98)]
+        #[case("0x1p200", 16, RoundingMode::HalfUp, $uint!(160693804425899027554196209234116260252), -22)]
+        #[case("0x1p200", 16, RoundingMode::Up, $uint!(160693804425899027554196209234116260253), -22)]
+        #[case("0.1", 3, RoundingMode::HalfUp, $uint!(333333333333333333333333333333333333333), 39)]
+        #[case("0.2", 3, RoundingMode::HalfUp, $uint!(66666666666666666666666666666666666667), 38)]
+        #[case("0.2", 3, RoundingMode::Down, $uint!(66666666666666666666666666666666666666), 38)]
+        fn test_parse_radix_round_128(
+            #[case] s: &str,
+            #[case] radix: u32,
+            #[case] rm: RoundingMode,
+            #[case] digits: $U,
+            #[case] scale: i16,
+        ) {
+            let dec = $D::from_str_radix(s, radix, Context::default().with_rounding_mode(rm)).unwrap();
+            assert_eq!(dec.digits(), digits);
+            assert_eq!(dec.fractional_digits_count(), scale);
+            assert!(dec.is_op_inexact());
+        }
+
+        #[rstest(::trace)]
+        #[case("0x1p-1074", f64::from_bits(1))]
+        #[case("0x1.fffffffffffffp1023", f64::MAX)]
+        #[case("0x1.999999999999ap-4", 0.1)]
+        #[case("0x1p-1022", f64::MIN_POSITIVE)]
+        fn test_parse_radix_f64_128(#[case] s: &str, #[case] f: f64) {
+            let dec = $D::from_str_radix(s, 16, Context::default()).unwrap();
+            assert_eq!(dec, $D::try_from(f).unwrap());
+        }
+
     };
     (COMMON:: 128, $uint: ident, $D: ident, $U: ident) => {
         #[rstest(::trace)]
@@ -230,6 +368,270 @@ macro_rules! test_impl {
         fn test_parse_exponent_overflow(#[case] s: &str) {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
+
+        #[rstest(::trace)]
+        #[case("0", $uint!(0), 0)]
+        #[case("+1", $uint!(1), 0)]
+        #[case("1331.107", $uint!(1331107), -3)]
+        #[case(".107",  $uint!(107), -3)]
+        #[case("1.0", $uint!(10), -1)]
+        #[case("1.23E-10", $uint!(123), -12)]
+        #[case("31_862_140.830_686_979", $uint!(31862140830686979), -9)]
+        #[case("340282366920938463.463374607431768211455e-1000", $uint!(340282366920938463463374607431768211455), -1021)]
+        fn test_parse_exact_ok(#[case] s: &str, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_exact(s, Context::default()).unwrap();
+            assert_eq!(dec, $D::from_str(s, Context::default()).unwrap());
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("", ParseError::Empty)]
+        #[case("1.2.3", ParseError::InvalidLiteral)]
+        #[case("1e", ParseError::Empty)]
+        #[case("1e9223372036854775809", ParseError::ExponentOverflow)]
+        fn test_parse_exact_error(#[case] s: &str, #[case] error: ParseError) {
+            assert_eq!($D::from_str_exact(s, Context::default()), Err(error));
+        }
+
+        #[rstest(::trace)]
+        #[case("0", 16, "0")]
+        #[case("0x1.8p3", 16, "12")]
+        #[case("0X1.8P3", 16, "12")]
+        #[case("1.8p+3", 16, "12")]
+        #[case("0x1p-1", 16, "0.5")]
+        #[case("0x1p-4", 16, "0.0625")]
+        #[case("0x.8", 16, "0.5")]
+        #[case("ff.c", 16, "255.75")]
+        #[case("FF.C", 16, "255.75")]
+        #[case("0x1_000.0_8", 16, "4096.03125")]
+        #[case("0x10p0", 16, "16")]
+        #[case("0x1.000", 16, "1")]
+        #[case("0x0.00", 16, "0")]
+        #[case("0b1010.01", 2, "10.25")]
+        #[case("0b0.0001p-2", 2, "0.015625")]
+        #[case("+1.", 2, "1")]
+        #[case(".1", 2, "0.5")]
+        #[case("0o17.4", 8, "15.5")]
+        #[case("1p3", 8, "8")]
+        #[case("1p3", 4, "8")]
+        #[case("0.2", 5, "0.4")]
+        #[case("0.1", 20, "0.05")]
+        #[case("12.5", 10, "12.5")]
+        #[case("1.2e1", 10, "12")]
+        #[case("z", 36, "35")]
+        #[case("0.i", 36, "0.5")]
+        fn test_parse_radix(#[case] s: &str, #[case] radix: u32, #[case] expected: &str) {
+            let dec = $D::from_str_radix(s, radix, Context::default()).unwrap();
+            let expected = $D::from_str(expected, Context::default()).unwrap();
+            assert_eq!(dec, expected);
+            assert_eq!(dec.fractional_digits_count(), expected.fractional_digits_count());
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("", 16, ParseError::Empty)]
+        #[case("0x", 16, ParseError::Empty)]
+        #[case("p3", 16, ParseError::Empty)]
+        #[case("-", 16, ParseError::Empty)]
+        #[case("0xg", 16, ParseError::InvalidLiteral)]
+        #[case("1.2.3", 16, ParseError::InvalidLiteral)]
+        #[case("2", 2, ParseError::InvalidLiteral)]
+        #[case("0x1", 2, ParseError::InvalidLiteral)]
+        #[case("1p3", 3, ParseError::InvalidLiteral)]
+        #[case("1p", 16, ParseError::Empty)]
+        #[case("1p3.5", 16, ParseError::InvalidLiteral)]
+        #[case("1p99999999999", 16, ParseError::ExponentOverflow)]
+        #[case("-nan", 16, ParseError::InvalidLiteral)]
+        #[case("1", 1, ParseError::InvalidRadix)]
+        #[case("1", 37, ParseError::InvalidRadix)]
+        fn test_parse_radix_error(#[case] s: &str, #[case] radix: u32, #[case] error: ParseError) {
+            assert_eq!($D::from_str_radix(s, radix, Context::default()), Err(error));
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_radix_overflow() {
+            let s = "f".repeat(200);
+            assert_eq!($D::from_str_radix(&s, 16, Context::default()), Err(ParseError::PosOverflow));
+            assert_eq!($D::from_str_radix(&format!("{s}p-10"), 16, Context::default()), Err(ParseError::PosOverflow));
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_radix_nan_inf() {
+            assert!($D::from_str_radix("NaN", 16, Context::default()).unwrap().is_nan());
+            assert_eq!($D::from_str_radix("inf", 36, Context::default()).unwrap(), $D::INFINITY);
+            assert_eq!($D::from_str_radix("+Infinity", 2, Context::default()).unwrap(), $D::INFINITY);
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_exact_nan_inf() {
+            assert!($D::from_str_exact("NaN", Context::default()).unwrap().is_nan());
+            assert_eq!($D::from_str_exact("Inf", Context::default()).unwrap(), $D::INFINITY);
+        }
+
+        #[rstest(::trace)]
+        #[case("0")]
+        #[case("1.5")]
+        #[case("+.107")]
+        #[case("1_1.2_2e-3")]
+        #[case("Infinity")]
+        fn test_parse_detailed_ok(#[case] s: &str) {
+            let ctx = Context::default();
+            assert_eq!($D::from_str_detailed(s, ctx), Ok($D::from_str(s, ctx).unwrap()));
+        }
+
+        #[rstest(::trace)]
+        #[case("", ParseError::Empty, 0, Some(ExpectedToken::Sign))]
+        #[case("x1", ParseError::InvalidLiteral, 0, Some(ExpectedToken::Sign))]
+        #[case("+", ParseError::Empty, 1, Some(ExpectedToken::Digit))]
+        #[case(".", ParseError::Empty, 1, Some(ExpectedToken::Digit))]
+        #[case("+nan", ParseError::InvalidLiteral, 1, Some(ExpectedToken::Digit))]
+        #[case("12x", ParseError::InvalidLiteral, 2, Some(ExpectedToken::End))]
+        #[case("1.2.3", ParseError::InvalidLiteral, 3, Some(ExpectedToken::End))]
+        #[case("1e5e5", ParseError::InvalidLiteral, 3, Some(ExpectedToken::End))]
+        #[case("nanx", ParseError::InvalidLiteral, 3, Some(ExpectedToken::End))]
+        #[case("inf5", ParseError::InvalidLiteral, 3, Some(ExpectedToken::End))]
+        #[case("1e", ParseError::Empty, 2, Some(ExpectedToken::Exponent))]
+        #[case("1e+", ParseError::InvalidLiteral, 3, Some(ExpectedToken::Exponent))]
+        #[case("1.5e+x", ParseError::InvalidLiteral, 5, Some(ExpectedToken::Exponent))]
+        #[case("1e9223372036854775809", ParseError::ExponentOverflow, 2, None)]
+        #[case("+1e-9223372036854775808", ParseError::ExponentOverflow, 3, None)]
+        #[case("1e2000000000", ParseError::PosOverflow, 2, None)]
+        #[case("1e99999", ParseError::PosOverflow, 2, None)]
+        #[case("+1.5e+99999", ParseError::PosOverflow, 5, None)]
+        fn test_parse_detailed_error(
+            #[case] s: &str,
+            #[case] kind: ParseError,
+            #[case] position: usize,
+            #[case] expected: Option<ExpectedToken>,
+        ) {
+            let e = $D::from_str_detailed(s, Context::default()).unwrap_err();
+            assert_eq!((e.kind(), e.position(), e.expected()), (kind, position, expected));
+            assert_eq!($D::from_str(s, Context::default()), Err(ParseError::from(e)));
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_detailed_display() {
+            let e = $D::from_str_detailed("1.2.3", Context::default()).unwrap_err();
+            assert_eq!(e.to_string(), "(fastnum) invalid literal found in string at byte 3, expected end of string");
+
+            let e = $D::from_str_detailed("1e2000000000", Context::default()).unwrap_err();
+            assert_eq!(e.to_string(), "(fastnum) number too large to fit in target type at byte 2");
+        }
+
+        #[rstest(::trace)]
+        #[case("7", "7", 1)]
+        #[case("12.5kg", "12.5", 4)]
+        #[case("1_000 m", "1000", 5)]
+        #[case("+.5;", "0.5", 3)]
+        #[case("1.2.3", "1.2", 3)]
+        #[case("1.5e3x", "1.5e3", 5)]
+        #[case("1.5E-3,4", "1.5E-3", 6)]
+        #[case("1.5em", "1.5", 3)]
+        #[case("1.5e+", "1.5", 3)]
+        #[case("infinite", "Inf", 3)]
+        #[case("Infinity!", "Inf", 8)]
+        fn test_parse_prefix(#[case] s: &str, #[case] number: &str, #[case] len: usize) {
+            let ctx = Context::default();
+            assert_eq!($D::from_str_prefix(s, ctx), Ok(($D::from_str(number, ctx).unwrap(), len)));
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_prefix_nan() {
+            let (dec, len) = $D::from_str_prefix("NaN, 1", Context::default()).unwrap();
+            assert!(dec.is_nan());
+            assert_eq!(len, 3);
+        }
+
+        #[rstest(::trace)]
+        #[case("", ParseError::Empty, 0, Some(ExpectedToken::Sign))]
+        #[case("kg", ParseError::InvalidLiteral, 0, Some(ExpectedToken::Sign))]
+        #[case("+kg", ParseError::InvalidLiteral, 1, Some(ExpectedToken::Digit))]
+        #[case("._", ParseError::Empty, 2, Some(ExpectedToken::Digit))]
+        #[case("1e2000000000 m", ParseError::PosOverflow, 2, None)]
+        #[case("1e9223372036854775809 m", ParseError::ExponentOverflow, 2, None)]
+        fn test_parse_prefix_error(
+            #[case] s: &str,
+            #[case] kind: ParseError,
+            #[case] position: usize,
+            #[case] expected: Option<ExpectedToken>,
+        ) {
+            let e = $D::from_str_prefix(s, Context::default()).unwrap_err();
+            assert_eq!((e.kind(), e.position(), e.expected()), (kind, position, expected));
+        }
+
+        #[rstest(::trace)]
+        #[case("1331.107", ParseOptions::new(), $uint!(1331107), -3)]
+        #[case("1_1.2_2", ParseOptions::new(), $uint!(1122), -2)]
+        #[case("1,234,567.89", ParseOptions::new().with_grouping_separator(','), $uint!(123456789), -2)]
+        #[case("1,234,567", ParseOptions::new().with_grouping_separator(','), $uint!(1234567), 0)]
+        #[case("12,34,567.8e2", ParseOptions::new().with_grouping_separator(','), $uint!(12345678), 1)]
+        #[case("1.234.567,89", ParseOptions::new().with_grouping_separator('.').with_decimal_separator(','), $uint!(123456789), -2)]
+        #[case("1 234 567,89", ParseOptions::new().with_grouping_separator(' ').with_decimal_separator(','), $uint!(123456789), -2)]
+        #[case("1'234'567.89", ParseOptions::new().with_grouping_separator('\''), $uint!(123456789), -2)]
+        #[case(",5", ParseOptions::new().with_decimal_separator(','), $uint!(5), -1)]
+        #[case("  12.5 \t", ParseOptions::new().allow_whitespace(true), $uint!(125), -1)]
+        #[case("+ 12.5", ParseOptions::new().allow_whitespace(true), $uint!(125), -1)]
+        #[case("$12.50", ParseOptions::new().allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("12.50€", ParseOptions::new().allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("€ 12,50", ParseOptions::new().with_decimal_separator(',').allow_currency_symbol(true).allow_whitespace(true), $uint!(1250), -2)]
+        #[case("₹12,34,567", ParseOptions::new().with_grouping_separator(',').allow_currency_symbol(true), $uint!(1234567), 0)]
+        #[case("+$12.50", ParseOptions::new().allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("12.5%", ParseOptions::new().allow_percent(true), $uint!(125), -3)]
+        #[case("100 %", ParseOptions::new().allow_percent(true).allow_whitespace(true), $uint!(100), -2)]
+        #[case("12.5+", ParseOptions::new().allow_trailing_sign(true), $uint!(125), -1)]
+        fn test_parse_with_ok(#[case] s: &str, #[case] opts: ParseOptions, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_with(s, Context::default(), &opts).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case(" 12.5", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("1,234.5", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("1_234.5", ParseOptions::new().allow_underscores(false), ParseError::InvalidLiteral)]
+        #[case("1,,234.5", ParseOptions::new().with_grouping_separator(','), ParseError::InvalidLiteral)]
+        #[case(",234.5", ParseOptions::new().with_grouping_separator(','), ParseError::InvalidLiteral)]
+        #[case("1,234,.5", ParseOptions::new().with_grouping_separator(','), ParseError::InvalidLiteral)]
+        #[case("1,234,", ParseOptions::new().with_grouping_separator(','), ParseError::InvalidLiteral)]
+        #[case("1.234,5", ParseOptions::new().with_grouping_separator(','), ParseError::InvalidLiteral)]
+        #[case("1.5", ParseOptions::new().with_decimal_separator(','), ParseError::InvalidLiteral)]
+        #[case("$12.50", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("$12.50$", ParseOptions::new().allow_currency_symbol(true), ParseError::InvalidLiteral)]
+        #[case("12.5%", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("12.5+", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("+12.5+", ParseOptions::new().allow_trailing_sign(true), ParseError::InvalidLiteral)]
+        #[case("(12.5)", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("+nan", ParseOptions::new(), ParseError::InvalidLiteral)]
+        #[case("", ParseOptions::new().allow_whitespace(true), ParseError::Empty)]
+        #[case("  ", ParseOptions::new().allow_whitespace(true), ParseError::Empty)]
+        #[case("$", ParseOptions::new().allow_currency_symbol(true), ParseError::Empty)]
+        #[case("%", ParseOptions::new().allow_percent(true), ParseError::Empty)]
+        #[case("nan%", ParseOptions::new().allow_percent(true), ParseError::InvalidLiteral)]
+        #[case("Inf %", ParseOptions::new().allow_percent(true).allow_whitespace(true), ParseError::InvalidLiteral)]
+        #[case("-infinity%", ParseOptions::new().allow_percent(true), ParseError::InvalidLiteral)]
+        #[case("1e-9223372036854775808", ParseOptions::new(), ParseError::ExponentOverflow)]
+        fn test_parse_with_error(#[case] s: &str, #[case] opts: ParseOptions, #[case] error: ParseError) {
+            assert_eq!($D::from_str_with(s, Context::default(), &opts), Err(error));
+        }
+
+        #[rstest(::trace)]
+        #[case::nan("NaN")]
+        #[case::nan(" nan ")]
+        fn test_parse_with_nan(#[case] s: &str) {
+            let opts = ParseOptions::new().allow_whitespace(true);
+            assert!($D::from_str_with(s, Context::default(), &opts).unwrap().is_nan());
+        }
+
+        #[rstest(::trace)]
+        #[case::inf("Inf")]
+        #[case::inf("$ +Infinity")]
+        fn test_parse_with_inf(#[case] s: &str) {
+            let opts = ParseOptions::new().allow_whitespace(true).allow_currency_symbol(true);
+            assert_eq!($D::from_str_with(s, Context::default(), &opts).unwrap(), $D::INFINITY);
+        }
     };
     (UNSIGNED:: 128, $uint: ident, $D: ident, $U: ident, THIS) => {
         super::test_impl!(UNSIGNED:: 128, $uint, $D, $U);
@@ -244,6 +646,39 @@ macro_rules! test_impl {
         fn test_parse_unsigned(#[case] s: &str) {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
+
+        #[rstest(::trace)]
+        #[case("-1")]
+        #[case("(1)")]
+        #[case("1-")]
+        #[case("-$1")]
+        fn test_parse_with_unsigned(#[case] s: &str) {
+            let opts = ParseOptions::new()
+                .allow_parentheses(true)
+                .allow_trailing_sign(true)
+                .allow_currency_symbol(true);
+            assert_eq!($D::from_str_with(s, Context::default(), &opts), Err(ParseError::Signed));
+        }
+
+        #[rstest(::trace)]
+        #[case("-1")]
+        #[case("-0.0")]
+        #[should_panic(expected = "(fastnum) number would be signed for unsigned type")]
+        fn test_parse_exact_unsigned(#[case] s: &str) {
+            let _ = $D::from_str_exact(s, Context::default()).unwrap();
+        }
+
+        #[rstest(::trace)]
+        #[case("-1")]
+        #[case("-0.0")]
+        #[case("-Inf")]
+        fn test_parse_detailed_unsigned(#[case] s: &str) {
+            let e = $D::from_str_detailed(s, Context::default()).unwrap_err();
+            assert_eq!((e.kind(), e.position(), e.expected()), (ParseError::Signed, 0, None));
+
+            let e = $D::from_str_prefix(s, Context::default()).unwrap_err();
+            assert_eq!((e.kind(), e.position(), e.expected()), (ParseError::Signed, 0, None));
+        }
     };
     (SIGNED:: 128, $uint: ident, $D: ident, $U: ident, THIS) => {
         super::test_impl!(SIGNED:: 128, $uint, $D, $U);
@@ -258,6 +693,51 @@ macro_rules! test_impl {
         fn test_parse_overflow_128_signed(#[case] s: &str) {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
+
+        #[rstest(::trace)]
+        #[case("-340282366920938463463374607431768211455.5", RoundingMode::Floor, $uint!(34028236692093846346337460743176821146), 1)]
+        #[case("-340282366920938463463374607431768211455.5", RoundingMode::Ceiling, $uint!(340282366920938463463374607431768211455), 0)]
+        fn test_parse_with_round_signed(#[case] s: &str, #[case] rm: RoundingMode, #[case] _int: $U, #[case] exp: i16) {
+            let opts = ParseOptions::new().with_excess_digits(ExcessDigits::Round);
+            let dec = $D::from_str_with(s, Context::default().with_rounding_mode(rm), &opts).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.sign(), Sign::Minus);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_inexact());
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_with_excess_error_signed() {
+            let s = "-340282366920938463463374607431768211456";
+            assert_eq!($D::from_str_with(s, Context::default(), &ParseOptions::new()), Err(ParseError::Inexact));
+        }
+
+        #[rstest(::trace)]
+        #[case("-3402823669209384634633746074317682114550", $uint!(340282366920938463463374607431768211455), 1)]
+        #[case("-0.1234567890123456789012345678901234567890", $uint!(123456789012345678901234567890123456789), -39)]
+        fn test_parse_exact_ok_128_signed(#[case] s: &str, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_exact(s, Context::default()).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.sign(), Sign::Minus);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("-340282366920938463463374607431768211456")]
+        #[case("-0.1234567890123456789012345678901234567891")]
+        #[should_panic(expected = "(fastnum) number cannot be represented exactly in target type")]
+        fn test_parse_exact_inexact_128_signed(#[case] s: &str) {
+            let _ = $D::from_str_exact(s, Context::default()).unwrap();
+        }
+
+        #[rstest(::trace)]
+        #[case("-1e2000000000")]
+        #[case("-3402823669209384634633746074317682114550e2000000000")]
+        #[should_panic(expected = "(fastnum) number too small to fit in target type")]
+        fn test_parse_exact_overflow_128_signed(#[case] s: &str) {
+            let _ = $D::from_str_exact(s, Context::default()).unwrap();
+        }
     };
     (SIGNED:: 128, $uint: ident, $D: ident, $U: ident) => {
         #[rstest(::trace)]
@@ -304,6 +784,42 @@ macro_rules! test_impl {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
 
+        #[rstest(::trace)]
+        #[case("-12.5", ParseOptions::new(), $uint!(125), -1)]
+        #[case("(12.5)", ParseOptions::new().allow_parentheses(true), $uint!(125), -1)]
+        #[case("( 1,234.50 )", ParseOptions::new().with_grouping_separator(',').allow_parentheses(true).allow_whitespace(true), $uint!(123450), -2)]
+        #[case("($12.50)", ParseOptions::new().allow_parentheses(true).allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("-$12.50", ParseOptions::new().allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("$-12.50", ParseOptions::new().allow_currency_symbol(true), $uint!(1250), -2)]
+        #[case("12.50 €-", ParseOptions::new().allow_currency_symbol(true).allow_trailing_sign(true).allow_whitespace(true), $uint!(1250), -2)]
+        #[case("12.5-", ParseOptions::new().allow_trailing_sign(true), $uint!(125), -1)]
+        #[case("-12.5%", ParseOptions::new().allow_percent(true), $uint!(125), -3)]
+        #[case("(12.5%)", ParseOptions::new().allow_parentheses(true).allow_percent(true), $uint!(125), -3)]
+        fn test_parse_with_ok_signed(#[case] s: &str, #[case] opts: ParseOptions, #[case] _int: $U, #[case] exp: i16) {
+            let dec = $D::from_str_with(s, Context::default(), &opts).unwrap();
+            assert_eq!(dec.digits(), _int);
+            assert_eq!(dec.sign(), Sign::Minus);
+            assert_eq!(dec.fractional_digits_count(), -exp);
+            assert!(dec.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("(-12.5)", ParseOptions::new().allow_parentheses(true))]
+        #[case("-12.5-", ParseOptions::new().allow_trailing_sign(true))]
+        #[case("(12.5-)", ParseOptions::new().allow_parentheses(true).allow_trailing_sign(true))]
+        #[case("-$-12.5", ParseOptions::new().allow_currency_symbol(true))]
+        #[case("--12.5", ParseOptions::new())]
+        fn test_parse_with_invalid_signed(#[case] s: &str, #[case] opts: ParseOptions) {
+            assert_eq!($D::from_str_with(s, Context::default(), &opts), Err(ParseError::InvalidLiteral));
+        }
+
+        #[rstest(::trace)]
+        fn test_parse_with_inf_neg() {
+            let opts = ParseOptions::new().allow_parentheses(true);
+            assert_eq!($D::from_str_with("(Inf)", Context::default(), &opts).unwrap(), $D::NEG_INFINITY);
+            assert_eq!($D::from_str_with("-Infinity", Context::default(), &opts).unwrap(), $D::NEG_INFINITY);
+        }
+
         #[rstest(::trace)]
         #[case::invalid_exponent("-1e9223372036854775809")]
         #[case::invalid_exponent("-1e-9223372036854775808")]
@@ -311,6 +827,33 @@ macro_rules! test_impl {
         fn test_parse_exponent_overflow_signed(#[case] s: &str) {
             let _ = $D::from_str(s, Context::default()).unwrap();
         }
+
+        #[rstest(::trace)]
+        #[case("-", ParseError::Empty, 1, Some(ExpectedToken::Digit))]
+        #[case("--1", ParseError::InvalidLiteral, 1, Some(ExpectedToken::Digit))]
+        #[case("-1.2.3", ParseError::InvalidLiteral, 4, Some(ExpectedToken::End))]
+        #[case("-1e-", ParseError::InvalidLiteral, 4, Some(ExpectedToken::Exponent))]
+        #[case("-1e9223372036854775809", ParseError::ExponentOverflow, 3, None)]
+        #[case("-1e2000000000", ParseError::NegOverflow, 3, None)]
+        fn test_parse_detailed_error_signed(
+            #[case] s: &str,
+            #[case] kind: ParseError,
+            #[case] position: usize,
+            #[case] expected: Option<ExpectedToken>,
+        ) {
+            let e = $D::from_str_detailed(s, Context::default()).unwrap_err();
+            assert_eq!((e.kind(), e.position(), e.expected()), (kind, position, expected));
+        }
+
+        #[rstest(::trace)]
+        #[case("-12.5kg", "-12.5", 5)]
+        #[case("-1e-3-", "-1e-3", 5)]
+        #[case("-1e-", "-1", 2)]
+        #[case("-Inf.", "-Inf", 4)]
+        fn test_parse_prefix_signed(#[case] s: &str, #[case] number: &str, #[case] len: usize) {
+            let ctx = Context::default();
+            assert_eq!($D::from_str_prefix(s, ctx), Ok(($D::from_str(number, ctx).unwrap(), len)));
+        }
     };
 }
 
@@ -3,7 +3,9 @@ use crate::decimal::common::consts::test_impl;
 test_impl!(D, 128);
 test_impl!(D, 256);
 test_impl!(D, 512);
+test_impl!(D, 4096);
 
 test_impl!(UD, 128);
 test_impl!(UD, 256);
 test_impl!(UD, 512);
+test_impl!(UD, 4096);
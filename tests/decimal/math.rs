@@ -1,9 +1,14 @@
 mod abs;
 mod add;
+mod correctly_rounded;
 mod div;
+mod erf;
 mod exp;
+mod gamma;
+mod lambert_w;
 mod ln;
 mod mul;
+mod nth_root;
 mod pow;
 mod recip;
 mod rem;
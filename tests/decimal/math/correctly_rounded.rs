@@ -0,0 +1,175 @@
+use rstest::*;
+
+use fastnum::{decimal::*, *};
+
+use RoundingMode::*;
+
+#[rstest]
+#[case(|x: D128| x.exp_correctly_rounded::<4>(), dec128!(48.479663411), HalfDown, dec128!(1133575116314244705857.45628567050932628))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1.392413404), Floor, dec128!(3.02455120986818663454846478148124548791))]
+#[case(|x: D128| x.exp2_correctly_rounded::<4>(), dec128!(-49.845149231), Up, dec128!(9.8881476599119224867376417483331912524E-16))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(-0.283063571), Up, dec128!(-0.332768104795833191124356365833365703598))]
+#[case(|x: D128| x.log2_correctly_rounded::<4>(), dec128!(63712.841554010), Up, dec128!(15.9592965617504270528550028548659154325))]
+#[case(|x: D128| x.log10_correctly_rounded::<4>(), dec128!(633150.863567944), Ceiling, dec128!(5.8015072035539842929988435344627473910))]
+#[case(|x: D128| x.log_correctly_rounded::<4>(dec128!(3.3757)), dec128!(132.312047074), HalfDown, dec128!(4.0154136470963405894846657764516174518))]
+#[case(|x: D128| x.pow_correctly_rounded::<4>(dec128!(3.3757)), dec128!(228.265304902), HalfDown, dec128!(91492520.279519783148574619017666790528))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(21.490182850), HalfEven, dec128!(4.6357505163673335883920404264955300523))]
+#[case(|x: D128| x.cbrt_correctly_rounded::<4>(), dec128!(3915.586162591), HalfUp, dec128!(15.7615501571988691138907977201333824372))]
+#[case(|x: D128| x.sin_correctly_rounded::<4>(), dec128!(-9.286653845), HalfUp, dec128!(-0.137685339639489846507146026599825741081))]
+#[case(|x: D128| x.cos_correctly_rounded::<4>(), dec128!(-6.937603281), HalfEven, dec128!(0.79340234038453392260543149379545886005))]
+#[case(|x: D128| x.tan_correctly_rounded::<4>(), dec128!(0.300197231), HalfDown, dec128!(0.309552366618333054340639699276688882968))]
+#[case(|x: D128| x.asin_correctly_rounded::<4>(), dec128!(0.084741304), Ceiling, dec128!(0.084843055622233468860372894692788195368))]
+#[case(|x: D128| x.acos_correctly_rounded::<4>(), dec128!(0.502820115), Ceiling, dec128!(1.04393809020962454822715680568875723172))]
+#[case(|x: D128| x.atan_correctly_rounded::<4>(), dec128!(0.381742172), HalfDown, dec128!(0.36466847395172789678487928838492984481))]
+#[case(|x: D128| x.sinh_correctly_rounded::<4>(), dec128!(-12.447519013), Up, dec128!(-127308.917407332580472607974014680626344))]
+#[case(|x: D128| x.cosh_correctly_rounded::<4>(), dec128!(-12.450712733), Up, dec128!(127716.156404472995412283316656471400156))]
+#[case(|x: D128| x.tanh_correctly_rounded::<4>(), dec128!(0.574337761), Ceiling, dec128!(0.51853782497543030748258868707670707923))]
+#[case(|x: D128| x.asinh_correctly_rounded::<4>(), dec128!(428.543556000), HalfEven, dec128!(6.7535409223253375798719784388840096531))]
+#[case(|x: D128| x.acosh_correctly_rounded::<4>(), dec128!(83.231123113), HalfDown, dec128!(5.1147324438894840753323936520570237672))]
+#[case(|x: D128| x.atanh_correctly_rounded::<4>(), dec128!(0.715563386), HalfDown, dec128!(0.89849318987907894903144028490322855450))]
+fn test_correctly_rounded(
+    #[case] f: fn(D128) -> D128,
+    #[case] d: D128,
+    #[case] rm: RoundingMode,
+    #[case] expected: D128,
+) {
+    let res = f(d.with_rounding_mode(rm));
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+    assert!(res.is_op_rounded());
+}
+
+#[rstest]
+// 1.000…000|499…, just below the midpoint.
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), HalfUp, dec128!(1.00000000000000000000000000000000000000))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), HalfDown, dec128!(1.00000000000000000000000000000000000000))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), HalfEven, dec128!(1.00000000000000000000000000000000000000))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), Up, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), Down, dec128!(1.00000000000000000000000000000000000000))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), Ceiling, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000001), Floor, dec128!(1.00000000000000000000000000000000000000))]
+// 1.000…000|500…001, just above the midpoint.
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), HalfUp, dec128!(1.00000000000000000000000000000000000001E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), HalfDown, dec128!(1.00000000000000000000000000000000000001E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), HalfEven, dec128!(1.00000000000000000000000000000000000001E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), Up, dec128!(1.00000000000000000000000000000000000001E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), Down, dec128!(1.00000000000000000000000000000000000000E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), Ceiling, dec128!(1.00000000000000000000000000000000000001E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(1e-38), Floor, dec128!(1.00000000000000000000000000000000000000E-38))]
+// 1.000…000|999…, just below the next unit.
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), HalfUp, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), HalfDown, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), HalfEven, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), Up, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), Down, dec128!(1.00000000000000000000000000000000000000))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), Ceiling, dec128!(1.00000000000000000000000000000000000001))]
+#[case(|x: D128| x.sqrt_correctly_rounded::<4>(), dec128!(1.00000000000000000000000000000000000002), Floor, dec128!(1.00000000000000000000000000000000000000))]
+// 9.999…500…000|000…003, just above the unit.
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), HalfUp, dec128!(9.9999999999999999999999999999950000000E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), HalfDown, dec128!(9.9999999999999999999999999999950000000E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), HalfEven, dec128!(9.9999999999999999999999999999950000000E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), Up, dec128!(9.9999999999999999999999999999950000001E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), Down, dec128!(9.9999999999999999999999999999950000000E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), Ceiling, dec128!(9.9999999999999999999999999999950000001E-31))]
+#[case(|x: D128| x.ln_1p_correctly_rounded::<4>(), dec128!(1e-30), Floor, dec128!(9.9999999999999999999999999999950000000E-31))]
+// -9.999…999|500…002, just above the midpoint in magnitude.
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), HalfUp, dec128!(-1.0000000000000000000000000000000000000E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), HalfDown, dec128!(-1.0000000000000000000000000000000000000E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), HalfEven, dec128!(-1.0000000000000000000000000000000000000E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), Up, dec128!(-1.0000000000000000000000000000000000000E-38))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), Down, dec128!(-9.9999999999999999999999999999999999999E-39))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), Ceiling, dec128!(-9.9999999999999999999999999999999999999E-39))]
+#[case(|x: D128| x.exp_m1_correctly_rounded::<4>(), dec128!(-1e-38), Floor, dec128!(-1.0000000000000000000000000000000000000E-38))]
+fn test_correctly_rounded_near_boundary(
+    #[case] f: fn(D128) -> D128,
+    #[case] d: D128,
+    #[case] rm: RoundingMode,
+    #[case] expected: D128,
+) {
+    let res = f(d.with_rounding_mode(rm));
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+    assert!(res.is_op_rounded());
+}
+
+#[rstest(::trace)]
+#[case(HalfUp, D128::E)]
+#[case(HalfEven, D128::E)]
+#[case(Ceiling, D128::E)]
+#[case(Up, D128::E)]
+#[case(HalfDown, D128::E)]
+#[case(Floor, dec128!(2.71828182845904523536028747135266249775))]
+#[case(Down, dec128!(2.71828182845904523536028747135266249775))]
+fn test_exp_correctly_rounded_e(#[case] rm: RoundingMode, #[case] expected: D128) {
+    let res = dec128!(1).with_rounding_mode(rm).exp_correctly_rounded::<4>();
+    assert_eq!(res, expected);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(44.640022138), HalfUp, dec256!(24373353456682041307.990407483378401325151056548247431734706566095882341399408))]
+#[case(dec256!(49.361207104), HalfEven, dec256!(2737157987118555323652.9537223887249612266982302857731958098857325918696052890))]
+#[case(dec256!(-10.287309578), HalfDown, dec256!(0.000034062633100868932144659508221834759721695891177406022046848699182958709858529))]
+fn test_exp_correctly_rounded_256(#[case] d: D256, #[case] rm: RoundingMode, #[case] expected: D256) {
+    let res = d.with_rounding_mode(rm).exp_correctly_rounded::<8>();
+    assert_eq!(res, expected);
+}
+
+#[rstest(::trace)]
+#[case(HalfUp)]
+#[case(HalfEven)]
+#[case(HalfDown)]
+#[case(Floor)]
+#[case(Ceiling)]
+#[case(Up)]
+#[case(Down)]
+fn test_correctly_rounded_exact(#[case] rm: RoundingMode) {
+    let x = |d: D128| d.with_rounding_mode(rm);
+
+    for (res, expected) in [
+        (x(dec128!(4)).pow_correctly_rounded::<4>(dec128!(0.5)), dec128!(2)),
+        (x(dec128!(16)).pow_correctly_rounded::<4>(dec128!(0.25)), dec128!(2)),
+        (x(dec128!(32)).pow_correctly_rounded::<4>(dec128!(-0.6)), dec128!(0.125)),
+        (x(dec128!(2.25)).sqrt_correctly_rounded::<4>(), dec128!(1.5)),
+        (x(dec128!(-27)).cbrt_correctly_rounded::<4>(), dec128!(-3)),
+        (x(dec128!(-3)).exp2_correctly_rounded::<4>(), dec128!(0.125)),
+        (x(dec128!(1000)).log10_correctly_rounded::<4>(), dec128!(3)),
+        (x(dec128!(0.125)).log2_correctly_rounded::<4>(), dec128!(-3)),
+        (x(dec128!(8)).log_correctly_rounded::<4>(dec128!(2)), dec128!(3)),
+        (x(dec128!(8)).log_correctly_rounded::<4>(dec128!(4)), dec128!(1.5)),
+        (x(dec128!(0)).exp_correctly_rounded::<4>(), dec128!(1)),
+        (x(dec128!(1)).ln_correctly_rounded::<4>(), dec128!(0)),
+    ] {
+        assert_eq!(res, expected);
+        assert!(!res.is_op_inexact());
+    }
+}
+
+#[test]
+fn test_correctly_rounded_special() {
+    let ctx = Context::default().without_traps();
+
+    let res = D128::NAN.with_ctx(ctx).exp_correctly_rounded::<4>();
+    assert!(res.is_nan());
+    assert!(res.is_op_invalid());
+
+    assert_eq!(D128::INFINITY.exp_correctly_rounded::<4>(), D128::INFINITY);
+    assert_eq!(D128::NEG_INFINITY.exp_correctly_rounded::<4>(), dec128!(0));
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) working width must be wider than the decimal")]
+fn test_correctly_rounded_narrow() {
+    let _ = dec128!(1).exp_correctly_rounded::<2>();
+}
+
+#[test]
+fn test_unsigned_correctly_rounded() {
+    let d = udec128!(1).with_rounding_mode(Floor);
+
+    assert_eq!(d.exp_correctly_rounded::<4>(), udec128!(2.71828182845904523536028747135266249775));
+    assert_eq!(udec128!(4).pow_correctly_rounded::<4>(dec128!(0.5)), udec128!(2));
+    assert_eq!(udec128!(2.25).sqrt_correctly_rounded::<4>(), udec128!(1.5));
+    assert_eq!(udec128!(2).ln_correctly_rounded::<4>(), D128::LN_2);
+}
@@ -0,0 +1,123 @@
+use rstest::*;
+
+use fastnum::{decimal::*, *};
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(0.52049987781304653768274665389196452874))]
+#[case(dec128!(1e-30), dec128!(1.12837916709551257389615890312154517169E-30))]
+#[case(dec128!(1), dec128!(0.84270079294971486934122063508260925930))]
+#[case(dec128!(2), dec128!(0.99532226501895273416206925636725292861))]
+#[case(dec128!(3), dec128!(0.99997790950300141455862722387041767962))]
+#[case(dec128!(4.2), dec128!(0.99999999714450582040781138425078077182))]
+#[case(dec128!(-0.3), dec128!(-0.328626759459127427638914047866756551170))]
+#[case(dec128!(-7), dec128!(-0.99999999999999999999995816174392220586))]
+#[case(dec128!(10), dec128!(1))]
+fn test_erf(#[case] d: D128, #[case] expected: D128) {
+    let res = d.erf();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.3), dec128!(0.67137324054087257236108595213324344883))]
+#[case(dec128!(0.5), dec128!(0.47950012218695346231725334610803547126))]
+#[case(dec128!(1.5), dec128!(0.0338948535246892729330237383540521413186))]
+#[case(dec128!(4), dec128!(1.54172579002800188521596734868840485721E-8))]
+#[case(dec128!(10), dec128!(2.08848758376254475700078629495778861156E-45))]
+#[case(dec128!(17), dec128!(1.02122801509426088114559923507765299440E-127))]
+#[case(dec128!(27), dec128!(5.2370489237892556850160676828495470909E-319))]
+#[case(dec128!(100), dec128!(6.4059614249217320390213391485863941482E-4346))]
+#[case(dec128!(-0.7), dec128!(1.67780119383741847297562880924415139672))]
+#[case(dec128!(-3), dec128!(1.99997790950300141455862722387041767962))]
+fn test_erfc(#[case] d: D128, #[case] expected: D128) {
+    let res = d.erfc();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec256!(2), dec256!(0.0046777349810472658379307436327470713891082029599399232616476737995627192800048))]
+#[case(dec256!(10), dec256!(2.0884875837625447570007862949577886115608181193211637270122137139381746958334E-45))]
+#[case(dec256!(20), dec256!(5.3958656116079009289349991679053456040882726709236052834701037849130187631567E-176))]
+fn test_erfc_256(#[case] d: D256, #[case] expected: D256) {
+    assert_eq!(d.erfc(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0), dec128!(0.5))]
+#[case(dec128!(1e-25), dec128!(0.50000000000000000000000003989422804014))]
+#[case(dec128!(2), dec128!(0.97724986805182079279971736283346656253))]
+#[case(dec128!(-3.5), dec128!(0.000232629079035525036349925886727984773549))]
+#[case(dec128!(-10), dec128!(7.6198530241605260659733432515993083635E-24))]
+#[case(dec128!(-40), dec128!(3.6558935409150297037489858026882836651E-350))]
+fn test_norm_cdf(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.norm_cdf(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(0))]
+#[case(dec128!(0.5000000001), dec128!(2.50662827463100050244201463472058261925E-10))]
+#[case(dec128!(0.3), dec128!(-0.52440051270804078403828932502512255433))]
+#[case(dec128!(0.05), dec128!(-1.64485362695147271486384890799163213608))]
+#[case(dec128!(0.01), dec128!(-2.32634787404084110088560616334691172335))]
+#[case(dec128!(0.999999), dec128!(4.7534243088228989481939881870042750056))]
+#[case(dec128!(1e-100), dec128!(-21.2734535609653242951172121886622264186))]
+#[case(dec128!(1e-30), dec128!(-11.4640246884436157269822642212360372440))]
+#[case(dec128!(0.99999999999999999999), dec128!(9.2623400897984075737173569778753251175))]
+#[case(dec128!(1e-1000), dec128!(-67.785685596602619841886475223183043684))]
+fn test_norm_ppf(#[case] p: D128, #[case] expected: D128) {
+    assert_eq!(p.norm_ppf(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(0.975), dec256!(1.9599639845400542355245944305205515279555500778695483984769526463616352741449))]
+#[case(dec256!(1e-50), dec256!(-14.933337534788488981165969399872784191872928636439704735914787872177882850985))]
+#[case(dec256!(1e-30), dec256!(-11.4640246884436157269822642212360372439612984588341932384094125145322632857815))]
+fn test_norm_ppf_256(#[case] p: D256, #[case] expected: D256) {
+    assert_eq!(p.norm_ppf(), expected);
+}
+
+#[test]
+fn test_erf_special() {
+    let ctx = Context::default().without_traps();
+
+    assert_eq!(dec128!(0).erf(), dec128!(0));
+    assert_eq!(dec128!(0).erfc(), dec128!(1));
+    assert_eq!(D128::INFINITY.erf(), dec128!(1));
+    assert_eq!(D128::NEG_INFINITY.erf(), dec128!(-1));
+    assert_eq!(D128::INFINITY.erfc(), dec128!(0));
+    assert_eq!(D128::NEG_INFINITY.erfc(), dec128!(2));
+    assert_eq!(D128::NEG_INFINITY.norm_cdf(), dec128!(0));
+    assert_eq!(D128::INFINITY.norm_cdf(), dec128!(1));
+
+    let res = dec128!(1000).erfc();
+    assert!(res.is_zero());
+    assert!(res.is_op_underflow());
+
+    let res = dec128!(0).with_ctx(ctx).norm_ppf();
+    assert_eq!(res, D128::NEG_INFINITY);
+    assert!(res.is_op_invalid());
+
+    let res = dec128!(1).with_ctx(ctx).norm_ppf();
+    assert_eq!(res, D128::INFINITY);
+    assert!(res.is_op_invalid());
+
+    for res in [
+        D128::NAN.with_ctx(ctx).erf(),
+        D128::NAN.with_ctx(ctx).erfc(),
+        D128::NAN.with_ctx(ctx).norm_cdf(),
+        dec128!(-0.1).with_ctx(ctx).norm_ppf(),
+        dec128!(1.5).with_ctx(ctx).norm_ppf(),
+    ] {
+        assert!(res.is_nan());
+        assert!(res.is_op_invalid());
+    }
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) invalid operation")]
+fn test_norm_ppf_panic() {
+    let _ = dec128!(2).norm_ppf();
+}
@@ -0,0 +1,223 @@
+use rstest::*;
+
+use fastnum::{decimal::*, *};
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(1.77245385090551602729816748334114518280))]
+#[case(dec128!(2.5), dec128!(1.32934038817913702047362561250585888710))]
+#[case(dec128!(0.1), dec128!(9.5135076986687318362924871772654021925))]
+#[case(dec128!(1e-20), dec128!(99999999999999999999.422784335098467139))]
+#[case(dec128!(1e-40), dec128!(1.0000000000000000000000000000000000000e+40))]
+#[case(dec128!(-1e-40), dec128!(-1.0000000000000000000000000000000000000e+40))]
+#[case(dec128!(7.000001), dec128!(720.00134840603918366900226379003898566))]
+#[case(dec128!(100.3), dec128!(3.7114818671827252602123230462072047169e+156))]
+#[case(dec128!(1234.5678), dec128!(2.35604534799516380381491033030376284961e+3279))]
+#[case(dec128!(-0.001), dec128!(-1000.57820562935864799009761532227074726))]
+#[case(dec128!(-1.5), dec128!(2.36327180120735470306422331112152691040))]
+#[case(dec128!(-20.25), dec128!(-8.5690326638851274804109712704886352798E-19))]
+fn test_gamma(#[case] d: D128, #[case] expected: D128) {
+    let res = d.gamma();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec256!(0.5), dec256!(1.7724538509055160272981674833411451827975494561223871282138077898529112845910))]
+#[case(dec256!(-7.5), dec256!(0.00022384932885968949716374039576982679877957729222250890088811671992321026571222))]
+#[case(dec256!(3000.25), dec256!(1.02359124210518905699574096097992494437616809573038400327304724420437341011386e+9128))]
+fn test_gamma_256(#[case] d: D256, #[case] expected: D256) {
+    assert_eq!(d.gamma(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), dec128!(1))]
+#[case(dec128!(2), dec128!(1))]
+#[case(dec128!(5), dec128!(24))]
+#[case(dec128!(21), dec128!(2432902008176640000))]
+fn test_gamma_exact(#[case] d: D128, #[case] expected: D128) {
+    let res = d.gamma();
+
+    assert_eq!(res, expected);
+    assert!(!res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(0.57236494292470008707171367567652935582))]
+#[case(dec128!(0.999), dec128!(0.00057803853289137972403634250139039698946))]
+#[case(dec128!(1e-10), dec128!(23.0258509298827352736979859311168313135))]
+#[case(dec128!(1000.5), dec128!(5908.6741758486774886838747340626248805))]
+#[case(dec128!(12345.678), dec128!(103959.919905546060921080570493683420315))]
+#[case(dec128!(1e10), dec128!(220258509288.810581470041923123460126556))]
+#[case(dec128!(1e30), dec128!(68077552789821370520539743640497.306390))]
+#[case(dec128!(-2.5), dec128!(-0.05624371649767405067259453009765428412))]
+#[case(dec128!(-1000.5), dec128!(-5914.4377011168518766096993446909082786))]
+fn test_ln_gamma(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.ln_gamma(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(2000.5), dec256!(13202.723836784036366170280385336970663338235167863243803621326714746571002251))]
+#[case(dec256!(1e70), dec256!(1601809565095831978812594018279054945320771042040141083223329530677300747.1026))]
+fn test_ln_gamma_256(#[case] d: D256, #[case] expected: D256) {
+    assert_eq!(d.ln_gamma(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0), dec128!(1))]
+#[case(dec128!(1), dec128!(1))]
+#[case(dec128!(20), dec128!(2432902008176640000))]
+#[case(dec128!(30), dec128!(265252859812191058636308480000000))]
+#[case(dec128!(34), dec128!(295232799039604140847618609643520000000))]
+fn test_factorial(#[case] d: D128, #[case] expected: D128) {
+    let res = d.factorial();
+
+    assert_eq!(res, expected);
+    assert!(!res.is_op_inexact());
+    assert!(!res.is_op_rounded());
+}
+
+#[test]
+fn test_factorial_rounded() {
+    let res = dec128!(35).factorial();
+    assert_eq!(res, dec128!(1.03331479663861449296666513375232e+40));
+    assert!(!res.is_op_inexact());
+    assert!(res.is_op_rounded());
+
+    let res = dec128!(100).factorial();
+    assert_eq!(res, dec128!(9.3326215443944152681699238856266700491e+157));
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(10), dec128!(3), dec128!(120))]
+#[case(dec128!(10), dec128!(0), dec128!(1))]
+#[case(dec128!(10), dec128!(10), dec128!(1))]
+#[case(dec128!(10), dec128!(11), dec128!(0))]
+#[case(dec128!(10), dec128!(-1), dec128!(0))]
+#[case(dec128!(60), dec128!(30), dec128!(118264581564861424))]
+#[case(dec128!(100), dec128!(50), dec128!(100891344545564193334812497256))]
+#[case(dec128!(1000000), dec128!(3), dec128!(166666166667000000))]
+fn test_binomial(#[case] n: D128, #[case] k: D128, #[case] expected: D128) {
+    let res = n.binomial(k);
+
+    assert_eq!(res, expected);
+    assert!(!res.is_op_inexact());
+}
+
+#[test]
+fn test_binomial_rounded() {
+    let res = dec128!(1000).binomial(dec128!(500));
+    assert_eq!(res, dec128!(2.70288240945436569515614693625975275496e+299));
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(2.5), dec128!(3.5), dec128!(0.036815538909255389513234102147806674424))]
+#[case(dec128!(0.1), dec128!(0.2), dec128!(14.5993714927648299428730966291397144656))]
+#[case(dec128!(-0.5), dec128!(0.25), dec128!(2.62205755429211981046483958989111941368))]
+#[case(dec128!(100.5), dec128!(200.25), dec128!(1.87925719271739053308067031634674594095E-84))]
+#[case(dec128!(5000), dec128!(4000.5), dec128!(2.86425509958757357475743820325541038912E-2687))]
+#[case(dec128!(3000), dec128!(4000), dec128!(5.0062627969282340683500503251684142513E-2078))]
+#[case(dec128!(1000), dec128!(1500.5), dec128!(1.53457529877467404620519825843890771999E-732))]
+#[case(dec128!(-1.5), dec128!(-1.5), dec128!(0))]
+fn test_beta(#[case] a: D128, #[case] b: D128, #[case] expected: D128) {
+    assert_eq!(a.beta(b), expected);
+}
+
+#[test]
+fn test_gamma_special() {
+    let ctx = Context::default().without_traps();
+
+    let res = dec128!(0).with_ctx(ctx).gamma();
+    assert_eq!(res, D128::INFINITY);
+    assert!(res.is_op_div_by_zero());
+
+    let res = dec128!(-0).with_ctx(ctx).gamma();
+    assert_eq!(res, D128::NEG_INFINITY);
+    assert!(res.is_op_div_by_zero());
+
+    let res = dec128!(-3).with_ctx(ctx).ln_gamma();
+    assert_eq!(res, D128::INFINITY);
+    assert!(res.is_op_div_by_zero());
+
+    assert_eq!(D128::INFINITY.gamma(), D128::INFINITY);
+    assert_eq!(D128::INFINITY.ln_gamma(), D128::INFINITY);
+
+    for res in [
+        dec128!(-3).with_ctx(ctx).gamma(),
+        D128::NEG_INFINITY.with_ctx(ctx).gamma(),
+        D128::NAN.with_ctx(ctx).gamma(),
+        D128::NAN.with_ctx(ctx).ln_gamma(),
+        dec128!(2.5).with_ctx(ctx).factorial(),
+        dec128!(-1).with_ctx(ctx).factorial(),
+        dec128!(2.5)
+            .with_ctx(ctx)
+            .binomial(dec128!(1).with_ctx(ctx)),
+        dec128!(5)
+            .with_ctx(ctx)
+            .binomial(dec128!(1.5).with_ctx(ctx)),
+        dec128!(0).with_ctx(ctx).beta(dec128!(1).with_ctx(ctx)),
+        dec128!(1).with_ctx(ctx).beta(D128::INFINITY.with_ctx(ctx)),
+    ] {
+        assert!(res.is_nan());
+        assert!(res.is_op_invalid());
+    }
+}
+
+#[test]
+fn test_gamma_overflow() {
+    let ctx = Context::default().without_traps();
+
+    for res in [
+        dec128!(9999).with_ctx(ctx).factorial(),
+        dec128!(99999).with_ctx(ctx).factorial(),
+        dec128!(9999).with_ctx(ctx).gamma(),
+        dec128!(1e5).with_ctx(ctx).gamma(),
+        dec128!(19999.5).with_ctx(ctx).gamma(),
+        dec128!(20000.5).with_ctx(ctx).gamma(),
+    ] {
+        assert_eq!(res, D128::INFINITY);
+        assert!(res.is_op_overflow());
+        assert!(!res.is_op_invalid());
+    }
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+fn test_factorial_overflow_panic() {
+    let _ = dec128!(99999).factorial();
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) division by zero")]
+fn test_gamma_pole_panic() {
+    let _ = dec128!(0).gamma();
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) invalid operation")]
+fn test_factorial_panic() {
+    let _ = dec128!(0.5).factorial();
+}
+
+#[test]
+fn test_unsigned_gamma() {
+    assert_eq!(
+        udec128!(0.5).gamma(),
+        udec128!(1.77245385090551602729816748334114518280)
+    );
+    assert_eq!(
+        udec128!(1000.5).ln_gamma(),
+        dec128!(5908.6741758486774886838747340626248805)
+    );
+    assert_eq!(udec128!(20).factorial(), udec128!(2432902008176640000));
+    assert_eq!(
+        udec128!(100).binomial(udec128!(50)),
+        udec128!(100891344545564193334812497256)
+    );
+    assert_eq!(
+        udec128!(2.5).beta(udec128!(3.5)),
+        udec128!(0.036815538909255389513234102147806674424)
+    );
+}
@@ -0,0 +1,163 @@
+use rstest::*;
+
+use fastnum::{decimal::*, *};
+
+#[rstest(::trace)]
+#[case(dec128!(1), dec128!(0.56714329040978387299996866221035554975))]
+#[case(dec128!(0.5), dec128!(0.35173371124919582602490930092995106517))]
+#[case(dec128!(3), dec128!(1.04990889496403995998869707055289790459))]
+#[case(dec128!(7.5), dec128!(1.56623095378238753941985597753718406736))]
+#[case(dec128!(100), dec128!(3.38563014029005018488824436452972686749))]
+#[case(dec128!(1e100), dec128!(224.843106445118501539373134337955675411))]
+#[case(dec128!(1e30000), dec128!(69066.409966037386025066537972126736825))]
+#[case(dec128!(1e-30), dec128!(9.9999999999999999999999999999900000000E-31))]
+#[case(dec128!(-1e-30), dec128!(-1.00000000000000000000000000000100000000E-30))]
+#[case(dec128!(-0.1), dec128!(-0.111832559158962964833569456820265842273))]
+#[case(dec128!(-0.3), dec128!(-0.48940222718021496903623125199629336892))]
+#[case(dec128!(-0.35), dec128!(-0.71663881645607385058816980000386504061))]
+fn test_lambert_w0(#[case] d: D128, #[case] expected: D128) {
+    let res = d.lambert_w0();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(-0.01), dec128!(-6.4727751243940046947410578927244880371))]
+#[case(dec128!(-0.1), dec128!(-3.5771520639572972184093919635119948804))]
+#[case(dec128!(-0.3), dec128!(-1.78133702342162761197417028151274526082))]
+#[case(dec128!(-0.35), dec128!(-1.34971725219224883338314445944635709819))]
+#[case(dec128!(-0.36), dec128!(-1.22277013397850595314293807342386231317))]
+#[case(dec128!(-1e-10), dec128!(-26.2952388192469256941101288218549182377))]
+#[case(dec128!(-1e-1000), dec128!(-2310.33023874784054589842688636390557371))]
+fn test_lambert_wm1(#[case] d: D128, #[case] expected: D128) {
+    let res = d.lambert_wm1();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec256!(10), dec256!(1.7455280027406993830743012648753899115352881290809413313222060485555572599416))]
+#[case(dec256!(-0.2), dec256!(-0.25917110181907374505665195021540670571358833970089370322183912751271282179576))]
+#[case(dec256!(-0.36787944117144232159552377016146086), dec256!(-0.99999999999999999363763988827703254900840109785292535644996495725292276275650))]
+fn test_lambert_w0_256(#[case] d: D256, #[case] expected: D256) {
+    assert_eq!(d.lambert_w0(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(-0.2), dec256!(-2.5426413577735264242938061566618482901614749075294317671169346997933525354832))]
+#[case(dec256!(-1e-30), dec256!(-73.373110313822976797067478758120874529181611813765934251299973573946556802258))]
+fn test_lambert_wm1_256(#[case] d: D256, #[case] expected: D256) {
+    assert_eq!(d.lambert_wm1(), expected);
+}
+
+#[test]
+fn test_lambert_w_identity() {
+    for x in [dec128!(0.001), dec128!(2), dec128!(50), dec128!(-0.2)] {
+        let w = x.lambert_w0();
+        assert!(((w * w.exp() - x) / x).abs() < dec128!(1e-36));
+    }
+
+    for x in [dec128!(-0.001), dec128!(-0.2)] {
+        let w = x.lambert_wm1();
+        assert!(((w * w.exp() - x) / x).abs() < dec128!(1e-36));
+    }
+}
+
+#[test]
+fn test_lambert_w_branch_point() {
+    let x = dec128!(-0.36787944117144232159552377016146086745);
+    assert_eq!(x.lambert_w0(), dec128!(-1));
+    assert_eq!(x.lambert_wm1(), dec128!(-1));
+
+    let x = dec256!(-0.3678794411714423215955237701614608674458111310317678345078368016974614957449);
+    assert_eq!(x.lambert_w0(), dec256!(-1));
+    assert_eq!(x.lambert_wm1(), dec256!(-1));
+}
+
+#[test]
+fn test_lambert_w_special() {
+    let ctx = Context::default().without_traps();
+
+    assert_eq!(dec128!(0).lambert_w0(), dec128!(0));
+    assert_eq!(D128::INFINITY.lambert_w0(), D128::INFINITY);
+
+    let res = dec128!(0).with_ctx(ctx).lambert_wm1();
+    assert_eq!(res, D128::NEG_INFINITY);
+    assert!(res.is_op_invalid());
+
+    for res in [
+        D128::NAN.with_ctx(ctx).lambert_w0(),
+        D128::NAN.with_ctx(ctx).lambert_wm1(),
+        D128::NEG_INFINITY.with_ctx(ctx).lambert_w0(),
+        D128::NEG_INFINITY.with_ctx(ctx).lambert_wm1(),
+        dec128!(-0.4).with_ctx(ctx).lambert_w0(),
+        dec128!(-0.4).with_ctx(ctx).lambert_wm1(),
+        dec128!(0.1).with_ctx(ctx).lambert_wm1(),
+    ] {
+        assert!(res.is_nan());
+        assert!(res.is_op_invalid());
+    }
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) invalid operation")]
+fn test_lambert_w0_panic() {
+    let _ = dec128!(-1).lambert_w0();
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0), dec128!(0), dec128!(0.69314718055994530941723212145817656808))]
+#[case(dec128!(1), dec128!(2), dec128!(2.31326168751822283404899549496785564192))]
+#[case(dec128!(-5), dec128!(30), dec128!(30.0000000000000006305116760146987397914))]
+#[case(dec128!(0), dec128!(-200), dec128!(1.38389652673673753064868145697908468540E-87))]
+#[case(dec128!(1e-50), dec128!(-200), dec128!(1.00000000000000000000000000000000000014E-50))]
+#[case(dec128!(-100000), dec128!(-100000.5), dec128!(-99999.525923015819893319127002644918829))]
+#[case(dec128!(100000), dec128!(100000), dec128!(100000.693147180559945309417232121458177))]
+fn test_log_add_exp(#[case] a: D128, #[case] b: D128, #[case] expected: D128) {
+    assert_eq!(a.log_add_exp(b), expected);
+    assert_eq!(b.log_add_exp(a), expected);
+}
+
+#[test]
+fn test_log_add_exp_special() {
+    let ctx = Context::default().without_traps();
+
+    assert_eq!(D128::NEG_INFINITY.log_add_exp(dec128!(-3)), dec128!(-3));
+    assert_eq!(dec128!(-3).log_add_exp(D128::NEG_INFINITY), dec128!(-3));
+    assert_eq!(D128::INFINITY.log_add_exp(dec128!(3)), D128::INFINITY);
+    assert_eq!(
+        D128::NEG_INFINITY.log_add_exp(D128::NEG_INFINITY),
+        D128::NEG_INFINITY
+    );
+
+    let res = D128::NAN.with_ctx(ctx).log_add_exp(dec128!(1).with_ctx(ctx));
+    assert!(res.is_nan());
+    assert!(res.is_op_invalid());
+}
+
+#[rstest(::trace)]
+#[case(&[], D128::NEG_INFINITY)]
+#[case(&[dec128!(5)], dec128!(5))]
+#[case(&[dec128!(-3), dec128!(7), dec128!(0.5)], dec128!(7.0015476409084938364465460246756007702))]
+#[case(&[dec128!(1), dec128!(2), dec128!(3), dec128!(-1e6), dec128!(1e6), dec128!(1e6)], dec128!(1000000.69314718055994530941723212145818))]
+#[case(&[D128::NEG_INFINITY, dec128!(2), D128::NEG_INFINITY], dec128!(2))]
+#[case(&[dec128!(2), D128::INFINITY, dec128!(3)], D128::INFINITY)]
+fn test_log_sum_exp(#[case] x: &[D128], #[case] expected: D128) {
+    assert_eq!(D128::log_sum_exp(x.iter().copied()), expected);
+}
+
+#[test]
+fn test_log_sum_exp_nan() {
+    let ctx = Context::default().without_traps();
+
+    let res = D128::log_sum_exp([
+        dec128!(1).with_ctx(ctx),
+        D128::NAN.with_ctx(ctx),
+        dec128!(2).with_ctx(ctx),
+    ]);
+
+    assert!(res.is_nan());
+    assert!(res.is_op_invalid());
+}
@@ -0,0 +1,107 @@
+use rstest::*;
+
+use fastnum::{decimal::*, *};
+
+#[rstest(::trace)]
+#[case(dec128!(8), dec128!(2))]
+#[case(dec128!(-8), dec128!(-2))]
+#[case(dec128!(0.001), dec128!(0.1))]
+#[case(dec128!(1e3), dec128!(1e1))]
+#[case(dec128!(-1e-30), dec128!(-1e-10))]
+#[case(dec128!(1.000), dec128!(1.0))]
+#[case(dec128!(1881.365963625), dec128!(12.345))]
+fn test_cbrt_exact(#[case] d: D128, #[case] expected: D128) {
+    let res = d.cbrt();
+
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+    assert!(!res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(2), dec128!(1.25992104989487316476721060727822835057))]
+#[case(dec128!(-2), dec128!(-1.25992104989487316476721060727822835057))]
+#[case(dec128!(100), dec128!(4.6415888336127788924100763509194465765))]
+fn test_cbrt(#[case] d: D128, #[case] expected: D128) {
+    let res = d.cbrt();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(16), 4, dec128!(2))]
+#[case(dec128!(-32), 5, dec128!(-2))]
+#[case(dec128!(0.00032), 5, dec128!(0.2))]
+#[case(dec128!(1024), 10, dec128!(2))]
+#[case(dec128!(1.2345), 1, dec128!(1.2345))]
+#[case(dec128!(-1.2345), 1, dec128!(-1.2345))]
+fn test_nth_root_exact(#[case] d: D128, #[case] n: u32, #[case] expected: D128) {
+    let res = d.nth_root(n);
+
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+    assert!(!res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(-16), 4)]
+#[case(dec128!(-2), 2)]
+fn test_nth_root_neg_even(#[case] d: D128, #[case] n: u32) {
+    let ctx = decimal::Context::default().without_traps();
+    let res = d.with_ctx(ctx).nth_root(n);
+
+    assert!(res.is_nan());
+    assert!(res.is_op_invalid());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(2))]
+#[case(dec128!(0))]
+#[case(dec128!(-8))]
+#[case(D128::INFINITY)]
+fn test_nth_root_zero(#[case] d: D128) {
+    let ctx = decimal::Context::default().without_traps();
+    let res = d.with_ctx(ctx).nth_root(0);
+
+    assert!(res.is_nan());
+    assert!(res.is_op_invalid());
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) invalid operation")]
+fn test_nth_root_zero_panic() {
+    let _ = dec128!(2).nth_root(0);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(2))]
+#[case(dec128!(-1.2300))]
+#[case(dec128!(0.000))]
+#[case(D128::NEG_INFINITY)]
+fn test_nth_root_one(#[case] d: D128) {
+    let res = d.nth_root(1);
+
+    assert_eq!(res, d);
+    assert_eq!(res.fractional_digits_count(), d.fractional_digits_count());
+    assert!(res.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(D128::NEG_INFINITY, 3, D128::NEG_INFINITY)]
+#[case(D128::INFINITY, 4, D128::INFINITY)]
+fn test_nth_root_infinity(#[case] d: D128, #[case] n: u32, #[case] expected: D128) {
+    assert_eq!(d.nth_root(n), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1.23e-50), 100, dec128!(0.316883080362766952131366262879729324026))]
+#[case(dec128!(1.23e-50), 50, dec128!(0.100414886620195818358799673537517577106))]
+#[case(dec128!(123456789.123), 1000, dec128!(1.01880604928966586355627053291550476622))]
+#[case(dec128!(2), 40, dec128!(1.01747969210268639363528628479664301453))]
+fn test_nth_root_large_n(#[case] d: D128, #[case] n: u32, #[case] expected: D128) {
+    let res = d.nth_root(n);
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+}
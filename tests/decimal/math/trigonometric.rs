@@ -371,4 +371,194 @@ fn test_angles_360(#[case] angle: D128, #[case] sin: D128, #[case] cos: D128, #[
     if !tan.is_infinite() {
         assert_eq!(angle.to_radians().tan().round(4), tan);
     }
+
+    // The radian table has a negative zero at 180°.
+    assert!((angle.sin_deg() - sin).round(4).is_zero());
+    assert!((angle.cos_deg() - cos).round(4).is_zero());
+    if !tan.is_infinite() {
+        assert!((angle.tan_deg() - tan).round(4).is_zero());
+    }
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0), dec128!(0), dec128!(1), dec128!(0))]
+#[case(dec128!(30), dec128!(0.5), D128::SQRT_3 / dec128!(2), D128::SQRT_3 / dec128!(3))]
+#[case(dec128!(45), D128::FRAC_1_SQRT_2, D128::FRAC_1_SQRT_2, dec128!(1))]
+#[case(dec128!(60), D128::SQRT_3 / dec128!(2), dec128!(0.5), D128::SQRT_3)]
+#[case(dec128!(120), D128::SQRT_3 / dec128!(2), dec128!(-0.5), -D128::SQRT_3)]
+#[case(dec128!(135), D128::FRAC_1_SQRT_2, -D128::FRAC_1_SQRT_2, dec128!(-1))]
+#[case(dec128!(150), dec128!(0.5), -D128::SQRT_3 / dec128!(2), -D128::SQRT_3 / dec128!(3))]
+#[case(dec128!(180), dec128!(0), dec128!(-1), dec128!(0))]
+#[case(dec128!(210), dec128!(-0.5), -D128::SQRT_3 / dec128!(2), D128::SQRT_3 / dec128!(3))]
+#[case(dec128!(-30), dec128!(-0.5), D128::SQRT_3 / dec128!(2), -D128::SQRT_3 / dec128!(3))]
+#[case(dec128!(-390), dec128!(-0.5), D128::SQRT_3 / dec128!(2), -D128::SQRT_3 / dec128!(3))]
+#[case(dec128!(720.0), dec128!(0), dec128!(1), dec128!(0))]
+#[case(dec128!(1e30), dec128!(280).sin_deg(), dec128!(280).cos_deg(), dec128!(280).tan_deg())]
+fn test_deg_exact(#[case] angle: D128, #[case] sin: D128, #[case] cos: D128, #[case] tan: D128) {
+    assert_eq!(angle.sin_deg(), sin);
+    assert_eq!(angle.cos_deg(), cos);
+    assert_eq!(angle.tan_deg(), tan);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0))]
+#[case(dec128!(90))]
+#[case(dec128!(180))]
+#[case(dec128!(-270))]
+#[case(dec128!(450))]
+#[case(dec128!(9e40))]
+fn test_deg_no_inexact(#[case] angle: D128) {
+    assert!(!angle.sin_deg().is_op_inexact());
+    assert!(!angle.cos_deg().is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), dec128!(0.0174524064372835128194189785163161924723), dec128!(0.99984769515639123915701155881391485169), dec128!(0.0174550649282175857651288952197278243141))]
+#[case(dec128!(12.5), dec128!(0.216439613938102879759553669617940728673), dec128!(0.97629600711993336597088648960542757717), dec128!(0.221694662642939901577417535162907607299))]
+#[case(dec128!(75), dec128!(0.96592582628906828674974319972889736763), dec128!(0.258819045102520762348898837624048328349), dec128!(3.7320508075688772935274463415058723669))]
+#[case(dec128!(-100), dec128!(-0.98480775301220805936674302458952301367), dec128!(-0.173648177666930348851716626769314796000), dec128!(5.6712818196177095309944184398639644216))]
+#[case(dec128!(89.999), dec128!(0.99999999984769129011051202417815196064), dec128!(0.0000174532925190571996135491056851289696691), dec128!(57295.779507264556703365576736929267142))]
+#[case(dec128!(1000000007), dec128!(-0.95630475596303548133865081661841896201), dec128!(0.292371704722736728097468695377143252665), dec128!(-3.27085261848414086530885625730541077711))]
+fn test_deg(#[case] angle: D128, #[case] sin: D128, #[case] cos: D128, #[case] tan: D128) {
+    assert_eq!(angle.sin_deg(), sin);
+    assert_eq!(angle.cos_deg(), cos);
+    assert_eq!(angle.tan_deg(), tan);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(90), D128::INFINITY)]
+#[case(dec128!(270), D128::NEG_INFINITY)]
+#[case(dec128!(-90), D128::NEG_INFINITY)]
+#[case(dec128!(450), D128::INFINITY)]
+fn test_tan_deg_pole(#[case] angle: D128, #[case] expected: D128) {
+    let ctx = decimal::Context::default().without_traps();
+    let res = angle.with_ctx(ctx).tan_deg();
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_div_by_zero());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(1.83048772171245191926801943896881662376), dec128!(1.13949392732454912231332776820494992842), dec128!(2.08582964293348818577250167545929030196))]
+#[case(dec128!(1), dec128!(0.64209261593433070300641998659426562023), dec128!(1.85081571768092561791175324139865019347), dec128!(1.18839510577812121626159945237455100353))]
+#[case(dec128!(-2), dec128!(0.45765755436028576375027741043204727643), dec128!(-2.40299796172238098975460040142006622625), dec128!(-1.09975017029461646675669739702631289666))]
+#[case(dec128!(3), dec128!(-7.0152525514345334694285513795264765783), dec128!(-1.01010866590799375130303648146319295519), dec128!(7.0861673957371859182175322724612798674))]
+#[case(dec128!(0.001), dec128!(999.99966666664444444232804211640209502), dec128!(1.00000050000020833341805558990576789049), dec128!(1000.00016666668611111316137587136245520))]
+fn test_reciprocal(#[case] d: D128, #[case] cot: D128, #[case] sec: D128, #[case] csc: D128) {
+    assert_eq!(d.cot(), cot);
+    assert_eq!(d.sec(), sec);
+    assert_eq!(d.csc(), csc);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0))]
+#[case(dec128!(-0))]
+fn test_cot_pole(#[case] d: D128) {
+    let ctx = decimal::Context::default().without_traps();
+    let res = d.with_ctx(ctx).cot();
+
+    assert!(res.is_infinite());
+    assert!(res.is_op_div_by_zero());
+    assert_eq!(res, d.with_ctx(ctx).csc());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0), D128::FRAC_PI_2)]
+#[case(dec128!(1), D128::FRAC_PI_4)]
+#[case(dec128!(0.5), dec128!(1.10714871779409050301706546017853704007))]
+#[case(dec128!(-0.7), dec128!(-0.96007036240568800268756281514951534828))]
+#[case(dec128!(-2), dec128!(-0.46364760900080611621425623146121440203))]
+#[case(dec128!(30), dec128!(0.0333209958782471971561477890213939465991))]
+#[case(dec128!(1e-20), dec128!(1.57079632679489661922132169163975144210))]
+#[case(D128::INFINITY, dec128!(0))]
+fn test_acot(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.acot(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), dec128!(0), D128::FRAC_PI_2)]
+#[case(dec128!(-1), D128::PI, -D128::FRAC_PI_2)]
+#[case(dec128!(2), D128::FRAC_PI_3, D128::FRAC_PI_6)]
+#[case(dec128!(-2), dec128!(2.09439510239319549230842892218633525613), -D128::FRAC_PI_6)]
+#[case(dec128!(1.5), dec128!(0.84106867056793025577652503182643074670), dec128!(0.72972765622696636345479665981332069540))]
+#[case(dec128!(-1.001), dec128!(3.09688991592957207826810619786894746295), dec128!(-1.52609358913467545903678450622919602085))]
+#[case(dec128!(1e20), dec128!(1.57079632679489661922132169163975144210), dec128!(1e-20))]
+fn test_asec_acsc(#[case] d: D128, #[case] asec: D128, #[case] acsc: D128) {
+    assert_eq!(d.asec(), asec);
+    assert_eq!(d.acsc(), acsc);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5))]
+#[case(dec128!(-0.999))]
+#[case(dec128!(0))]
+fn test_asec_acsc_nan(#[case] d: D128) {
+    let ctx = decimal::Context::default().without_traps();
+
+    assert!(d.with_ctx(ctx).asec().is_nan());
+    assert!(d.with_ctx(ctx).acsc().is_nan());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(2.16395341373865284877000401021802311709), dec128!(0.88681888397007390865889779778340856253), dec128!(1.91903475133494371949220287872700615959))]
+#[case(dec128!(-0.7), dec128!(-1.65462163580262940471322428080549472935), dec128!(0.79670545999287502521130204918219182884), dec128!(-1.31824609146629719165164762154575013838))]
+#[case(dec128!(2.5), dec128!(1.01356730981260846219203980180602556845), dec128!(0.163071231929977826043195139320894767452), dec128!(0.165283669855095564578652155098521114710))]
+#[case(dec128!(0.001), dec128!(1000.00033333331111111322751301587303725), dec128!(0.99999950000020833324861114546129560158), dec128!(999.99983333335277777572751343749997866))]
+#[case(dec128!(30), dec128!(1.00000000000000000000000001751302152539), dec128!(1.87152459376803492098316642828771610101E-13), dec128!(1.87152459376803492098316646106376659697E-13))]
+#[case(D128::NEG_INFINITY, dec128!(-1), dec128!(0), dec128!(-0))]
+fn test_reciprocal_hyperbolic(#[case] d: D128, #[case] coth: D128, #[case] sech: D128, #[case] csch: D128) {
+    assert_eq!(d.coth(), coth);
+    assert_eq!(d.sech(), sech);
+    assert_eq!(d.csch(), csch);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1.5), dec128!(0.80471895621705018730037966661309381976))]
+#[case(dec128!(-2), dec128!(-0.54930614433405484569762261846126285232))]
+#[case(dec128!(-1.001), dec128!(-3.8007011672918667046926350505473167143))]
+#[case(dec128!(30), dec128!(0.0333456872493361093729461460902228024777))]
+#[case(dec128!(1e20), dec128!(1e-20))]
+fn test_acoth(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.acoth(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), dec128!(0))]
+#[case(dec128!(0.5), dec128!(1.31695789692481670862504634730796844403))]
+#[case(dec128!(0.001), dec128!(7.6009022095419886114191231179982237482))]
+#[case(dec128!(1e-20), dec128!(46.744849040440858989777061215145460720))]
+fn test_asech(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.asech(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5), dec128!(1.44363547517881034249327674027310526941))]
+#[case(dec128!(1), dec128!(0.88137358701954302523260932497979230903))]
+#[case(dec128!(-0.7), dec128!(-1.15447739423706844903369902443775298521))]
+#[case(dec128!(3), dec128!(0.327450150237258443322535259988258127701))]
+#[case(dec128!(0.001), dec128!(7.6009027095419886115232897846649396336))]
+#[case(dec128!(1e20), dec128!(1e-20))]
+#[case(dec128!(1e-20000), dec128!(46052.395007061473625669246325808742329))]
+#[case(dec128!(-1e-32767), dec128!(-75449.498889316454873526943227764020167))]
+fn test_acsch(#[case] d: D128, #[case] expected: D128) {
+    assert_eq!(d.acsch(), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(0.5))]
+#[case(dec128!(-0.999))]
+#[case(dec128!(0))]
+fn test_acoth_nan(#[case] d: D128) {
+    let ctx = decimal::Context::default().without_traps();
+
+    assert!(d.with_ctx(ctx).acoth().is_nan());
+}
+
+#[rstest(::trace)]
+#[case(dec128!(-0.5))]
+#[case(dec128!(1.001))]
+fn test_asech_nan(#[case] d: D128) {
+    let ctx = decimal::Context::default().without_traps();
+
+    assert!(d.with_ctx(ctx).asech().is_nan());
 }
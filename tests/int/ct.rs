@@ -0,0 +1,156 @@
+use rstest::*;
+
+use fastnum::{
+    int::ct::{trace, Choice, ConstTime},
+    U128, U256, U512,
+};
+
+const P: U256 = U256::parse_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16);
+
+fn inputs() -> [U256; 7] {
+    [
+        U256::ZERO,
+        U256::ONE,
+        U256::from_digit(u64::MAX),
+        U256::parse_str_radix("deadbeefcafebabe0123456789abcdef", 16),
+        P,
+        U256::MAX.sub(U256::ONE),
+        U256::MAX,
+    ]
+}
+
+fn widen(x: U256) -> U512 {
+    U512::from_str_radix(&x.to_str_radix(16), 16).unwrap()
+}
+
+fn ct(x: U256) -> ConstTime<U256> {
+    ConstTime::new(x)
+}
+
+#[rstest(::trace)]
+fn test_arithmetic() {
+    for a in inputs() {
+        for b in inputs() {
+            let (sum, carry) = ct(a).overflowing_add(ct(b));
+            assert_eq!((sum.get(), bool::from(carry)), a.overflowing_add(b));
+
+            let (diff, borrow) = ct(a).overflowing_sub(ct(b));
+            assert_eq!((diff.get(), bool::from(borrow)), a.overflowing_sub(b));
+
+            assert_eq!((ct(a) * ct(b)).get(), a.wrapping_mul(b));
+
+            let (low, high) = ct(a).widening_mul(ct(b));
+            assert_eq!((low.get(), high.get()), a.widening_mul(b));
+            assert_eq!(widen(low.get()) + (widen(high.get()) << 256), widen(a) * widen(b));
+        }
+    }
+}
+
+#[rstest(::trace)]
+fn test_compare() {
+    for a in inputs() {
+        assert_eq!(bool::from(ct(a).ct_is_zero()), a.is_zero());
+
+        for b in inputs() {
+            assert_eq!(bool::from(ct(a).ct_eq(&ct(b))), a == b);
+            assert_eq!(bool::from(ct(a).ct_lt(&ct(b))), a < b);
+            assert_eq!(bool::from(ct(a).ct_gt(&ct(b))), a > b);
+        }
+    }
+}
+
+#[rstest(::trace)]
+#[case(Choice::FALSE)]
+#[case(Choice::TRUE)]
+fn test_select_swap(#[case] choice: Choice) {
+    let a = U256::from_digit(3);
+    let b = P;
+    let (expected_a, expected_b) = if bool::from(choice) { (b, a) } else { (a, b) };
+
+    assert_eq!(ConstTime::select(&ct(a), &ct(b), choice).get(), expected_a);
+
+    let (mut x, mut y) = (ct(a), ct(b));
+    ConstTime::conditional_swap(&mut x, &mut y, choice);
+    assert_eq!((x.get(), y.get()), (expected_a, expected_b));
+
+    let mut x = ct(a);
+    x.conditional_assign(&ct(b), choice);
+    assert_eq!(x.get(), expected_a);
+}
+
+#[rstest(::trace)]
+fn test_choice() {
+    for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        let (x, y) = (Choice::from(a), Choice::from(b));
+        assert_eq!((x & y).unwrap_u8(), a & b);
+        assert_eq!((x | y).unwrap_u8(), a | b);
+        assert_eq!((x ^ y).unwrap_u8(), a ^ b);
+        assert_eq!((!x).unwrap_u8(), a ^ 1);
+    }
+}
+
+#[rstest(::trace)]
+fn test_debug_redacted() {
+    let x = ct(P);
+
+    assert_eq!(format!("{x:?}"), "ConstTime(..)");
+    assert_eq!(format!("{:?}", x.ct_eq(&x)), "Choice(..)");
+    assert_eq!(format!("{:?}", Choice::FALSE), "Choice(..)");
+}
+
+/// Every operation must execute the same sequence of limb primitives
+/// regardless of the values of its operands.
+#[rstest]
+#[case::add(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.overflowing_add(b); })]
+#[case::sub(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.overflowing_sub(b); })]
+#[case::mul(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.wrapping_mul(b); })]
+#[case::widening_mul(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.widening_mul(b); })]
+#[case::is_zero(|a: ConstTime<U256>, _: ConstTime<U256>| { let _ = a.ct_is_zero(); })]
+#[case::eq(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.ct_eq(&b); })]
+#[case::lt(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.ct_lt(&b); })]
+#[case::gt(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = a.ct_gt(&b); })]
+#[case::select(|a: ConstTime<U256>, b: ConstTime<U256>| { let _ = ConstTime::select(&a, &b, a.ct_lt(&b)); })]
+#[case::swap(|mut a: ConstTime<U256>, mut b: ConstTime<U256>| { let c = a.ct_gt(&b); ConstTime::conditional_swap(&mut a, &mut b, c); })]
+fn test_trace_independent_of_data(#[case] op: fn(ConstTime<U256>, ConstTime<U256>)) {
+    let ((), expected) = trace::record(|| op(ct(U256::ZERO), ct(U256::ZERO)));
+    assert!(expected.ops > 0);
+
+    for a in inputs() {
+        for b in inputs() {
+            let ((), trace) = trace::record(|| op(ct(a), ct(b)));
+            assert_eq!(trace, expected);
+        }
+    }
+}
+
+#[rstest(::trace)]
+fn test_trace_scales_with_width() {
+    let ((), t128) = trace::record(|| {
+        let _ = ConstTime::new(U128::MAX).wrapping_mul(ConstTime::new(U128::ONE));
+    });
+    let ((), t256) = trace::record(|| {
+        let _ = ConstTime::new(U256::MAX).wrapping_mul(ConstTime::new(U256::ONE));
+    });
+
+    // Triangular schoolbook multiplication: N·(N + 1) / 2 multiply-accumulates.
+    assert_eq!(t128.ops, 3);
+    assert_eq!(t256.ops, 10);
+}
+
+#[cfg(feature = "subtle")]
+#[rstest(::trace)]
+fn test_subtle() {
+    use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    for a in inputs() {
+        for b in inputs() {
+            assert_eq!(bool::from(ConstantTimeEq::ct_eq(&ct(a), &ct(b))), a == b);
+            assert_eq!(bool::from(ConstantTimeGreater::ct_gt(&ct(a), &ct(b))), a > b);
+            assert_eq!(bool::from(ConstantTimeLess::ct_lt(&ct(a), &ct(b))), a < b);
+
+            let choice = subtle::Choice::from((a < b) as u8);
+            let min = ConstTime::conditional_select(&ct(b), &ct(a), choice);
+            assert_eq!(min.get(), a.min(b));
+        }
+    }
+}
@@ -1 +1,7 @@
+mod ct;
+mod modular;
+mod prime;
+mod radix;
+mod resize;
+mod root;
 mod u256;
@@ -0,0 +1,173 @@
+use rstest::*;
+
+use fastnum::{
+    int::{BarrettCtx, MontgomeryCtx},
+    U128, U256, U512,
+};
+
+const P: U256 = U256::parse_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16);
+const Q: U256 = U256::parse_str_radix("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 16);
+
+const P_MONTGOMERY: MontgomeryCtx<4> = MontgomeryCtx::new(P);
+const P_BARRETT: BarrettCtx<4> = BarrettCtx::new(P);
+
+fn widen(x: U256) -> U512 {
+    U512::from_str_radix(&x.to_str_radix(16), 16).unwrap()
+}
+
+fn narrow(x: U512) -> U256 {
+    U256::from_str_radix(&x.to_str_radix(16), 16).unwrap()
+}
+
+fn mul_mod(a: U256, b: U256, m: U256) -> U256 {
+    narrow((widen(a) * widen(b)) % widen(m))
+}
+
+fn pow_mod(base: U256, exp: U256, m: U256) -> U256 {
+    let mut result = U256::ONE % m;
+    let mut i = exp.bits();
+    while i > 0 {
+        i -= 1;
+        result = mul_mod(result, result, m);
+        if exp.bit(i) {
+            result = mul_mod(result, base % m, m);
+        }
+    }
+    result
+}
+
+fn u128_to_u256(x: U128) -> U256 {
+    U256::from_str_radix(&x.to_str_radix(16), 16).unwrap()
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(0), U256::from_digit(0))]
+#[case(U256::from_digit(1), U256::from_digit(1))]
+#[case(U256::from_digit(2), U256::from_digit(3))]
+#[case(U256::from_digit(12345678901234567890), U256::parse_str_radix("98765432109876543210", 10))]
+#[case(P.sub(U256::from_digit(1)), P.sub(U256::from_digit(1)))]
+#[case(P.sub(U256::from_digit(1)), U256::from_digit(2))]
+fn test_secp256k1_mul(#[case] a: U256, #[case] b: U256) {
+    let expected = mul_mod(a, b, P);
+
+    let am = P_MONTGOMERY.to_montgomery(a);
+    let bm = P_MONTGOMERY.to_montgomery(b);
+
+    assert_eq!(P_MONTGOMERY.from_montgomery(P_MONTGOMERY.mul(am, bm)), expected);
+    assert_eq!(P_MONTGOMERY.from_montgomery(P_MONTGOMERY.square(am)), mul_mod(a, a, P));
+    assert_eq!(P_BARRETT.mul(a, b), expected);
+    assert_eq!(P_BARRETT.square(a), mul_mod(a, a, P));
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(2))]
+#[case(U256::from_digit(3))]
+#[case(U256::parse_str_radix("deadbeef", 16))]
+#[case(P.sub(U256::from_digit(1)))]
+fn test_secp256k1_fermat_inverse(#[case] a: U256) {
+    let p_2 = P.sub(U256::from_digit(2));
+
+    let am = P_MONTGOMERY.to_montgomery(a);
+    let inv = P_MONTGOMERY.pow(am, p_2);
+    assert_eq!(P_MONTGOMERY.from_montgomery(P_MONTGOMERY.mul(am, inv)), U256::from_digit(1));
+
+    let inv = P_BARRETT.pow(a, p_2);
+    assert_eq!(P_BARRETT.mul(a, inv), U256::from_digit(1));
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(0))]
+#[case(U256::from_digit(1))]
+#[case(P.sub(U256::from_digit(1)))]
+#[case(P)]
+#[case(U256::MAX)]
+fn test_montgomery_round_trip(#[case] a: U256) {
+    let am = P_MONTGOMERY.to_montgomery(a);
+    assert!(am < P);
+    assert_eq!(P_MONTGOMERY.from_montgomery(am), a % P);
+    assert_eq!(P_BARRETT.reduce(a), a % P);
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(7))]
+#[case(U256::parse_str_radix("abcdef0123456789", 16))]
+fn test_secp256k1_order(#[case] a: U256) {
+    let ctx = MontgomeryCtx::new(Q);
+    let am = ctx.to_montgomery(a);
+    assert_eq!(ctx.from_montgomery(ctx.pow(am, Q.sub(U256::from_digit(1)))), U256::from_digit(1));
+
+    let ctx = BarrettCtx::new(Q);
+    assert_eq!(ctx.pow(a, Q.sub(U256::from_digit(1))), U256::from_digit(1));
+}
+
+#[rstest(::trace)]
+#[case(U128::from_digit(3), U128::from_digit(2), U128::from_digit(10))]
+#[case(U128::from_digit(1000000007), U128::from_digit(123456789), U128::from_digit(987654321))]
+#[case(U128::from_digit(18446744073709551557), U128::from_digit(18446744073709551556), U128::from_digit(18446744073709551555))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211297", 10), U128::parse_str_radix("340282366920938463463374607431768211296", 10), U128::from_digit(65537))]
+#[case(U128::parse_str_radix("170141183460469231731687303715884105727", 10), U128::parse_str_radix("12345678901234567890123456789", 10), U128::from_digit(314159265358979))]
+fn test_pow_odd(#[case] m: U128, #[case] base: U128, #[case] exp: U128) {
+    let expected = pow_mod(u128_to_u256(base), u128_to_u256(exp), u128_to_u256(m));
+
+    let montgomery = MontgomeryCtx::new(m);
+    let b = montgomery.to_montgomery(base);
+    assert_eq!(u128_to_u256(montgomery.from_montgomery(montgomery.pow(b, exp))), expected);
+
+    let barrett = BarrettCtx::new(m);
+    assert_eq!(u128_to_u256(barrett.pow(barrett.reduce(base), exp)), expected);
+}
+
+#[rstest(::trace)]
+#[case(U128::from_digit(1))]
+#[case(U128::from_digit(2))]
+#[case(U128::from_digit(10))]
+#[case(U128::from_digit(1024))]
+#[case(U128::from_digit(1000000000000))]
+#[case(U128::parse_str_radix("170141183460469231731687303715884105728", 10))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211455", 10))]
+fn test_barrett_any_modulus(#[case] m: U128) {
+    let ctx = BarrettCtx::new(m);
+
+    for (a, b) in [
+        (U128::ZERO, U128::ZERO),
+        (U128::ONE, U128::ONE),
+        (U128::from_digit(7), U128::from_digit(13)),
+        (U128::from_digit(18446744073709551615), U128::from_digit(3)),
+        (U128::MAX, U128::MAX.sub(U128::ONE)),
+    ] {
+        let (a, b) = (a % m, b % m);
+        let expected = mul_mod(u128_to_u256(a), u128_to_u256(b), u128_to_u256(m));
+        assert_eq!(u128_to_u256(ctx.mul(a, b)), expected);
+    }
+
+    assert_eq!(ctx.reduce(U128::MAX), U128::MAX % m);
+}
+
+#[rstest(::trace)]
+#[case(U128::from_digit(7), U128::from_digit(3), U128::from_digit(5))]
+#[case(U128::from_digit(7), U128::from_digit(5), U128::from_digit(3))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211297", 10), U128::from_digit(1), U128::parse_str_radix("340282366920938463463374607431768211296", 10))]
+fn test_add_sub(#[case] m: U128, #[case] a: U128, #[case] b: U128) {
+    let montgomery = MontgomeryCtx::new(m);
+    let barrett = BarrettCtx::new(m);
+
+    let sum = montgomery.add(a, b);
+    assert_eq!(sum, barrett.add(a, b));
+    assert_eq!(montgomery.sub(sum, b), a);
+    assert_eq!(barrett.sub(sum, a), b);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) Montgomery modulus must be odd and greater than one")]
+#[case(U128::from_digit(10))]
+#[should_panic(expected = "(fastnum) Montgomery modulus must be odd and greater than one")]
+#[case(U128::from_digit(1))]
+fn test_montgomery_invalid_modulus(#[case] m: U128) {
+    let _ = MontgomeryCtx::new(m);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) Barrett modulus must be non-zero")]
+fn test_barrett_zero_modulus() {
+    let _ = BarrettCtx::new(U128::from_digit(0));
+}
@@ -0,0 +1,88 @@
+use rstest::*;
+
+use fastnum::{int::UIntExt, U128, U256, U64, U1024};
+
+fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    for i in 2..limit {
+        if is_prime[i] {
+            for j in (i * i..limit).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+    }
+    is_prime
+}
+
+#[rstest(::trace)]
+fn test_is_prime_small() {
+    for (i, expected) in sieve(20000).into_iter().enumerate() {
+        assert_eq!(U64::from_digit(i as u64).is_prime(), expected, "{i}");
+        assert_eq!(U256::from_digit(i as u64).is_prime(), expected, "{i}");
+    }
+}
+
+#[rstest(::trace)]
+// Mersenne primes
+#[case(U256::from_digit(1).shl(61).sub(U256::ONE))]
+#[case(U256::from_digit(1).shl(89).sub(U256::ONE))]
+#[case(U256::from_digit(1).shl(107).sub(U256::ONE))]
+#[case(U256::from_digit(1).shl(127).sub(U256::ONE))]
+// Largest primes below 2^64, 2^128 and 2^256
+#[case(U256::from_digit(18446744073709551557))]
+#[case(U256::parse_str_radix("340282366920938463463374607431768211297", 10))]
+#[case(U256::MAX.sub(U256::from_digit(188)))]
+// secp256k1 field prime and group order
+#[case(U256::parse_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16))]
+#[case(U256::parse_str_radix("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 16))]
+fn test_prime(#[case] p: U256) {
+    assert!(p.is_prime());
+}
+
+#[rstest(::trace)]
+// Carmichael numbers
+#[case(U256::from_digit(561))]
+#[case(U256::from_digit(41041))]
+#[case(U256::from_digit(825265))]
+// Strong pseudoprimes to several small bases
+#[case(U256::from_digit(3215031751))]
+#[case(U256::from_digit(3825123056546413051))]
+#[case(U256::parse_str_radix("318665857834031151167461", 10))]
+#[case(U256::parse_str_radix("3317044064679887385961981", 10))]
+// Composite Mersenne numbers are strong base-2 pseudoprimes
+#[case(U256::from_digit(1).shl(83).sub(U256::ONE))]
+#[case(U256::from_digit(1).shl(97).sub(U256::ONE))]
+#[case(U256::from_digit(1).shl(131).sub(U256::ONE))]
+// Squares and products of primes
+#[case(U256::from_digit(1).shl(61).sub(U256::ONE).pow(2))]
+#[case(U256::from_digit(1).shl(89).sub(U256::ONE).pow(2))]
+#[case(U256::from_digit(1).shl(61).sub(U256::ONE).mul(U256::from_digit(1).shl(89).sub(U256::ONE)))]
+#[case(U256::MAX)]
+fn test_composite(#[case] n: U256) {
+    assert!(!n.is_prime());
+}
+
+#[rstest(::trace)]
+fn test_mersenne_521() {
+    let p = U1024::ONE.shl(521).sub(U1024::ONE);
+    assert!(p.is_prime());
+    assert!(!p.sub(U1024::from_digit(2)).is_prime());
+}
+
+#[rstest(::trace)]
+#[case(U128::from_digit(0), Some(U128::from_digit(2)))]
+#[case(U128::from_digit(1), Some(U128::from_digit(2)))]
+#[case(U128::from_digit(2), Some(U128::from_digit(3)))]
+#[case(U128::from_digit(3), Some(U128::from_digit(5)))]
+#[case(U128::from_digit(13), Some(U128::from_digit(17)))]
+#[case(U128::from_digit(1000000000), Some(U128::from_digit(1000000007)))]
+#[case(U128::from_digit(u64::MAX), Some(U128::parse_str_radix("18446744073709551629", 10)))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211283", 10), Some(U128::parse_str_radix("340282366920938463463374607431768211297", 10)))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211296", 10), Some(U128::parse_str_radix("340282366920938463463374607431768211297", 10)))]
+#[case(U128::parse_str_radix("340282366920938463463374607431768211297", 10), None)]
+#[case(U128::MAX, None)]
+fn test_next_prime(#[case] n: U128, #[case] expected: Option<U128>) {
+    assert_eq!(n.next_prime(), expected);
+}
@@ -0,0 +1,119 @@
+use rstest::*;
+
+use fastnum::{
+    int::{ParseError, UIntExt},
+    U128, U256, U4096, U64, U8192,
+};
+
+/// Deterministic pseudo-random values with every bit length.
+fn samples<const N: usize>() -> impl Iterator<Item = fastnum::int::UInt<N>> {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    (0..N as u32 * 64).step_by(7).map(move |bits| {
+        let mut digits = [0; N];
+        for d in digits.iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *d = state;
+        }
+        fastnum::int::UInt::<N>::from_digits(digits) >> (N as u32 * 64 - 1 - bits)
+    })
+}
+
+fn assert_roundtrip<const N: usize>(x: fastnum::int::UInt<N>) {
+    let mut buf = vec![0; N * 64];
+    for radix in [2, 3, 7, 8, 10, 16, 32, 36] {
+        let expected = x.to_str_radix(radix);
+        assert_eq!(x.to_str_radix_into(&mut buf, radix), Some(expected.as_str()), "{radix}");
+        assert_eq!(fastnum::int::UInt::<N>::from_radix_str(&expected, radix), Ok(x), "{radix}");
+        assert_eq!(
+            fastnum::int::UInt::<N>::from_radix_str(&expected.to_uppercase(), radix),
+            Ok(x),
+            "{radix}"
+        );
+    }
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_u64() {
+    samples::<1>().for_each(assert_roundtrip);
+    assert_roundtrip(U64::ZERO);
+    assert_roundtrip(U64::MAX);
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_u256() {
+    samples::<4>().for_each(assert_roundtrip);
+    assert_roundtrip(U256::MAX);
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_u4096() {
+    samples::<64>().for_each(assert_roundtrip);
+    assert_roundtrip(U4096::MAX);
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_u8192() {
+    samples::<128>().step_by(5).for_each(assert_roundtrip);
+    assert_roundtrip(U8192::MAX);
+}
+
+#[rstest(::trace)]
+fn test_powers_of_ten() {
+    let mut x = U8192::ONE;
+    let mut expected = String::from("1");
+    let mut buf = [0; 8192];
+    while let Some(next) = x.checked_mul(U8192::TEN) {
+        assert_eq!(x.to_str_radix_into(&mut buf, 10), Some(expected.as_str()));
+        assert_eq!(U8192::from_radix_str(&expected, 10), Ok(x));
+
+        let minus_one = x - U8192::ONE;
+        assert_eq!(minus_one.to_str_radix_into(&mut buf, 10), Some(minus_one.to_str_radix(10).as_str()));
+
+        x = next;
+        expected.push('0');
+    }
+}
+
+#[rstest(::trace)]
+#[case(U256::ZERO, 10, 1)]
+#[case(U256::from_digit(9), 10, 1)]
+#[case(U256::from_digit(10), 10, 2)]
+#[case(U256::MAX, 10, 78)]
+#[case(U256::MAX, 16, 64)]
+#[case(U256::MAX, 2, 256)]
+#[case(U256::MAX, 36, 50)]
+fn test_buffer_size(#[case] x: U256, #[case] radix: u32, #[case] len: usize) {
+    let mut buf = [0; 256];
+    assert_eq!(x.to_str_radix_into(&mut buf[..len], radix).map(str::len), Some(len));
+    assert_eq!(x.to_str_radix_into(&mut buf[..len - 1], radix), None);
+}
+
+#[rstest(::trace)]
+#[case("", 10, Err(ParseError::Empty))]
+#[case("+", 10, Err(ParseError::InvalidDigit))]
+#[case("-1", 10, Err(ParseError::InvalidDigit))]
+#[case("1_000", 10, Err(ParseError::InvalidDigit))]
+#[case(" 1", 10, Err(ParseError::InvalidDigit))]
+#[case("12a", 10, Err(ParseError::InvalidDigit))]
+#[case("2", 2, Err(ParseError::InvalidDigit))]
+#[case("+0", 10, Ok(U128::ZERO))]
+#[case("000000000000000000000000000000000000000000000000000000042", 10, Ok(U128::from_digit(42)))]
+#[case("340282366920938463463374607431768211455", 10, Ok(U128::MAX))]
+#[case("340282366920938463463374607431768211456", 10, Err(ParseError::PosOverflow))]
+#[case("1000000000000000000000000000000000000000", 10, Err(ParseError::PosOverflow))]
+#[case("ffffffffffffffffffffffffffffffff", 16, Ok(U128::MAX))]
+#[case("100000000000000000000000000000000", 16, Err(ParseError::PosOverflow))]
+#[case("f5lxx1zz5pnorynqglhzmsp33", 36, Ok(U128::MAX))]
+#[case("f5lxx1zz5pnorynqglhzmsp34", 36, Err(ParseError::PosOverflow))]
+fn test_parse(#[case] s: &str, #[case] radix: u32, #[case] expected: Result<U128, ParseError>) {
+    assert_eq!(U128::from_radix_str(s, radix), expected);
+    assert_eq!(U128::from_str_radix(s, radix).map_err(|_| ()), expected.map_err(|_| ()));
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) radix must be in the range 2..=36")]
+fn test_invalid_radix() {
+    let _ = U256::ONE.to_str_radix_into(&mut [0; 8], 37);
+}
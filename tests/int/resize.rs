@@ -0,0 +1,101 @@
+use rstest::*;
+
+use fastnum::{
+    int::{IntExt, UIntExt},
+    I128, I256, I64, U128, U256, U64,
+};
+
+#[rstest(::trace)]
+#[case(U128::ZERO)]
+#[case(U128::ONE)]
+#[case(U128::from(u64::MAX))]
+#[case(U128::MAX)]
+fn test_widen(#[case] x: U128) {
+    let w: U256 = x.widen();
+    assert_eq!(w, U256::from(u128::try_from(x).unwrap()));
+    assert_eq!(x.widen::<2>(), x);
+    assert_eq!(w.try_narrow::<2>(), Some(x));
+}
+
+#[rstest(::trace)]
+#[case(U256::ZERO, Some(U64::ZERO))]
+#[case(U256::from(u64::MAX), Some(U64::MAX))]
+#[case(U256::from(u64::MAX) + U256::ONE, None)]
+#[case(U256::MAX, None)]
+fn test_try_narrow(#[case] x: U256, #[case] expected: Option<U64>) {
+    assert_eq!(x.try_narrow::<1>(), expected);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) target width is narrower than the source")]
+fn test_widen_narrower() {
+    let _ = U256::ONE.widen::<2>();
+}
+
+#[rstest(::trace)]
+#[case(U128::ZERO, U128::MAX)]
+#[case(U128::ONE, U128::MAX)]
+#[case(U128::MAX, U128::MAX)]
+#[case(U128::from(u64::MAX), U128::from(u64::MAX) + U128::ONE)]
+#[case(U128::from(123456789u64), U128::from(987654321u64))]
+fn test_widening_mul(#[case] a: U128, #[case] b: U128) {
+    let (low, high) = a.widening_mul(b);
+    let p: U256 = a.widening_mul_to(b);
+    assert_eq!(p, a.widen::<4>() * b.widen::<4>());
+    assert_eq!(p, low.widen::<4>() | (high.widen::<4>() << 128));
+    assert_eq!(a.widening_mul_to::<8>(b), p.widen::<8>());
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) target width is too narrow for the full product")]
+fn test_widening_mul_narrow() {
+    let _ = U256::ONE.widening_mul_to::<6>(U256::ONE);
+}
+
+#[rstest(::trace)]
+#[case(I128::ZERO)]
+#[case(I128::ONE)]
+#[case(I128::NEG_ONE)]
+#[case(I128::MIN)]
+#[case(I128::MAX)]
+#[case(I128::from(-12345))]
+fn test_widen_signed(#[case] x: I128) {
+    let w: I256 = x.widen();
+    assert_eq!(w, I256::from(i128::try_from(x).unwrap()));
+    assert_eq!(w.is_negative(), x.is_negative());
+    assert_eq!(w.try_narrow::<2>(), Some(x));
+    assert_eq!(w.widen::<8>().try_narrow::<2>(), Some(x));
+}
+
+#[rstest(::trace)]
+#[case(I256::ZERO, Some(I64::ZERO))]
+#[case(I256::from(i64::MAX), Some(I64::MAX))]
+#[case(I256::from(i64::MIN), Some(I64::MIN))]
+#[case(I256::from(i64::MAX) + I256::ONE, None)]
+#[case(I256::from(i64::MIN) - I256::ONE, None)]
+#[case(I256::from(u64::MAX), None)]
+#[case(I256::MAX, None)]
+#[case(I256::MIN, None)]
+fn test_try_narrow_signed(#[case] x: I256, #[case] expected: Option<I64>) {
+    assert_eq!(x.try_narrow::<1>(), expected);
+}
+
+#[rstest(::trace)]
+#[case(I128::ZERO, I128::MIN)]
+#[case(I128::NEG_ONE, I128::ONE)]
+#[case(I128::NEG_ONE, I128::MIN)]
+#[case(I128::MIN, I128::MIN)]
+#[case(I128::MAX, I128::MIN)]
+#[case(I128::MAX, I128::MAX)]
+#[case(I128::from(-123456789), I128::from(987654321))]
+#[case(I128::from(i64::MIN), I128::from(u64::MAX))]
+fn test_widening_mul_signed(#[case] a: I128, #[case] b: I128) {
+    let expected = a.widen::<4>() * b.widen::<4>();
+    assert_eq!(a.widening_mul_to::<4>(b), expected);
+    assert_eq!(b.widening_mul_to::<4>(a), expected);
+    assert_eq!(a.widening_mul_to::<8>(b), expected.widen::<8>());
+
+    let (low, high) = a.widening_mul(b);
+    let p = U256::from(u128::try_from(low).unwrap()) | (high.widen::<4>().to_bits() << 128);
+    assert_eq!(I256::from_bits(p), expected);
+}
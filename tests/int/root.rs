@@ -0,0 +1,88 @@
+use rstest::*;
+
+use fastnum::{int::UIntExt, U128, U256, U512};
+
+fn widen(x: U256) -> U512 {
+    U512::from_str_radix(&x.to_str_radix(16), 16).unwrap()
+}
+
+/// Checks _r<sup>n</sup> <= x < (r + 1)<sup>n</sup>_.
+fn assert_floor_root(x: U256, n: u32, r: U256) {
+    let (x, r) = (widen(x), widen(r));
+    assert!(r.checked_pow(n).is_some_and(|p| p <= x), "{r}^{n} > {x}");
+    assert!((r + U512::ONE).checked_pow(n).is_none_or(|p| p > x), "({r} + 1)^{n} <= {x}");
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(0), U256::from_digit(0))]
+#[case(U256::from_digit(1), U256::from_digit(1))]
+#[case(U256::from_digit(2), U256::from_digit(1))]
+#[case(U256::from_digit(3), U256::from_digit(1))]
+#[case(U256::from_digit(4), U256::from_digit(2))]
+#[case(U256::from_digit(99), U256::from_digit(9))]
+#[case(U256::from_digit(100), U256::from_digit(10))]
+#[case(U256::from_digit(u64::MAX), U256::from_digit(4294967295))]
+#[case(U256::parse_str_radix("100000000000000000000000000000000", 16), U256::parse_str_radix("10000000000000000", 16))]
+#[case(U256::MAX, U256::parse_str_radix("ffffffffffffffffffffffffffffffff", 16))]
+fn test_isqrt(#[case] x: U256, #[case] expected: U256) {
+    assert_eq!(x.isqrt(), expected);
+    assert_eq!(x.inth_root(2), expected);
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(0), U256::from_digit(0))]
+#[case(U256::from_digit(7), U256::from_digit(1))]
+#[case(U256::from_digit(8), U256::from_digit(2))]
+#[case(U256::from_digit(26), U256::from_digit(2))]
+#[case(U256::from_digit(27), U256::from_digit(3))]
+#[case(U256::from_digit(1000000000000), U256::from_digit(10000))]
+#[case(U256::from_digit(999999999999), U256::from_digit(9999))]
+#[case(U256::MAX, U256::parse_str_radix("285145f31ae515c447bb56", 16))]
+fn test_icbrt(#[case] x: U256, #[case] expected: U256) {
+    assert_eq!(x.icbrt(), expected);
+    assert_eq!(x.inth_root(3), expected);
+}
+
+#[rstest(::trace)]
+#[case(U256::MAX)]
+#[case(U256::MAX.sub(U256::ONE))]
+#[case(U256::parse_str_radix("123456789012345678901234567890123456789012345678901234567890", 10))]
+#[case(U256::parse_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16))]
+#[case(U256::from_digit(1).shl(200))]
+#[case(U256::from_digit(1).shl(200).sub(U256::ONE))]
+#[case(U256::from_digit(12345))]
+fn test_inth_root(#[case] x: U256) {
+    for n in [1, 2, 3, 4, 5, 7, 10, 16, 31, 32, 64, 100, 200, 255, 256, 1000] {
+        assert_floor_root(x, n, x.inth_root(n));
+    }
+}
+
+#[rstest(::trace)]
+fn test_roots_small() {
+    for i in 0..2000u64 {
+        let x = U128::from_digit(i);
+        assert_eq!(x.isqrt(), U128::from_digit((i as f64).sqrt() as u64));
+        assert_eq!(x.icbrt(), U128::from_digit((0..=i).take_while(|r| r * r * r <= i).last().unwrap()));
+        assert_eq!(x.is_perfect_square(), ((i as f64).sqrt() as u64).pow(2) == i);
+    }
+}
+
+#[rstest(::trace)]
+#[case(U256::from_digit(0), true)]
+#[case(U256::from_digit(1), true)]
+#[case(U256::from_digit(1).shl(254), true)]
+#[case(U256::from_digit(1).shl(255), false)]
+#[case(U256::parse_str_radix("ffffffffffffffffffffffffffffffff", 16).pow(2), true)]
+#[case(U256::parse_str_radix("ffffffffffffffffffffffffffffffff", 16).pow(2).sub(U256::ONE), false)]
+#[case(U256::parse_str_radix("ffffffffffffffffffffffffffffffff", 16).pow(2).add(U256::ONE), false)]
+#[case(U256::from_digit(4294967295).pow(2), true)]
+#[case(U256::MAX, false)]
+fn test_is_perfect_square(#[case] x: U256, #[case] expected: bool) {
+    assert_eq!(x.is_perfect_square(), expected);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) zeroth root is undefined")]
+fn test_zeroth_root() {
+    let _ = U256::from_digit(8).inth_root(0);
+}
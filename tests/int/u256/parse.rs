@@ -17,7 +17,7 @@ fn test_max_u256_hex() {
 }
 
 #[rstest]
-#[should_panic(expected = "attempt to parse integer from string containing invalid digit")]
+#[should_panic(expected = "InvalidDigit")]
 fn test_u256_negative() {
     let _ = U256::from_str("-1").unwrap();
 }
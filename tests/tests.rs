@@ -1,4 +1,4 @@
 extern crate core;
 
 mod decimal;
-// mod int;
+mod int;